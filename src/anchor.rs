@@ -0,0 +1,157 @@
+use std::ops::Range;
+
+use crate::IndexableString;
+
+/// `Anchor` is a handle to a `char` position in an `IndexableString` that is moved by every `edit`, so the position
+/// stays attached to the same text. It's only meaningful for the string that created it, and its clones.
+///
+/// # Examples
+/// ```
+/// use indexable_str::{Affinity, IndexableString};
+///
+/// let mut s = IndexableString::from("let x = 😀;");
+/// let anchor = s.add_anchor(8, Affinity::Left);
+///
+/// s.edit(4..5, "value");
+///
+/// assert_eq!(s.anchor_position(anchor), Some(12));
+/// assert_eq!(s[12], '😀');
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Anchor {
+    id: usize,
+}
+
+/// `Affinity` chooses where an `Anchor` goes when the text around it is replaced: an anchor at an insertion point, or
+/// inside a replaced range, moves to the start of the new text if its affinity is `Left`, and to the end of it if its
+/// affinity is `Right`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Affinity {
+    Left,
+    Right,
+}
+
+/// The positions of the anchors of one string, indexed by anchor id. Removed anchors leave `None`, so ids aren't
+/// reused.
+#[derive(Clone, Default)]
+pub(crate) struct Anchors {
+    positions: Vec<Option<(usize, Affinity)>>,
+}
+
+impl Anchors {
+    /// Moves the anchors after the `char`s in `range` were replaced by `new_length` `char`s.
+    pub(crate) fn adjust(&mut self, range: Range<usize>, new_length: usize) {
+        let new_end: usize = range.start + new_length;
+
+        for (position, affinity) in self.positions.iter_mut().flatten() {
+            *position = match *position {
+                index if index < range.start || (index == range.start && range.start < range.end) => index,
+                index if index > range.end || (index == range.end && range.start < range.end) => index - range.end + new_end,
+                _ => match affinity {
+                    Affinity::Left => range.start,
+                    Affinity::Right => new_end,
+                },
+            };
+        }
+    }
+}
+
+impl IndexableString {
+    /// Returns a new `Anchor` at the `char` index `index`, which moves with the text around it when the string is
+    /// edited.
+    /// # Arguments
+    ///
+    /// * `index` - The `char` index. The number of `char`s is allowed, for the end of the string.
+    /// * `affinity` - Where the anchor goes when text is inserted at it, or when the text around it is replaced.
+    /// # Panics
+    /// * If `index` is greater than the number of characters in the string.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{Affinity, IndexableString};
+    ///
+    /// let mut s = IndexableString::from("ab");
+    /// let left = s.add_anchor(1, Affinity::Left);
+    /// let right = s.add_anchor(1, Affinity::Right);
+    ///
+    /// s.edit(1..1, "😀😀");
+    ///
+    /// assert_eq!(s.anchor_position(left), Some(1));
+    /// assert_eq!(s.anchor_position(right), Some(3));
+    /// ```
+    pub fn add_anchor(&mut self, index: usize, affinity: Affinity) -> Anchor {
+        if index > self.len() {
+            panic!("Anchor index: ({index}) must be less than or equal to the number of UTF-8 characters in the string ({})!", self.len());
+        }
+
+        let anchors: &mut Anchors = self.anchors_mut();
+
+        anchors.positions.push(Some((index, affinity)));
+
+        Anchor {
+            id: anchors.positions.len() - 1,
+        }
+    }
+
+    /// Returns the current `char` index of `anchor`, or `None` if it was removed or wasn't created by this string.
+    /// # Arguments
+    ///
+    /// * `anchor` - The anchor.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{Affinity, IndexableString};
+    ///
+    /// let mut s = IndexableString::from("0😀2345");
+    /// let anchor = s.add_anchor(4, Affinity::Right);
+    ///
+    /// s.edit(2..5, "");
+    ///
+    /// assert_eq!(s.anchor_position(anchor), Some(2));
+    /// ```
+    pub fn anchor_position(&self, anchor: Anchor) -> Option<usize> {
+        self.anchors().positions.get(anchor.id).copied().flatten().map(|(position, _)| position)
+    }
+
+    /// Removes `anchor`, so edits no longer move it, and returns its last `char` index, or `None` if it was already
+    /// removed or wasn't created by this string.
+    /// # Arguments
+    ///
+    /// * `anchor` - The anchor.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{Affinity, IndexableString};
+    ///
+    /// let mut s = IndexableString::from("0😀2");
+    /// let anchor = s.add_anchor(1, Affinity::Left);
+    ///
+    /// assert_eq!(s.remove_anchor(anchor), Some(1));
+    /// assert_eq!(s.anchor_position(anchor), None);
+    /// ```
+    pub fn remove_anchor(&mut self, anchor: Anchor) -> Option<usize> {
+        self.anchors_mut().positions.get_mut(anchor.id)?.take().map(|(position, _)| position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anchors_follow_edits() {
+        let mut s = IndexableString::from("0123456789");
+        let anchors: Vec<(Anchor, Anchor)> = (0..=10).map(|index| (s.add_anchor(index, Affinity::Left), s.add_anchor(index, Affinity::Right))).collect();
+
+        s.edit(3..6, "😀");
+
+        let expected: [(usize, usize); 11] = [(0, 0), (1, 1), (2, 2), (3, 3), (3, 4), (3, 4), (4, 4), (5, 5), (6, 6), (7, 7), (8, 8)];
+
+        for (index, (left, right)) in anchors.iter().enumerate() {
+            assert_eq!((s.anchor_position(*left).unwrap(), s.anchor_position(*right).unwrap()), expected[index], "{index}");
+        }
+
+        s.edit(8..8, "ab");
+
+        assert_eq!(s.anchor_position(anchors[10].0), Some(8));
+        assert_eq!(s.anchor_position(anchors[10].1), Some(10));
+        assert_eq!(s.anchor_position(Anchor { id: 100 }), None);
+    }
+}
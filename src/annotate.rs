@@ -0,0 +1,194 @@
+use std::{collections::BTreeSet, error::Error, fmt::Display};
+
+use crate::{error::check_range, IndexableStr, IndexableString, Offset, Span};
+
+/// `OverlapError` is returned by `IndexableStr::annotate` when two annotations cover some of the same `char`s.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct OverlapError {
+    pub first: Span,
+    pub second: Span,
+}
+
+impl Display for OverlapError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Span: ({}..{}) overlaps Span: ({}..{})!", self.first.start, self.first.end, self.second.start, self.second.end)
+    }
+}
+
+impl Error for OverlapError {}
+
+/// `AnnotatedSegment` is a piece of a string returned by `IndexableStr::annotate`: its `Span`, its text, and the tag
+/// of the annotation that covers it, or `None` if there isn't one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnnotatedSegment<'a, 't, T> {
+    pub span: Span,
+    pub text: &'a str,
+    pub tag: Option<&'t T>,
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns the string broken into segments at the boundaries of `annotations`, with the `Span` and text of each
+    /// segment and the tag of the annotation that covers it, or `None` for the text between annotations. Empty
+    /// annotations are ignored.
+    /// # Arguments
+    ///
+    /// * `annotations` - The spans to tag, in any order.
+    /// # Panics
+    /// * If the end of a span is greater than the number of characters in the string.
+    /// * If the end of a span is less than its start.
+    /// # Errors
+    /// * If two annotations overlap. The error holds both spans.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("find 😀 here");
+    /// let annotations = [(Span::new(7, 11), "hit"), (Span::new(0, 4), "keyword")];
+    /// let segments = s.annotate(&annotations).unwrap();
+    ///
+    /// let pieces: Vec<(&str, Option<&&str>)> = segments.iter().map(|segment| (segment.text, segment.tag)).collect();
+    ///
+    /// assert_eq!(pieces, vec![("find", Some(&"keyword")), (" 😀 ", None), ("here", Some(&"hit"))]);
+    /// assert_eq!(segments[1].span, Span::new(4, 7));
+    /// assert!(s.annotate(&[(Span::new(0, 5), "a"), (Span::new(4, 6), "b")]).is_err());
+    /// ```
+    pub fn annotate<'t, T>(&self, annotations: &'t [(Span, T)]) -> Result<Vec<AnnotatedSegment<'a, 't, T>>, OverlapError> {
+        let mut spans: Vec<Span> = annotations.iter().map(|&(span, _)| span).filter(|span| !span.is_empty()).collect();
+
+        spans.sort();
+
+        for pair in spans.windows(2) {
+            if pair[1].start < pair[0].end {
+                return Err(OverlapError { first: pair[0], second: pair[1] });
+            }
+        }
+
+        Ok(self.annotate_by_priority(annotations))
+    }
+
+    /// Returns the string broken into segments at the boundaries of `annotations`, like `annotate`, but where
+    /// annotations overlap, the one that comes first in `annotations` tags the `char`s they share.
+    /// # Arguments
+    ///
+    /// * `annotations` - The spans to tag, from the highest priority to the lowest.
+    /// # Panics
+    /// * If the end of a span is greater than the number of characters in the string.
+    /// * If the end of a span is less than its start.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("a 😀 b");
+    /// let annotations = [(Span::new(2, 3), "emoji"), (Span::new(0, 5), "line")];
+    /// let segments = s.annotate_by_priority(&annotations);
+    /// let pieces: Vec<(&str, Option<&&str>)> = segments.iter().map(|segment| (segment.text, segment.tag)).collect();
+    ///
+    /// assert_eq!(pieces, vec![("a ", Some(&"line")), ("😀", Some(&"emoji")), (" b", Some(&"line"))]);
+    /// ```
+    pub fn annotate_by_priority<'t, T>(&self, annotations: &'t [(Span, T)]) -> Vec<AnnotatedSegment<'a, 't, T>> {
+        // Each annotation starts and ends at a boundary, where the annotation with the highest priority among those
+        // that cover the text after it tags the next segment.
+        let mut boundaries: Vec<(usize, bool, usize)> = Vec::with_capacity(annotations.len() * 2);
+
+        for (priority, &(span, _)) in annotations.iter().enumerate() {
+            if let Err(error) = check_range(span.start, span.end, self.chars_length) {
+                panic!("{error}");
+            }
+
+            if !span.is_empty() {
+                boundaries.push((span.start, true, priority));
+                boundaries.push((span.end, false, priority));
+            }
+        }
+
+        boundaries.sort();
+
+        let mut segments: Vec<AnnotatedSegment<'a, 't, T>> = Vec::new();
+        let mut active: BTreeSet<usize> = BTreeSet::new();
+        let mut start: usize = 0;
+        let mut tag: Option<usize> = None;
+
+        for (index, is_start, priority) in boundaries {
+            match is_start {
+                true => active.insert(priority),
+                false => active.remove(&priority),
+            };
+
+            let next_tag: Option<usize> = active.first().copied();
+
+            if next_tag != tag {
+                if index > start {
+                    segments.push(AnnotatedSegment {
+                        span: Span::new(start, index),
+                        text: self.create_str_from_range(start, index),
+                        tag: tag.map(|priority| &annotations[priority].1),
+                    });
+                }
+
+                start = index;
+                tag = next_tag;
+            }
+        }
+
+        if self.chars_length > start {
+            segments.push(AnnotatedSegment {
+                span: Span::new(start, self.chars_length),
+                text: self.create_str_from_range(start, self.chars_length),
+                tag: None,
+            });
+        }
+
+        segments
+    }
+}
+
+impl IndexableString {
+    /// Returns the string broken into segments at the boundaries of `annotations`. See `IndexableStr::annotate`.
+    /// # Arguments
+    ///
+    /// * `annotations` - The spans to tag, in any order.
+    /// # Panics
+    /// * If the end of a span is greater than the number of characters in the string.
+    /// * If the end of a span is less than its start.
+    /// # Errors
+    /// * If two annotations overlap. The error holds both spans.
+    pub fn annotate<'t, T>(&self, annotations: &'t [(Span, T)]) -> Result<Vec<AnnotatedSegment<'_, 't, T>>, OverlapError> {
+        self.as_indexable_str().annotate(annotations)
+    }
+
+    /// Returns the string broken into segments at the boundaries of `annotations`, resolving overlaps by their order.
+    /// See `IndexableStr::annotate_by_priority`.
+    /// # Arguments
+    ///
+    /// * `annotations` - The spans to tag, from the highest priority to the lowest.
+    /// # Panics
+    /// * If the end of a span is greater than the number of characters in the string.
+    /// * If the end of a span is less than its start.
+    pub fn annotate_by_priority<'t, T>(&self, annotations: &'t [(Span, T)]) -> Vec<AnnotatedSegment<'_, 't, T>> {
+        self.as_indexable_str().annotate_by_priority(annotations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segments_agree_with_tagging_each_char() {
+        let s = IndexableStr::new("0😀2345678😀");
+        let annotations: Vec<(Span, usize)> = vec![(Span::new(3, 5), 0), (Span::new(1, 8), 1), (Span::new(6, 10), 2), (Span::new(4, 4), 3)];
+        let segments = s.annotate_by_priority(&annotations);
+
+        for index in 0..s.len() {
+            let expected: Option<&usize> = annotations.iter().find(|(span, _)| span.range().contains(&index)).map(|(_, tag)| tag);
+            let segment = segments.iter().find(|segment| segment.span.range().contains(&index)).unwrap();
+
+            assert_eq!(segment.tag, expected);
+        }
+
+        assert!(segments.windows(2).all(|pair| pair[0].span.end == pair[1].span.start && pair[0].tag != pair[1].tag));
+        assert_eq!(segments.iter().map(|segment| segment.text).collect::<String>(), s.as_str());
+        assert_eq!(s.annotate(&annotations).err().unwrap(), OverlapError { first: Span::new(1, 8), second: Span::new(3, 5) });
+        assert!(IndexableStr::new("").annotate::<()>(&[]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
+use crate::{char_table::CharTableBuilder, grapheme::GraphemeCache, IndexableStr, Span, SubStr};
+
+/// `ArenaHandle` identifies a string that was interned in an `IndexableArena`. It's only meaningful for the arena that
+/// returned it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArenaHandle {
+    id: u32,
+}
+
+/// `IndexableArena` interns many small strings into one backing `String` and one shared index, so interning a string
+/// doesn't allocate a `String` or index of its own. Interning the same text twice returns the same `ArenaHandle`, and
+/// `get` returns a `SubStr` view that supports the usual `char` indexing.
+///
+/// # Examples
+/// ```
+/// use indexable_str::IndexableArena;
+///
+/// let mut arena = IndexableArena::new();
+/// let first = arena.intern("😀name");
+/// let second = arena.intern("other");
+///
+/// assert_eq!(arena.intern("😀name"), first);
+/// assert_ne!(first, second);
+/// assert_eq!(arena.get(first)[0], '😀');
+/// assert_eq!(&arena.get(first)[1..], "name");
+/// assert_eq!(arena.len(), 2);
+/// ```
+pub struct IndexableArena {
+    string: String,
+    char_table_builder: CharTableBuilder,
+    spans: Vec<Span>,
+    lookup: HashMap<u64, Vec<ArenaHandle>>,
+}
+
+impl IndexableArena {
+    /// Returns an empty arena.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableArena;
+    ///
+    /// assert!(IndexableArena::new().is_empty());
+    /// ```
+    pub fn new() -> IndexableArena {
+        IndexableArena {
+            string: String::new(),
+            char_table_builder: CharTableBuilder::new(),
+            spans: Vec::new(),
+            lookup: HashMap::new(),
+        }
+    }
+
+    /// Returns the handle of the interned string with the same text as `str`, interning it first if there isn't
+    /// one.
+    /// # Arguments
+    ///
+    /// * `str` - The text to intern.
+    /// # Panics
+    /// * If the arena already holds `u32::MAX` strings.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableArena;
+    ///
+    /// let mut arena = IndexableArena::new();
+    ///
+    /// assert_eq!(arena.intern("x"), arena.intern("x"));
+    /// ```
+    pub fn intern(&mut self, str: &str) -> ArenaHandle {
+        let hash: u64 = {
+            let mut hasher: DefaultHasher = DefaultHasher::new();
+
+            str.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        if let Some(handles) = self.lookup.get(&hash) {
+            if let Some(handle) = handles.iter().find(|handle| self.get(**handle).as_str() == str) {
+                return *handle;
+            }
+        }
+
+        let id: u32 = match u32::try_from(self.spans.len()) {
+            Ok(id) if id < u32::MAX => id,
+            _ => panic!("An arena can't hold more than ({}) strings!", u32::MAX),
+        };
+        let start: usize = self.char_table_builder.len(&self.string);
+
+        self.string.push_str(str);
+        self.char_table_builder.extend(&self.string);
+        self.spans.push(Span::new(start, self.char_table_builder.len(&self.string)));
+
+        let handle: ArenaHandle = ArenaHandle { id };
+
+        self.lookup.entry(hash).or_default().push(handle);
+
+        handle
+    }
+
+    /// Returns a `SubStr` view of an interned string. Indices into it are relative to the start of the string, and
+    /// its `span` is its position in the arena's backing string.
+    /// # Arguments
+    ///
+    /// * `handle` - The handle returned by `intern`.
+    /// # Panics
+    /// * If the handle wasn't returned by this arena.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableArena;
+    ///
+    /// let mut arena = IndexableArena::new();
+    /// let handle = arena.intern("0😀2");
+    ///
+    /// assert_eq!(arena.get(handle).len(), 3);
+    /// assert_eq!(arena.get(handle).as_str(), "0😀2");
+    /// ```
+    pub fn get(&self, handle: ArenaHandle) -> SubStr<'_> {
+        let span: Span = match self.spans.get(handle.id as usize) {
+            Some(span) => *span,
+            None => panic!("Arena handle: ({}) must be less than the number of strings in the arena ({})!", handle.id, self.spans.len()),
+        };
+        let parent: IndexableStr<'_> = IndexableStr {
+            str: &self.string,
+            str_length: self.string.len(),
+            char_table: Cow::Borrowed(self.char_table_builder.char_table()),
+            chars_length: self.char_table_builder.len(&self.string),
+            graphemes: Cow::Owned(GraphemeCache::new()),
+        };
+
+        SubStr::new(&parent, self.char_table_builder.char_table(), span.start..span.end)
+    }
+
+    /// Returns the number of strings that have been interned.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableArena;
+    ///
+    /// let mut arena = IndexableArena::new();
+    ///
+    /// arena.intern("a");
+    /// arena.intern("a");
+    ///
+    /// assert_eq!(arena.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Returns `true` if no strings have been interned.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableArena;
+    ///
+    /// assert!(IndexableArena::new().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Returns the number of bytes allocated on the heap for the backing string, the shared index and the handle
+    /// tables.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableArena;
+    ///
+    /// let mut arena = IndexableArena::new();
+    ///
+    /// arena.intern("0123456789");
+    ///
+    /// assert!(arena.memory_usage() >= 10);
+    /// ```
+    pub fn memory_usage(&self) -> usize {
+        self.string.capacity()
+            + self.char_table_builder.char_table().memory_usage()
+            + self.spans.capacity() * std::mem::size_of::<Span>()
+            + self.lookup.capacity() * std::mem::size_of::<(u64, Vec<ArenaHandle>)>()
+            + self.lookup.values().map(|handles| handles.capacity() * std::mem::size_of::<ArenaHandle>()).sum::<usize>()
+    }
+}
+
+impl Default for IndexableArena {
+    fn default() -> Self {
+        IndexableArena::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interned_strings_agree_with_their_text() {
+        let words: Vec<String> = (0..200).map(|index| format!("{}😀{}", "abc".repeat(index % 3), index % 50)).collect();
+        let mut arena = IndexableArena::new();
+        let handles: Vec<ArenaHandle> = words.iter().map(|word| arena.intern(word)).collect();
+
+        assert_eq!(arena.len(), 150);
+        assert_eq!(arena.intern(""), arena.intern(""));
+
+        for (word, handle) in words.iter().zip(handles) {
+            let view: SubStr<'_> = arena.get(handle);
+
+            assert_eq!(view.as_str(), word);
+            assert_eq!(view.len(), word.chars().count());
+            assert_eq!(view[view.len() - 1], word.chars().last().unwrap());
+        }
+    }
+}
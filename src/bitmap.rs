@@ -0,0 +1,181 @@
+/// The number of bits covered by each entry in the rank directory.
+const SUPERBLOCK_BITS: usize = 512;
+
+/// The number of 64-bit words covered by each entry in the rank directory.
+const SUPERBLOCK_WORDS: usize = SUPERBLOCK_BITS / 64;
+
+/// The number of set bits between entries in the select directory.
+const SELECT_SAMPLE_RATE: usize = 512;
+
+/// `BoundaryBitmap` stores one bit per byte of a string, set when the byte starts a `char`.
+///
+/// A rank directory holding the number of set bits before every 512-bit superblock answers "how many `char`s start
+/// before this byte" by counting at most eight words. A select directory holding the position of every 512th set bit
+/// answers "at which byte does this `char` start" by scanning a bounded number of superblocks, because UTF-8 never
+/// uses more than four bytes for a `char`. Together they need about 0.16 bytes per byte of the string.
+#[derive(Clone)]
+pub(crate) struct BoundaryBitmap {
+    words: Vec<u64>,
+    ranks: Vec<usize>,
+    selects: Vec<usize>,
+    ones: usize,
+}
+
+impl BoundaryBitmap {
+    pub(crate) fn new(str: &str) -> BoundaryBitmap {
+        let words: Vec<u64> = str.as_bytes().chunks(64).map(|chunk| {
+            chunk.iter().enumerate().fold(0u64, |word, (bit, byte)| {
+                match byte & 0xC0 {
+                    0x80 => word,
+                    _ => word | (1 << bit),
+                }
+            })
+        }).collect();
+
+        let mut ranks: Vec<usize> = Vec::with_capacity(words.len() / SUPERBLOCK_WORDS + 1);
+        let mut selects: Vec<usize> = Vec::new();
+        let mut ones: usize = 0;
+
+        for (word_index, word) in words.iter().enumerate() {
+            if word_index % SUPERBLOCK_WORDS == 0 {
+                ranks.push(ones);
+            }
+
+            let count: usize = word.count_ones() as usize;
+            let next_sample: usize = selects.len() * SELECT_SAMPLE_RATE;
+
+            if next_sample < ones + count {
+                selects.push(word_index * 64 + select_in_word(*word, next_sample - ones));
+            }
+
+            ones += count;
+        }
+
+        ranks.push(ones);
+
+        BoundaryBitmap {
+            words,
+            ranks,
+            selects,
+            ones,
+        }
+    }
+
+    /// Returns the number of set bits, which is the number of `char`s in the string.
+    pub(crate) fn ones(&self) -> usize {
+        self.ones
+    }
+
+    /// Returns `true` if the bit for the byte at `position` is set.
+    pub(crate) fn is_set(&self, position: usize) -> bool {
+        self.words[position / 64] & (1 << (position % 64)) != 0
+    }
+
+    /// Returns the number of set bits before `position`. `position` may be equal to the number of bits.
+    pub(crate) fn rank(&self, position: usize) -> usize {
+        let word_index: usize = position / 64;
+        let superblock: usize = word_index / SUPERBLOCK_WORDS;
+        let mut rank: usize = self.ranks[superblock];
+
+        for word in &self.words[superblock * SUPERBLOCK_WORDS..word_index] {
+            rank += word.count_ones() as usize;
+        }
+
+        if !position.is_multiple_of(64) {
+            rank += (self.words[word_index] & ((1 << (position % 64)) - 1)).count_ones() as usize;
+        }
+
+        rank
+    }
+
+    /// Returns the position of the set bit with the zero-based rank `k`.
+    ///
+    /// # Panics
+    /// * If `k` is greater than or equal to the number of set bits.
+    pub(crate) fn select(&self, k: usize) -> usize {
+        if k >= self.ones {
+            panic!("Index: ({k}) must be less than the number of UTF-8 characters in the string ({})!", self.ones);
+        }
+
+        let mut superblock: usize = self.selects[k / SELECT_SAMPLE_RATE] / SUPERBLOCK_BITS;
+
+        while self.ranks[superblock + 1] <= k {
+            superblock += 1;
+        }
+
+        let mut remaining: usize = k - self.ranks[superblock];
+        let mut word_index: usize = superblock * SUPERBLOCK_WORDS;
+
+        loop {
+            let count: usize = self.words[word_index].count_ones() as usize;
+
+            if remaining < count {
+                return word_index * 64 + select_in_word(self.words[word_index], remaining);
+            }
+
+            remaining -= count;
+            word_index += 1;
+        }
+    }
+
+    /// Returns the number of bytes allocated on the heap for the bitmap and its directories.
+    pub(crate) fn memory_usage(&self) -> usize {
+        self.words.capacity() * std::mem::size_of::<u64>()
+            + self.ranks.capacity() * std::mem::size_of::<usize>()
+            + self.selects.capacity() * std::mem::size_of::<usize>()
+    }
+
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.words.shrink_to_fit();
+        self.ranks.shrink_to_fit();
+        self.selects.shrink_to_fit();
+    }
+}
+
+/// Returns the position of the set bit with the zero-based rank `k` within `word`.
+fn select_in_word(mut word: u64, k: usize) -> usize {
+    for _ in 0..k {
+        word &= word - 1;
+    }
+
+    word.trailing_zeros() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_and_select_agree_with_char_indices() {
+        let str: String = "0😀é中a".repeat(300);
+        let bitmap = BoundaryBitmap::new(&str);
+
+        assert_eq!(bitmap.ones(), str.chars().count());
+
+        for (index, (offset, _)) in str.char_indices().enumerate() {
+            assert_eq!(bitmap.select(index), offset);
+            assert_eq!(bitmap.rank(offset), index);
+            assert!(bitmap.is_set(offset));
+        }
+
+        assert_eq!(bitmap.rank(str.len()), bitmap.ones());
+    }
+
+    #[test]
+    fn test_continuation_bytes_are_not_set() {
+        let bitmap = BoundaryBitmap::new("😀a");
+
+        assert!(bitmap.is_set(0));
+        assert!(!bitmap.is_set(1));
+        assert!(!bitmap.is_set(3));
+        assert!(bitmap.is_set(4));
+    }
+
+    #[test]
+    fn test_select_out_of_bounds_panics() {
+        let bitmap = BoundaryBitmap::new("😀a");
+
+        let result = std::panic::catch_unwind(|| bitmap.select(2));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt::{self, Write};
+
+use crate::{char_table::CharTableBuilder, IndexableString};
+
+/// `IndexableStringBuilder` assembles an `IndexableString` a piece at a time. Each piece is indexed as it's
+/// appended, so finishing the builder doesn't need another pass over the text.
+///
+/// The builder also implements `std::fmt::Write`, so it can be the target of `write!`.
+///
+/// # Examples
+/// ```
+/// use std::fmt::Write;
+/// use indexable_str::IndexableStringBuilder;
+///
+/// let mut builder = IndexableStringBuilder::new();
+///
+/// builder.push_str("0😀");
+/// builder.push_char('2');
+/// write!(builder, "{}", 345).unwrap();
+///
+/// let s = builder.finish();
+///
+/// assert_eq!(s.as_str(), "0😀2345");
+/// assert_eq!(s[1], '😀');
+/// ```
+pub struct IndexableStringBuilder {
+    string: String,
+    char_table_builder: CharTableBuilder,
+}
+
+impl IndexableStringBuilder {
+    /// Returns an empty builder.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStringBuilder;
+    ///
+    /// let builder = IndexableStringBuilder::new();
+    /// ```
+    pub fn new() -> IndexableStringBuilder {
+        IndexableStringBuilder::with_capacity(0)
+    }
+
+    /// Returns an empty builder whose string can hold at least `capacity` bytes without reallocating.
+    /// # Arguments
+    ///
+    /// * `capacity` - The number of bytes to reserve.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStringBuilder;
+    ///
+    /// let builder = IndexableStringBuilder::with_capacity(1024);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> IndexableStringBuilder {
+        IndexableStringBuilder {
+            string: String::with_capacity(capacity),
+            char_table_builder: CharTableBuilder::new(),
+        }
+    }
+
+    /// Appends a string slice.
+    /// # Arguments
+    ///
+    /// * `str` - The string slice to append.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStringBuilder;
+    ///
+    /// let mut builder = IndexableStringBuilder::new();
+    ///
+    /// builder.push_str("0😀");
+    ///
+    /// assert_eq!(builder.as_str(), "0😀");
+    /// ```
+    pub fn push_str(&mut self, str: &str) {
+        self.string.push_str(str);
+        self.char_table_builder.extend(&self.string);
+    }
+
+    /// Appends a `char`.
+    /// # Arguments
+    ///
+    /// * `chr` - The `char` to append.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStringBuilder;
+    ///
+    /// let mut builder = IndexableStringBuilder::new();
+    ///
+    /// builder.push_char('😀');
+    ///
+    /// assert_eq!(builder.len(), 1);
+    /// ```
+    pub fn push_char(&mut self, chr: char) {
+        self.string.push(chr);
+        self.char_table_builder.extend(&self.string);
+    }
+
+    /// Returns the text that has been appended so far.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStringBuilder;
+    ///
+    /// let mut builder = IndexableStringBuilder::new();
+    ///
+    /// builder.push_str("0😀");
+    ///
+    /// assert_eq!(builder.as_str(), "0😀");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    /// Returns a `usize` for the number of `char`s that have been appended so far.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStringBuilder;
+    ///
+    /// let mut builder = IndexableStringBuilder::new();
+    ///
+    /// builder.push_str("0😀2");
+    ///
+    /// assert_eq!(builder.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.char_table_builder.len(&self.string)
+    }
+
+    /// Returns `true` if nothing has been appended.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStringBuilder;
+    ///
+    /// assert!(IndexableStringBuilder::new().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.string.is_empty()
+    }
+
+    /// Returns the assembled `IndexableString`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStringBuilder;
+    ///
+    /// let mut builder = IndexableStringBuilder::new();
+    ///
+    /// builder.push_str("0😀2");
+    ///
+    /// assert_eq!(builder.finish()[1], '😀');
+    /// ```
+    pub fn finish(self) -> IndexableString {
+        IndexableString::from_parts(self.string, self.char_table_builder.finish())
+    }
+}
+
+impl Default for IndexableStringBuilder {
+    fn default() -> Self {
+        IndexableStringBuilder::new()
+    }
+}
+
+impl Write for IndexableStringBuilder {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_agrees_with_indexable_string() {
+        let mut builder = IndexableStringBuilder::new();
+
+        for piece in ["012", "😀", "", "é中", "abc"] {
+            builder.push_str(piece);
+        }
+
+        builder.push_char('😀');
+
+        let s = builder.finish();
+        let expected = IndexableString::from("012😀é中abc😀");
+
+        assert_eq!(s.len(), expected.len());
+
+        for index in 0..expected.len() {
+            assert_eq!(s[index], expected[index]);
+            assert_eq!(&s[index..], &expected[index..]);
+        }
+    }
+
+    #[test]
+    fn test_ascii_builder_does_not_create_a_char_table() {
+        let mut builder = IndexableStringBuilder::new();
+
+        builder.push_str("0123");
+        builder.push_char('4');
+
+        let s = builder.finish();
+
+        assert_eq!(s.memory_usage(), 0);
+        assert_eq!(&s[1..4], "123");
+    }
+}
@@ -0,0 +1,283 @@
+use std::{
+    fmt::Display,
+    ops::{Index, Range, RangeFrom, RangeTo},
+};
+
+use crate::{
+    char_table::{CharOffsets, CharTable},
+    error::{check_index, check_range},
+    Offset, Span,
+};
+
+/// `IndexableBytes` indexes the `char`s of a byte string that is conventionally UTF-8, but may not be valid.
+///
+/// Each invalid sequence is read as a single `U+FFFD REPLACEMENT CHARACTER`, in the same places as
+/// `String::from_utf8_lossy` would put one, but the bytes themselves are never changed. Slicing by `char`s returns
+/// the original bytes, and `byte_range` gives the bytes each `char` came from. Requires the `bytes` feature.
+///
+/// # Examples
+/// ```
+/// use indexable_str::IndexableBytes;
+///
+/// let s = IndexableBytes::new(b"0\xF0\x9F\x98\x802\xFF4");
+///
+/// assert_eq!(s.len(), 5);
+/// assert_eq!(s[1], '😀');
+/// assert_eq!(s[3], '\u{FFFD}');
+/// assert!(s.is_invalid(3));
+/// assert_eq!(s.byte_range(3), 6..7);
+/// assert_eq!(&s[2..4], b"2\xFF");
+/// ```
+#[derive(Clone)]
+pub struct IndexableBytes<'a> {
+    bytes: &'a [u8],
+    // The bytes as a string slice if they're valid UTF-8, for the `Ascii` table, which reads `char`s from it.
+    // Otherwise this is empty, and the table is never `Ascii`.
+    str: &'a str,
+    char_table: CharTable,
+    chars_length: usize,
+}
+
+impl<'a> IndexableBytes<'a> {
+    /// Returns an indexable byte string.
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes to be indexed, which are expected to be UTF-8.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableBytes;
+    ///
+    /// let s = IndexableBytes::new(b"0\xFF2");
+    /// ```
+    pub fn new(bytes: &'a [u8]) -> IndexableBytes<'a> {
+        let (str, char_table) = match std::str::from_utf8(bytes) {
+            Ok(str) => (str, CharTable::new(str)),
+            Err(_) if bytes.len() <= u32::MAX as usize => ("", CharTable::Narrow(lossy_char_offsets(bytes))),
+            Err(_) => ("", CharTable::Wide(lossy_char_offsets(bytes))),
+        };
+        let chars_length: usize = char_table.len(str);
+
+        IndexableBytes {
+            bytes,
+            str,
+            char_table,
+            chars_length,
+        }
+    }
+
+    /// Returns the original bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableBytes;
+    ///
+    /// assert_eq!(IndexableBytes::new(b"0\xFF2").as_bytes(), b"0\xFF2");
+    /// ```
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns a `usize` for the number of `char`s, counting each invalid sequence as one `char`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableBytes;
+    ///
+    /// assert_eq!(IndexableBytes::new(b"0\xE4\xB8\xFF2").len(), 4);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.chars_length
+    }
+
+    /// Returns `true` if there are no bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableBytes;
+    ///
+    /// assert!(IndexableBytes::new(b"").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.chars_length == 0
+    }
+
+    /// Returns the range of bytes that the `char` at `index` was read from.
+    /// # Arguments
+    ///
+    /// * `index` - The `char` index.
+    /// # Panics
+    /// * If the index is greater than or equal to the number of characters.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableBytes;
+    ///
+    /// let s = IndexableBytes::new(b"0\xE4\xB8\xFF2");
+    ///
+    /// assert_eq!(s.byte_range(1), 1..3);
+    /// assert_eq!(s.byte_range(2), 3..4);
+    /// ```
+    pub fn byte_range(&self, index: usize) -> Range<usize> {
+        self.check_index(index);
+
+        self.byte_offset(index)..self.byte_offset(index + 1)
+    }
+
+    /// Returns `true` if the `char` at `index` stands for an invalid sequence, rather than a `U+FFFD` that was in
+    /// the bytes.
+    /// # Arguments
+    ///
+    /// * `index` - The `char` index.
+    /// # Panics
+    /// * If the index is greater than or equal to the number of characters.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableBytes;
+    ///
+    /// let s = IndexableBytes::new("\u{FFFD}".as_bytes());
+    ///
+    /// assert!(!s.is_invalid(0));
+    /// assert!(IndexableBytes::new(b"\xFF").is_invalid(0));
+    /// ```
+    pub fn is_invalid(&self, index: usize) -> bool {
+        self[index] == char::REPLACEMENT_CHARACTER && self.byte_range(index).len() != char::REPLACEMENT_CHARACTER.len_utf8()
+    }
+
+    fn check_index(&self, index: usize) {
+        if let Err(error) = check_index(index, self.chars_length) {
+            panic!("{error}");
+        }
+    }
+
+    fn byte_offset(&self, index: usize) -> usize {
+        match index {
+            _val if self.chars_length == index => self.bytes.len(),
+            _ => self.char_table.byte_offset(index),
+        }
+    }
+
+    fn create_bytes_from_range(&self, start_index: usize, end_index: usize) -> &[u8] {
+        if let Err(error) = check_range(start_index, end_index, self.chars_length) {
+            panic!("{error}");
+        }
+
+        &self.bytes[self.byte_offset(start_index)..self.byte_offset(end_index)]
+    }
+}
+
+/// Returns an entry for every `char` in `bytes`, with a `U+FFFD` entry at the start of each invalid sequence.
+fn lossy_char_offsets<O: Offset>(bytes: &[u8]) -> CharOffsets<O> {
+    let mut entries: CharOffsets<O> = CharOffsets::new();
+    let mut chunk_offset: usize = 0;
+
+    for chunk in bytes.utf8_chunks() {
+        for (offset, chr) in chunk.valid().char_indices() {
+            entries.push(chunk_offset + offset, chr);
+        }
+
+        chunk_offset += chunk.valid().len();
+
+        if !chunk.invalid().is_empty() {
+            entries.push(chunk_offset, char::REPLACEMENT_CHARACTER);
+        }
+
+        chunk_offset += chunk.invalid().len();
+    }
+
+    entries
+}
+
+/// Formats the bytes with each invalid sequence replaced by `U+FFFD`.
+impl<'a> Display for IndexableBytes<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(self.bytes))
+    }
+}
+
+/// # Panics
+/// * If the index is greater than or equal to the number of characters.
+impl<'a> Index<usize> for IndexableBytes<'a> {
+    type Output = char;
+
+    fn index(&self, index: usize) -> &char {
+        self.check_index(index);
+
+        self.char_table.char_ref(self.str, index)
+    }
+}
+
+/// # Panics
+/// * If the range end is greater than the number of characters.
+/// * If the range end is less than the range start.
+impl<'a> Index<Range<usize>> for IndexableBytes<'a> {
+    type Output = [u8];
+
+    fn index(&self, range: Range<usize>) -> &Self::Output {
+        self.create_bytes_from_range(range.start, range.end)
+    }
+}
+
+/// # Panics
+/// * If the range end is less than the range start.
+impl<'a> Index<RangeFrom<usize>> for IndexableBytes<'a> {
+    type Output = [u8];
+
+    fn index(&self, index: RangeFrom<usize>) -> &Self::Output {
+        self.create_bytes_from_range(index.start, self.chars_length)
+    }
+}
+
+/// # Panics
+/// * If the range end is greater than the number of characters.
+impl<'a> Index<RangeTo<usize>> for IndexableBytes<'a> {
+    type Output = [u8];
+
+    fn index(&self, index: RangeTo<usize>) -> &Self::Output {
+        self.create_bytes_from_range(0, index.end)
+    }
+}
+
+/// # Panics
+/// * If the span end is greater than the number of characters.
+/// * If the span end is less than the span start.
+impl<'a> Index<Span> for IndexableBytes<'a> {
+    type Output = [u8];
+
+    fn index(&self, span: Span) -> &Self::Output {
+        self.create_bytes_from_range(span.start, span.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chars_agree_with_from_utf8_lossy() {
+        let inputs: [&[u8]; 6] = [b"", b"0123", "0😀2".as_bytes(), b"\xFF\xFE", b"0\xE4\xB8\xE4\xB8\xAD\xF0\x9F\x98", b"\xC0\x80a\xED\xA0\x80"];
+
+        for bytes in inputs {
+            let s = IndexableBytes::new(bytes);
+            let lossy: Vec<char> = String::from_utf8_lossy(bytes).chars().collect();
+
+            assert_eq!(s.len(), lossy.len());
+
+            let mut rebuilt: Vec<u8> = Vec::new();
+
+            for (index, chr) in lossy.iter().enumerate() {
+                assert_eq!(s[index], *chr);
+                rebuilt.extend_from_slice(&bytes[s.byte_range(index)]);
+            }
+
+            assert_eq!(rebuilt, bytes);
+            assert_eq!(&s[0..], bytes);
+        }
+    }
+
+    #[test]
+    fn test_index_out_of_bounds_panics() {
+        let s = IndexableBytes::new(b"0\xFF");
+
+        let result = std::panic::catch_unwind(|| s[2]);
+        assert!(result.is_err());
+    }
+}
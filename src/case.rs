@@ -0,0 +1,127 @@
+use crate::{
+    edit::{IndexMapping, MappedEdit},
+    IndexableStr, IndexableString, IndexableStringBuilder, Offset, Span,
+};
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns a lowercase copy of the string, along with an `IndexMapping` between the `char` indices of the two
+    /// strings.
+    ///
+    /// Each `char` is mapped on its own with `char::to_lowercase`, so unlike `str::to_lowercase`, a final `'Σ'`
+    /// becomes `'σ'`. A `char` that maps to one `char` keeps its index mapping, while a `char` that maps to several,
+    /// such as `'İ'`, is recorded as a replacement, so use `IndexMapping::to_old_start` to map back into it.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("İD_Ω");
+    /// let (lowercase, mapping) = s.to_lowercase_mapped();
+    ///
+    /// assert_eq!(lowercase.as_str(), "i\u{307}d_ω");
+    /// assert_eq!(mapping.to_new(1), Some(2));
+    /// assert_eq!(mapping.to_old_start(1), 0);
+    /// assert_eq!(mapping.to_old(4), Some(3));
+    /// ```
+    pub fn to_lowercase_mapped(&self) -> (IndexableString, IndexMapping) {
+        self.map_chars(char::to_lowercase)
+    }
+
+    /// Returns an uppercase copy of the string, along with an `IndexMapping` between the `char` indices of the two
+    /// strings. A `char` that maps to several `char`s, such as `'ß'`, is recorded as a replacement. See
+    /// `IndexableStr::to_lowercase_mapped`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("straße!");
+    /// let (uppercase, mapping) = s.to_uppercase_mapped();
+    ///
+    /// assert_eq!(uppercase.as_str(), "STRASSE!");
+    /// assert_eq!(mapping.to_new(6), Some(7));
+    /// assert_eq!(mapping.to_old_start(5), 4);
+    /// ```
+    pub fn to_uppercase_mapped(&self) -> (IndexableString, IndexMapping) {
+        self.map_chars(char::to_uppercase)
+    }
+
+    fn map_chars<I: Iterator<Item = char>>(&self, map: impl Fn(char) -> I) -> (IndexableString, IndexMapping) {
+        let mut builder: IndexableStringBuilder = IndexableStringBuilder::with_capacity(self.str_length);
+        let mut mapping: IndexMapping = IndexMapping::default();
+
+        for (index, chr) in self.str.chars().enumerate() {
+            let new_start: usize = builder.len();
+
+            for mapped in map(chr) {
+                builder.push_char(mapped);
+            }
+
+            if builder.len() != new_start + 1 {
+                mapping.edits.push(MappedEdit {
+                    old: Span::new(index, index + 1),
+                    new: Span::new(new_start, builder.len()),
+                });
+            }
+        }
+
+        (builder.finish(), mapping)
+    }
+}
+
+impl IndexableString {
+    /// Returns a lowercase copy of the string, along with an `IndexMapping` between the `char` indices of the two
+    /// strings. See `IndexableStr::to_lowercase_mapped`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let (lowercase, mapping) = IndexableString::from("ÀB").to_lowercase_mapped();
+    ///
+    /// assert_eq!(lowercase.as_str(), "àb");
+    /// assert!(mapping.is_identity());
+    /// ```
+    pub fn to_lowercase_mapped(&self) -> (IndexableString, IndexMapping) {
+        self.as_indexable_str().to_lowercase_mapped()
+    }
+
+    /// Returns an uppercase copy of the string, along with an `IndexMapping` between the `char` indices of the two
+    /// strings. See `IndexableStr::to_uppercase_mapped`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let (uppercase, mapping) = IndexableString::from("ßa").to_uppercase_mapped();
+    ///
+    /// assert_eq!(uppercase.as_str(), "SSA");
+    /// assert_eq!(mapping.to_old(2), Some(1));
+    /// ```
+    pub fn to_uppercase_mapped(&self) -> (IndexableString, IndexMapping) {
+        self.as_indexable_str().to_uppercase_mapped()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_case_mapping_agrees_with_str_case_mapping() {
+        let s = IndexableStr::new("Straße İstanbul ﬁ 😀 ǅ");
+        let (uppercase, upper_mapping) = s.to_uppercase_mapped();
+        let (lowercase, lower_mapping) = s.to_lowercase_mapped();
+
+        assert_eq!(uppercase.as_str(), s.as_str().to_uppercase());
+        assert_eq!(lowercase.as_str(), s.as_str().to_lowercase());
+
+        for (index, chr) in s.as_str().chars().enumerate() {
+            let new_start: usize = upper_mapping.to_new_start(index);
+
+            assert_eq!(uppercase[new_start], chr.to_uppercase().next().unwrap());
+            assert_eq!(upper_mapping.to_old_start(new_start), index);
+            assert_eq!(lower_mapping.to_old_start(lower_mapping.to_new_start(index)), index);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{unicode, IndexableStr, IndexableString, Offset};
+
+/// `GeneralCategory` is the major class of a `char`'s Unicode General Category property.
+///
+/// The classes are worked out from the `char` predicates in `std` and a few small tables, rather than the full
+/// Unicode Character Database, so they are approximate outside of the common scripts: spacing marks are classed as
+/// letters, and punctuation and unassigned `char`s outside of the punctuation table are classed as symbols. Requires
+/// the `category` feature.
+///
+/// # Examples
+/// ```
+/// use indexable_str::GeneralCategory;
+///
+/// assert_eq!(GeneralCategory::of('é'), GeneralCategory::Letter);
+/// assert_eq!(GeneralCategory::of('\u{301}'), GeneralCategory::Mark);
+/// assert_eq!(GeneralCategory::of('٣'), GeneralCategory::Number);
+/// assert_eq!(GeneralCategory::of('、'), GeneralCategory::Punctuation);
+/// assert_eq!(GeneralCategory::of('+'), GeneralCategory::Symbol);
+/// assert_eq!(GeneralCategory::of('\u{3000}'), GeneralCategory::Separator);
+/// assert_eq!(GeneralCategory::of('\n'), GeneralCategory::Other);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GeneralCategory {
+    /// Letters (L), such as `'a'` and `'中'`.
+    Letter,
+    /// Combining marks (M), such as `'\u{301}'`.
+    Mark,
+    /// Numbers (N), such as `'7'`, `'٣'` and `'Ⅷ'`.
+    Number,
+    /// Punctuation (P), such as `','`, `'('` and `'、'`.
+    Punctuation,
+    /// Symbols (S), such as `'+'`, `'$'` and `'😀'`.
+    Symbol,
+    /// Space separators and the line and paragraph separators (Z).
+    Separator,
+    /// Control, format and private use `char`s (C).
+    Other,
+}
+
+impl GeneralCategory {
+    /// Returns the major general category class of `chr`.
+    /// # Arguments
+    ///
+    /// * `chr` - The `char` to classify.
+    /// # Examples
+    /// ```
+    /// use indexable_str::GeneralCategory;
+    ///
+    /// assert_eq!(GeneralCategory::of('\u{200D}'), GeneralCategory::Other);
+    /// ```
+    pub fn of(chr: char) -> GeneralCategory {
+        match chr {
+            _ if chr.is_control() || unicode::is_format(chr) || ('\u{E000}'..='\u{F8FF}').contains(&chr) => GeneralCategory::Other,
+            _ if unicode::is_mark(chr) => GeneralCategory::Mark,
+            _ if chr.is_numeric() => GeneralCategory::Number,
+            _ if chr.is_alphabetic() => GeneralCategory::Letter,
+            _ if chr.is_whitespace() => GeneralCategory::Separator,
+            _ if unicode::is_punctuation(chr) => GeneralCategory::Punctuation,
+            _ => GeneralCategory::Symbol,
+        }
+    }
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns an iterator over the `char`s of the string with their indices and general category classes, in one
+    /// pass over the text. Requires the `category` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{GeneralCategory, IndexableStr};
+    ///
+    /// let s = IndexableStr::new("x😀=1");
+    /// let categories: Vec<(usize, char, GeneralCategory)> = s.char_categories().collect();
+    ///
+    /// assert_eq!(categories[1], (1, '😀', GeneralCategory::Symbol));
+    /// assert_eq!(categories[3], (3, '1', GeneralCategory::Number));
+    /// ```
+    pub fn char_categories(&self) -> impl Iterator<Item = (usize, char, GeneralCategory)> + 'a {
+        self.str.chars().enumerate().map(|(index, chr)| (index, chr, GeneralCategory::of(chr)))
+    }
+}
+
+impl IndexableString {
+    /// Returns an iterator over the `char`s of the string with their indices and general category classes. See
+    /// `IndexableStr::char_categories`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{GeneralCategory, IndexableString};
+    ///
+    /// let s = IndexableString::from("a,");
+    ///
+    /// assert_eq!(s.char_categories().last(), Some((1, ',', GeneralCategory::Punctuation)));
+    /// ```
+    pub fn char_categories(&self) -> impl Iterator<Item = (usize, char, GeneralCategory)> + '_ {
+        self.as_indexable_str().char_categories()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_categories() {
+        let s = IndexableStr::new("a1 !$\t_~");
+        let categories: Vec<GeneralCategory> = s.char_categories().map(|(_, _, category)| category).collect();
+
+        assert_eq!(
+            categories,
+            vec![
+                GeneralCategory::Letter,
+                GeneralCategory::Number,
+                GeneralCategory::Separator,
+                GeneralCategory::Punctuation,
+                GeneralCategory::Symbol,
+                GeneralCategory::Other,
+                GeneralCategory::Punctuation,
+                GeneralCategory::Symbol,
+            ]
+        );
+    }
+}
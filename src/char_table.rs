@@ -0,0 +1,600 @@
+use std::ops::Range;
+
+use crate::{scan::for_each_char, Offset};
+
+/// Every ASCII `char`, so that a `&char` can be handed out for ASCII strings without storing a table.
+static ASCII_CHARS: [char; 128] = {
+    let mut chars: [char; 128] = ['\0'; 128];
+    let mut i: usize = 0;
+
+    while i < 128 {
+        chars[i] = i as u8 as char;
+        i += 1;
+    }
+
+    chars
+};
+
+/// The most `char`s that a `CharTable` stores inline, without allocating.
+const INLINE_CHARS: usize = 8;
+
+/// `CharOffsets` holds the `char`s of a string and their starting byte offsets. They're kept in separate vectors, so
+/// that an offset narrower than a `char`, such as a `u16`, isn't padded to the size of one.
+#[derive(Clone)]
+pub(crate) struct CharOffsets<O> {
+    chars: Vec<char>,
+    offsets: Vec<O>,
+}
+
+impl<O: Offset> CharOffsets<O> {
+    pub(crate) fn new() -> CharOffsets<O> {
+        CharOffsets::with_capacity(0)
+    }
+
+    fn with_capacity(capacity: usize) -> CharOffsets<O> {
+        CharOffsets {
+            chars: Vec::with_capacity(capacity),
+            offsets: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub(crate) fn push(&mut self, offset: usize, chr: char) {
+        self.chars.push(chr);
+        self.offsets.push(O::from_usize(offset));
+    }
+
+    fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    fn char_ref(&self, index: usize) -> &char {
+        &self.chars[index]
+    }
+
+    fn byte_offset(&self, index: usize) -> usize {
+        self.offsets[index].to_usize()
+    }
+
+    fn char_index(&self, byte_offset: usize) -> Result<usize, usize> {
+        self.offsets.binary_search(&O::from_usize(byte_offset))
+    }
+
+    /// Returns the entries with `usize` offsets, for a string that has grown too long for `O`.
+    fn widen(&self) -> CharOffsets<usize> {
+        CharOffsets {
+            chars: self.chars.clone(),
+            offsets: self.offsets.iter().map(|offset| offset.to_usize()).collect(),
+        }
+    }
+
+    /// Replaces the entries for `chars` with the entries for the replacement, which starts at `byte_start` in `str`,
+    /// and shifts the entries after it by the change in length.
+    fn splice(&mut self, str: &str, chars: Range<usize>, byte_start: usize, old_byte_length: usize, new_byte_length: usize) {
+        let replacement: CharOffsets<O> = char_offsets(&str[byte_start..byte_start + new_byte_length], byte_start);
+        let end: usize = chars.start + replacement.len();
+
+        self.chars.splice(chars.clone(), replacement.chars);
+        self.offsets.splice(chars, replacement.offsets);
+
+        for offset in &mut self.offsets[end..] {
+            *offset = O::from_usize(offset.to_usize() - old_byte_length + new_byte_length);
+        }
+    }
+
+    fn memory_usage(&self) -> usize {
+        self.chars.capacity() * std::mem::size_of::<char>() + self.offsets.capacity() * std::mem::size_of::<O>()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.chars.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+    }
+}
+
+/// `CharTable` maps `char` indices to `char`s and their starting byte offsets in the underlying string.
+///
+/// Pure ASCII strings don't need a table at all, because every `char` is a single byte and the `char` index is the
+/// byte offset. Strings of up to `INLINE_CHARS` `char`s are stored inline, so indexing a short token doesn't
+/// allocate. Strings of up to `O::MAX` bytes store their offsets as an `O`, which for the default `u32` halves the
+/// size of each offset on 64-bit targets. Only larger strings pay for `usize` offsets.
+#[derive(Clone)]
+pub(crate) enum CharTable<O = u32> {
+    Ascii,
+    Inline(InlineChars),
+    Narrow(CharOffsets<O>),
+    Wide(CharOffsets<usize>),
+}
+
+/// `InlineChars` holds the `char`s of a string of up to `INLINE_CHARS` `char`s, which is at most 32 bytes long, so
+/// the offsets fit in a `u8`.
+#[derive(Copy, Clone, Default)]
+pub(crate) struct InlineChars {
+    chars: [char; INLINE_CHARS],
+    offsets: [u8; INLINE_CHARS],
+    len: u8,
+}
+
+impl InlineChars {
+    /// Returns the `char`s of `str`, or `None` if it has more than `INLINE_CHARS` `char`s.
+    fn new(str: &str) -> Option<InlineChars> {
+        if str.len() > INLINE_CHARS * 4 {
+            return None;
+        }
+
+        let mut inline_chars: InlineChars = InlineChars::default();
+
+        for (offset, chr) in str.char_indices() {
+            if inline_chars.len as usize == INLINE_CHARS {
+                return None;
+            }
+
+            inline_chars.push(offset, chr);
+        }
+
+        Some(inline_chars)
+    }
+
+    fn push(&mut self, offset: usize, chr: char) {
+        self.chars[self.len as usize] = chr;
+        self.offsets[self.len as usize] = offset as u8;
+        self.len += 1;
+    }
+
+    fn len(&self) -> usize {
+        self.len as usize
+    }
+}
+
+impl<O: Offset> CharTable<O> {
+    pub(crate) fn new(str: &str) -> CharTable<O> {
+        if str.is_ascii() {
+            return CharTable::Ascii;
+        }
+
+        if let Some(inline_chars) = InlineChars::new(str) {
+            return CharTable::Inline(inline_chars);
+        }
+
+        if str.len() <= O::MAX {
+            return CharTable::Narrow(char_offsets(str, 0));
+        }
+
+        CharTable::Wide(char_offsets(str, 0))
+    }
+
+    /// Returns the same table as `new`, building it on up to `threads` threads.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn new_parallel(str: &str, threads: usize) -> CharTable<O> {
+        if str.is_ascii() {
+            return CharTable::Ascii;
+        }
+
+        if let Some(inline_chars) = InlineChars::new(str) {
+            return CharTable::Inline(inline_chars);
+        }
+
+        if str.len() <= O::MAX {
+            return CharTable::Narrow(parallel_char_offsets(str, threads));
+        }
+
+        CharTable::Wide(parallel_char_offsets(str, threads))
+    }
+
+    /// Returns the table for the concatenation of `pieces`, each of which is a string and the table created from it.
+    ///
+    /// The entries of each piece are copied and shifted by the number of bytes before the piece, so none of the
+    /// `char`s are decoded again.
+    pub(crate) fn concat(pieces: &[(&str, &CharTable<O>)]) -> CharTable<O> {
+        let total_length: usize = pieces.iter().map(|(str, _)| str.len()).sum();
+
+        if pieces.iter().all(|(_, char_table)| matches!(char_table, CharTable::Ascii)) {
+            return CharTable::Ascii;
+        }
+
+        if pieces.iter().map(|(str, char_table)| char_table.len(str)).sum::<usize>() <= INLINE_CHARS {
+            let mut inline_chars: InlineChars = InlineChars::default();
+            let mut piece_start: usize = 0;
+
+            for (str, char_table) in pieces {
+                for index in 0..char_table.len(str) {
+                    inline_chars.push(piece_start + char_table.byte_offset(index), *char_table.char_ref(str, index));
+                }
+
+                piece_start += str.len();
+            }
+
+            return CharTable::Inline(inline_chars);
+        }
+
+        if total_length <= O::MAX {
+            return CharTable::Narrow(concat_char_offsets(pieces));
+        }
+
+        CharTable::Wide(concat_char_offsets(pieces))
+    }
+
+    /// Returns the number of `char`s in `str`, which must be the string the table was created from.
+    pub(crate) fn len(&self, str: &str) -> usize {
+        match self {
+            CharTable::Ascii => str.len(),
+            CharTable::Inline(inline_chars) => inline_chars.len(),
+            CharTable::Narrow(entries) => entries.len(),
+            CharTable::Wide(entries) => entries.len(),
+        }
+    }
+
+    /// Returns the `char` at `index` in `str`, which must be the string the table was created from.
+    ///
+    /// # Panics
+    /// * If `index` is greater than or equal to the number of `char`s in the string.
+    pub(crate) fn char_ref<'s>(&'s self, str: &'s str, index: usize) -> &'s char {
+        match self {
+            CharTable::Ascii => &ASCII_CHARS[str.as_bytes()[index] as usize],
+            CharTable::Inline(inline_chars) => &inline_chars.chars[..inline_chars.len()][index],
+            CharTable::Narrow(entries) => entries.char_ref(index),
+            CharTable::Wide(entries) => entries.char_ref(index),
+        }
+    }
+
+    /// Returns the starting byte offset of the `char` at `index`.
+    ///
+    /// # Panics
+    /// * If `index` is greater than or equal to the number of `char`s in the string.
+    pub(crate) fn byte_offset(&self, index: usize) -> usize {
+        match self {
+            CharTable::Ascii => index,
+            CharTable::Inline(inline_chars) => inline_chars.offsets[..inline_chars.len()][index] as usize,
+            CharTable::Narrow(entries) => entries.byte_offset(index),
+            CharTable::Wide(entries) => entries.byte_offset(index),
+        }
+    }
+
+    /// Searches for the `char` starting at `byte_offset`, which must be less than the length of the string, in the
+    /// same way as `slice::binary_search`. If the byte offset isn't on a `char` boundary, the error holds the index
+    /// of the `char` after the one that contains it.
+    pub(crate) fn char_index(&self, byte_offset: usize) -> Result<usize, usize> {
+        match self {
+            CharTable::Ascii => Ok(byte_offset),
+            CharTable::Inline(inline_chars) => inline_chars.offsets[..inline_chars.len()].binary_search(&(byte_offset as u8)),
+            CharTable::Narrow(entries) => entries.char_index(byte_offset),
+            CharTable::Wide(entries) => entries.char_index(byte_offset),
+        }
+    }
+
+    /// Updates the table after the `char`s in `chars` were replaced. `str` is the edited string, in which the
+    /// replacement text starts at `byte_start` and is `new_byte_length` bytes long, replacing `old_byte_length` bytes.
+    ///
+    /// Only the entries for the replacement are created, while the entries after it are shifted by the change in
+    /// length.
+    pub(crate) fn splice(&mut self, str: &str, chars: Range<usize>, byte_start: usize, old_byte_length: usize, new_byte_length: usize) {
+        let replacement: &str = &str[byte_start..byte_start + new_byte_length];
+
+        match self {
+            CharTable::Ascii if replacement.is_ascii() => (),
+            CharTable::Ascii | CharTable::Inline(_) => *self = CharTable::new(str),
+            CharTable::Narrow(_) if str.len() > O::MAX => *self = CharTable::new(str),
+            CharTable::Narrow(entries) => entries.splice(str, chars, byte_start, old_byte_length, new_byte_length),
+            CharTable::Wide(entries) => entries.splice(str, chars, byte_start, old_byte_length, new_byte_length),
+        }
+    }
+
+    /// Returns the number of bytes allocated on the heap for the table.
+    pub(crate) fn memory_usage(&self) -> usize {
+        match self {
+            CharTable::Ascii | CharTable::Inline(_) => 0,
+            CharTable::Narrow(entries) => entries.memory_usage(),
+            CharTable::Wide(entries) => entries.memory_usage(),
+        }
+    }
+
+    pub(crate) fn shrink_to_fit(&mut self) {
+        match self {
+            CharTable::Ascii | CharTable::Inline(_) => (),
+            CharTable::Narrow(entries) => entries.shrink_to_fit(),
+            CharTable::Wide(entries) => entries.shrink_to_fit(),
+        }
+    }
+}
+
+/// `CharTableBuilder` builds a `CharTable` for a string that is appended to a piece at a time.
+pub(crate) struct CharTableBuilder {
+    char_table: CharTable,
+    indexed_length: usize,
+}
+
+impl CharTableBuilder {
+    pub(crate) fn new() -> CharTableBuilder {
+        CharTableBuilder {
+            char_table: CharTable::Ascii,
+            indexed_length: 0,
+        }
+    }
+
+    /// Indexes the part of `str` that has been appended since the last call. `str` must start with the string that
+    /// has already been indexed.
+    pub(crate) fn extend(&mut self, str: &str) {
+        let appended: &str = &str[self.indexed_length..];
+        let start: usize = self.indexed_length;
+
+        if let CharTable::Ascii = self.char_table {
+            if appended.is_ascii() {
+                self.indexed_length = str.len();
+                return;
+            }
+
+            self.char_table = match str.len() > u32::MAX as usize {
+                true => CharTable::Wide(char_offsets(&str[..start], 0)),
+                false => CharTable::Narrow(char_offsets(&str[..start], 0)),
+            };
+        }
+
+        if let CharTable::Narrow(entries) = &self.char_table {
+            if str.len() > u32::MAX as usize {
+                self.char_table = CharTable::Wide(entries.widen());
+            }
+        }
+
+        match &mut self.char_table {
+            CharTable::Ascii | CharTable::Inline(_) => (),
+            CharTable::Narrow(entries) => for_each_char(appended, |offset, chr| entries.push(start + offset, chr)),
+            CharTable::Wide(entries) => for_each_char(appended, |offset, chr| entries.push(start + offset, chr)),
+        }
+
+        self.indexed_length = str.len();
+    }
+
+    /// Returns the number of `char`s that have been indexed in `str`, which must be the string being built.
+    pub(crate) fn len(&self, str: &str) -> usize {
+        self.char_table.len(&str[..self.indexed_length])
+    }
+
+    pub(crate) fn char_table(&self) -> &CharTable {
+        &self.char_table
+    }
+
+    /// Returns the table, which is stored inline if the string is short enough.
+    pub(crate) fn finish(self) -> CharTable {
+        match self.char_table {
+            CharTable::Narrow(entries) if entries.len() <= INLINE_CHARS => {
+                let mut inline_chars: InlineChars = InlineChars::default();
+
+                for index in 0..entries.len() {
+                    inline_chars.push(entries.byte_offset(index), *entries.char_ref(index));
+                }
+
+                CharTable::Inline(inline_chars)
+            },
+            char_table => char_table,
+        }
+    }
+}
+
+/// Returns the entries for the `char`s of `str`, which starts at byte offset `start` of the string being indexed.
+fn char_offsets<O: Offset>(str: &str, start: usize) -> CharOffsets<O> {
+    let mut entries: CharOffsets<O> = CharOffsets::new();
+
+    for_each_char(str, |offset, chr| entries.push(start + offset, chr));
+
+    entries
+}
+
+fn concat_char_offsets<O: Offset, P: Offset>(pieces: &[(&str, &CharTable<P>)]) -> CharOffsets<O> {
+    let mut entries: CharOffsets<O> = CharOffsets::with_capacity(pieces.iter().map(|(str, char_table)| char_table.len(str)).sum());
+    let mut piece_start: usize = 0;
+
+    for (str, char_table) in pieces {
+        match char_table {
+            CharTable::Ascii => {
+                for (offset, byte) in str.bytes().enumerate() {
+                    entries.push(piece_start + offset, byte as char);
+                }
+            },
+            _ => {
+                for index in 0..char_table.len(str) {
+                    entries.push(piece_start + char_table.byte_offset(index), *char_table.char_ref(str, index));
+                }
+            },
+        }
+
+        piece_start += str.len();
+    }
+
+    entries
+}
+
+/// The smallest number of bytes that's worth handing to a thread of its own.
+#[cfg(feature = "parallel")]
+const MIN_PARALLEL_CHUNK_LENGTH: usize = 1 << 20;
+
+/// Splits `str` into at most `chunks` pieces of roughly equal length, ending each piece on a `char` boundary, and
+/// returns each piece with its starting byte offset.
+#[cfg(feature = "parallel")]
+fn split_at_char_boundaries(str: &str, chunks: usize) -> Vec<(usize, &str)> {
+    let chunk_length: usize = str.len().div_ceil(chunks.max(1)).max(1);
+    let mut pieces: Vec<(usize, &str)> = Vec::new();
+    let mut start: usize = 0;
+
+    while start < str.len() {
+        let mut end: usize = (start + chunk_length).min(str.len());
+
+        while !str.is_char_boundary(end) {
+            end += 1;
+        }
+
+        pieces.push((start, &str[start..end]));
+        start = end;
+    }
+
+    pieces
+}
+
+#[cfg(feature = "parallel")]
+fn parallel_char_offsets<O: Offset>(str: &str, threads: usize) -> CharOffsets<O> {
+    let threads: usize = threads.min(str.len() / MIN_PARALLEL_CHUNK_LENGTH).max(1);
+
+    if threads == 1 {
+        return char_offsets(str, 0);
+    }
+
+    let parts: Vec<CharOffsets<O>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = split_at_char_boundaries(str, threads).into_iter().map(|(start, piece)| {
+            scope.spawn(move || char_offsets(piece, start))
+        }).collect();
+
+        handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+    });
+
+    CharOffsets {
+        chars: parts.iter().flat_map(|part| part.chars.iter().copied()).collect(),
+        offsets: parts.iter().flat_map(|part| part.offsets.iter().copied()).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_non_ascii_string_is_stored_inline() {
+        let str = "0😀2";
+        let char_table: CharTable = CharTable::new(str);
+
+        assert!(matches!(char_table, CharTable::Inline(_)));
+        assert_eq!(char_table.len(str), 3);
+        assert_eq!(*char_table.char_ref(str, 1), '😀');
+        assert_eq!(char_table.byte_offset(2), 5);
+        assert_eq!(char_table.char_index(5), Ok(2));
+        assert_eq!(char_table.char_index(3), Err(2));
+        assert_eq!(char_table.memory_usage(), 0);
+
+        let result = std::panic::catch_unwind(|| *char_table.char_ref(str, 3));
+        assert!(result.is_err());
+
+        assert!(matches!(CharTable::<u32>::new("0😀2345678"), CharTable::Narrow(_)));
+        assert!(matches!(CharTable::concat(&[(str, &char_table), ("😀", &CharTable::new("😀"))]), CharTable::Inline(_)));
+    }
+
+    #[test]
+    fn test_small_non_ascii_string_uses_narrow_offsets() {
+        let str = "0😀2345678";
+        let char_table: CharTable = CharTable::new(str);
+
+        assert!(matches!(char_table, CharTable::Narrow(_)));
+        assert_eq!(char_table.len(str), 9);
+        assert_eq!(char_table.byte_offset(2), 5);
+    }
+
+    #[test]
+    fn test_u16_offsets_are_narrow_until_the_string_is_too_long() {
+        let str = "0😀2345678";
+        let char_table: CharTable<u16> = CharTable::new(str);
+
+        assert!(matches!(char_table, CharTable::Narrow(_)));
+        assert_eq!(char_table.byte_offset(2), 5);
+        assert_eq!(char_table.char_index(5), Ok(2));
+        assert_eq!(char_table.memory_usage() * 4, CharTable::<u32>::new(str).memory_usage() * 3);
+
+        let long: String = "😀".repeat(u16::MAX as usize / 4 + 1);
+        let long_table: CharTable<u16> = CharTable::new(&long);
+
+        assert!(matches!(long_table, CharTable::Wide(_)));
+        assert_eq!(long_table.byte_offset(long.len() / 4 - 1), long.len() - 4);
+    }
+
+    #[test]
+    fn test_wide_offsets_work() {
+        let str = "0😀2";
+        let char_table: CharTable = CharTable::Wide(char_offsets(str, 0));
+
+        assert_eq!(char_table.len(str), 3);
+        assert_eq!(*char_table.char_ref(str, 1), '😀');
+        assert_eq!(char_table.byte_offset(2), 5);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_split_at_char_boundaries_works() {
+        let str = "0😀2345678😀";
+        let pieces = split_at_char_boundaries(str, 4);
+
+        assert!(pieces.len() <= 4);
+        assert_eq!(pieces.iter().map(|(_, piece)| *piece).collect::<String>(), str);
+
+        for (start, piece) in pieces {
+            assert_eq!(&str[start..start + piece.len()], piece);
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_char_offsets_agree_with_char_offsets() {
+        let str: String = "0😀é中a".repeat(MIN_PARALLEL_CHUNK_LENGTH / 4);
+        let expected: CharOffsets<usize> = char_offsets(&str, 0);
+        let actual: CharOffsets<usize> = parallel_char_offsets(&str, 4);
+
+        assert_eq!(actual.chars, expected.chars);
+        assert_eq!(actual.offsets, expected.offsets);
+    }
+
+    #[test]
+    fn test_char_table_builder_agrees_with_new() {
+        let pieces = ["012", "345", "😀", "78", "é中", "", "a"];
+        let mut str: String = String::new();
+        let mut builder = CharTableBuilder::new();
+
+        for piece in pieces {
+            str.push_str(piece);
+            builder.extend(&str);
+        }
+
+        let built = builder.finish();
+        let expected: CharTable = CharTable::new(&str);
+
+        assert!(matches!(built, CharTable::Narrow(_)));
+        assert_eq!(built.len(&str), expected.len(&str));
+
+        for index in 0..expected.len(&str) {
+            assert_eq!(built.char_ref(&str, index), expected.char_ref(&str, index));
+            assert_eq!(built.byte_offset(index), expected.byte_offset(index));
+        }
+    }
+
+    #[test]
+    fn test_char_table_builder_stays_ascii() {
+        let mut builder = CharTableBuilder::new();
+
+        builder.extend("012");
+        builder.extend("012345");
+
+        assert!(matches!(builder.finish(), CharTable::Ascii));
+    }
+
+    #[test]
+    fn test_splice_agrees_with_new() {
+        let edits: [(&str, Range<usize>, &str); 5] = [
+            ("0123456789", 2..4, "ab"),
+            ("0123456789", 2..4, "😀"),
+            ("0😀23456789", 1..2, "é中"),
+            ("0😀23456789", 1..5, ""),
+            ("0😀2345678😀", 10..10, "😀a"),
+        ];
+
+        for (str, chars, replacement) in edits {
+            let mut char_table: CharTable = CharTable::new(str);
+            let byte_start: usize = str.char_indices().nth(chars.start).map_or(str.len(), |(offset, _)| offset);
+            let byte_end: usize = str.char_indices().nth(chars.end).map_or(str.len(), |(offset, _)| offset);
+            let edited: String = format!("{}{}{}", &str[..byte_start], replacement, &str[byte_end..]);
+
+            char_table.splice(&edited, chars, byte_start, byte_end - byte_start, replacement.len());
+
+            let expected: CharTable = CharTable::new(&edited);
+
+            assert_eq!(char_table.len(&edited), expected.len(&edited));
+
+            for index in 0..expected.len(&edited) {
+                assert_eq!(char_table.char_ref(&edited, index), expected.char_ref(&edited, index));
+                assert_eq!(char_table.byte_offset(index), expected.byte_offset(index));
+            }
+        }
+    }
+}
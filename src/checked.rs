@@ -0,0 +1,179 @@
+use std::{borrow::Cow, ops::Range};
+
+use crate::{
+    error::{check_index, check_range},
+    IndexError, IndexableText,
+};
+
+/// `Checked` wraps an indexable string so that only operations that can't panic are available. It has no `Index`
+/// impls and doesn't dereference to the wrapped string, so every out of bounds index or range returns an `IndexError`
+/// instead of panicking.
+///
+/// # Examples
+/// ```
+/// use indexable_str::{Checked, IndexError, IndexableStr};
+///
+/// let s = Checked::new(IndexableStr::new("0😀2"));
+///
+/// assert_eq!(s.char_at(1), Ok('😀'));
+/// assert_eq!(s.char_at(3), Err(IndexError::IndexOutOfBounds { index: 3, len: 3 }));
+/// assert_eq!(s.slice(1..3).as_deref(), Ok("😀2"));
+/// assert_eq!(s.slice(2..4), Err(IndexError::RangeEndOutOfBounds { end: 4, len: 3 }));
+/// ```
+#[derive(Clone)]
+pub struct Checked<T: IndexableText> {
+    text: T,
+}
+
+impl<T: IndexableText> Checked<T> {
+    /// Returns a wrapper around `text`.
+    /// # Arguments
+    ///
+    /// * `text` - The indexable string to wrap.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{Checked, IndexableString};
+    ///
+    /// let s = Checked::new(IndexableString::from("0😀2"));
+    /// ```
+    pub fn new(text: T) -> Checked<T> {
+        Checked { text }
+    }
+
+    /// Returns the wrapped string.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{Checked, IndexableString};
+    ///
+    /// let s = Checked::new(IndexableString::from("0😀2"));
+    ///
+    /// assert_eq!(s.into_inner().as_str(), "0😀2");
+    /// ```
+    pub fn into_inner(self) -> T {
+        self.text
+    }
+
+    /// Returns a `usize` for the number of `char`s in the string.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{Checked, IndexableStr};
+    ///
+    /// assert_eq!(Checked::new(IndexableStr::new("0😀2")).len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.text.char_count()
+    }
+
+    /// Returns `true` if the string contains no `char`s.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{Checked, IndexableStr};
+    ///
+    /// assert!(Checked::new(IndexableStr::new("")).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.text.char_count() == 0
+    }
+
+    /// Returns the `char` at `index`, or `IndexError::IndexOutOfBounds` if the index is greater than or equal to the
+    /// number of `char`s.
+    /// # Arguments
+    ///
+    /// * `index` - The `char` index.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{Checked, IndexableStr};
+    ///
+    /// assert_eq!(Checked::new(IndexableStr::new("0😀2")).char_at(2), Ok('2'));
+    /// ```
+    pub fn char_at(&self, index: usize) -> Result<char, IndexError> {
+        check_index(index, self.text.char_count())?;
+
+        Ok(self.text.char_at(index).unwrap())
+    }
+
+    /// Returns the text of a range of `char`s, or an `IndexError` if the range end is greater than the number of
+    /// `char`s or less than the range start.
+    /// # Arguments
+    ///
+    /// * `range` - The range of `char`s.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{Checked, IndexError, IndexableStr};
+    ///
+    /// let s = Checked::new(IndexableStr::new("0😀2"));
+    ///
+    /// assert_eq!(s.slice(0..2).as_deref(), Ok("0😀"));
+    /// assert_eq!(s.slice(2..1), Err(IndexError::RangeStartAfterEnd { start: 2, end: 1 }));
+    /// ```
+    pub fn slice(&self, range: Range<usize>) -> Result<Cow<'_, str>, IndexError> {
+        check_range(range.start, range.end, self.text.char_count())?;
+
+        Ok(self.text.char_slice(range).unwrap())
+    }
+
+    /// Returns the starting byte offset of the `char` at `index`, or `IndexError::RangeEndOutOfBounds` if the index is
+    /// greater than the number of `char`s. The number of `char`s itself is allowed, as the end of an empty range.
+    /// # Arguments
+    ///
+    /// * `index` - The `char` index.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{Checked, IndexError, IndexableStr};
+    ///
+    /// let s = Checked::new(IndexableStr::new("0😀2"));
+    ///
+    /// assert_eq!(s.byte_offset(2), Ok(5));
+    /// assert_eq!(s.byte_offset(4), Err(IndexError::RangeEndOutOfBounds { end: 4, len: 3 }));
+    /// ```
+    pub fn byte_offset(&self, index: usize) -> Result<usize, IndexError> {
+        check_range(index, index, self.text.char_count())?;
+
+        Ok(self.text.char_to_byte(index).unwrap())
+    }
+
+    /// Returns the index of the `char` that starts at `byte_offset`, or `IndexError::NotCharBoundary` if the byte
+    /// offset isn't on a `char` boundary.
+    /// # Arguments
+    ///
+    /// * `byte_offset` - The byte offset.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{Checked, IndexError, IndexableStr};
+    ///
+    /// let s = Checked::new(IndexableStr::new("0😀2"));
+    ///
+    /// assert_eq!(s.char_index(5), Ok(2));
+    /// assert_eq!(s.char_index(2), Err(IndexError::NotCharBoundary { byte_offset: 2 }));
+    /// ```
+    pub fn char_index(&self, byte_offset: usize) -> Result<usize, IndexError> {
+        self.text.byte_to_char(byte_offset).ok_or(IndexError::NotCharBoundary { byte_offset })
+    }
+}
+
+impl<T: IndexableText> From<T> for Checked<T> {
+    fn from(text: T) -> Self {
+        Checked::new(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IndexableStr;
+
+    #[test]
+    fn test_out_of_bounds_operations_return_errors() {
+        let s = Checked::new(IndexableStr::new("0😀2"));
+        let start: usize = usize::MAX;
+
+        assert_eq!(s.char_at(usize::MAX), Err(IndexError::IndexOutOfBounds { index: usize::MAX, len: 3 }));
+        assert_eq!(s.slice(0..usize::MAX), Err(IndexError::RangeEndOutOfBounds { end: usize::MAX, len: 3 }));
+        assert_eq!(s.slice(start..0), Err(IndexError::RangeStartAfterEnd { start: usize::MAX, end: 0 }));
+        assert_eq!(s.byte_offset(usize::MAX), Err(IndexError::RangeEndOutOfBounds { end: usize::MAX, len: 3 }));
+        assert_eq!(s.char_index(usize::MAX), Err(IndexError::NotCharBoundary { byte_offset: usize::MAX }));
+    }
+}
@@ -0,0 +1,155 @@
+use std::{borrow::Cow, cmp::Ordering};
+
+use crate::{IndexableStr, IndexableString};
+
+/// The text compared by the comparison implementations below.
+trait Text {
+    fn text(&self) -> &str;
+}
+
+impl<'a> Text for IndexableStr<'a> {
+    fn text(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Text for IndexableString {
+    fn text(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Text for str {
+    fn text(&self) -> &str {
+        self
+    }
+}
+
+impl Text for &str {
+    fn text(&self) -> &str {
+        self
+    }
+}
+
+impl Text for String {
+    fn text(&self) -> &str {
+        self
+    }
+}
+
+impl<'a> Text for Cow<'a, str> {
+    fn text(&self) -> &str {
+        self
+    }
+}
+
+/// Implements `PartialEq` and `PartialOrd` in both directions between two types, comparing their text.
+macro_rules! impl_cmp {
+    ($lhs:ty, $rhs:ty) => {
+        impl<'a, 'b> PartialEq<$rhs> for $lhs {
+            fn eq(&self, other: &$rhs) -> bool {
+                self.text() == other.text()
+            }
+        }
+
+        impl<'a, 'b> PartialEq<$lhs> for $rhs {
+            fn eq(&self, other: &$lhs) -> bool {
+                self.text() == other.text()
+            }
+        }
+
+        impl<'a, 'b> PartialOrd<$rhs> for $lhs {
+            fn partial_cmp(&self, other: &$rhs) -> Option<Ordering> {
+                self.text().partial_cmp(other.text())
+            }
+        }
+
+        impl<'a, 'b> PartialOrd<$lhs> for $rhs {
+            fn partial_cmp(&self, other: &$lhs) -> Option<Ordering> {
+                self.text().partial_cmp(other.text())
+            }
+        }
+    };
+}
+
+impl<'a, 'b> PartialEq<IndexableStr<'b>> for IndexableStr<'a> {
+    fn eq(&self, other: &IndexableStr<'b>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<'a> Eq for IndexableStr<'a> {}
+
+impl<'a, 'b> PartialOrd<IndexableStr<'b>> for IndexableStr<'a> {
+    fn partial_cmp(&self, other: &IndexableStr<'b>) -> Option<Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
+
+impl<'a> Ord for IndexableStr<'a> {
+    fn cmp(&self, other: &IndexableStr<'a>) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+/// Only the text is compared, so strings with different anchors or histories are equal if their text is.
+impl PartialEq for IndexableString {
+    fn eq(&self, other: &IndexableString) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for IndexableString {}
+
+impl PartialOrd for IndexableString {
+    fn partial_cmp(&self, other: &IndexableString) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for IndexableString {
+    fn cmp(&self, other: &IndexableString) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl_cmp!(IndexableStr<'a>, IndexableString);
+impl_cmp!(IndexableStr<'a>, str);
+impl_cmp!(IndexableStr<'a>, &'b str);
+impl_cmp!(IndexableStr<'a>, String);
+impl_cmp!(IndexableStr<'a>, Cow<'b, str>);
+impl_cmp!(IndexableString, str);
+impl_cmp!(IndexableString, &'a str);
+impl_cmp!(IndexableString, String);
+impl_cmp!(IndexableString, Cow<'a, str>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comparisons_use_only_the_text() {
+        let owned: String = String::from("0😀2");
+        let s = IndexableStr::new(&owned);
+        let mut string = IndexableString::from("0😀2");
+        let other: IndexableString = IndexableString::from("0😀2");
+        let larger: IndexableString = IndexableString::from("😀");
+
+        string.add_anchor(1, crate::Affinity::Left);
+
+        assert!(s == IndexableStr::new("0😀2"));
+        assert!(s == string);
+        assert!(string == s);
+        assert!(string == other);
+        assert!(s == "0😀2");
+        assert!("0😀2" == s);
+        assert!(string == owned);
+        assert!(owned == string);
+        assert!(s == Cow::Borrowed("0😀2"));
+        assert!(Cow::<str>::Owned(owned.clone()) == string);
+        assert!(s < "0😀3");
+        assert!(larger > s);
+        assert!(string < larger);
+        assert_eq!(s.cmp(&IndexableStr::new("0")), Ordering::Greater);
+    }
+}
@@ -0,0 +1,545 @@
+use std::{
+    fmt::Display,
+    ops::{Index, Range, RangeFrom, RangeTo},
+};
+
+use crate::{
+    bitmap::BoundaryBitmap,
+    error::{check_index, check_range},
+    scan::for_each_char,
+    Span,
+};
+
+/// `OffsetTable` maps `char` indices to the starting byte offsets of the `char`s in the underlying string, without
+/// storing the `char`s themselves.
+#[derive(Clone)]
+enum OffsetTable {
+    Ascii,
+    Narrow(Vec<u32>),
+    Wide(Vec<usize>),
+    Bitmap(BoundaryBitmap),
+    Checkpoints {
+        interval: usize,
+        offsets: Vec<usize>,
+        chars_length: usize,
+    },
+}
+
+impl OffsetTable {
+    fn new(str: &str) -> OffsetTable {
+        if str.is_ascii() {
+            return OffsetTable::Ascii;
+        }
+
+        if str.len() <= u32::MAX as usize {
+            return OffsetTable::Narrow(byte_offsets(str, |offset| offset as u32));
+        }
+
+        OffsetTable::Wide(byte_offsets(str, |offset| offset))
+    }
+
+    fn new_succinct(str: &str) -> OffsetTable {
+        if str.is_ascii() {
+            return OffsetTable::Ascii;
+        }
+
+        OffsetTable::Bitmap(BoundaryBitmap::new(str))
+    }
+
+    fn with_checkpoints(str: &str, interval: usize) -> OffsetTable {
+        if interval == 0 {
+            panic!("Checkpoint interval must be greater than zero!");
+        }
+
+        if str.is_ascii() {
+            return OffsetTable::Ascii;
+        }
+
+        let mut offsets: Vec<usize> = Vec::new();
+        let mut chars_length: usize = 0;
+
+        for (offset, _) in str.char_indices() {
+            if chars_length.is_multiple_of(interval) {
+                offsets.push(offset);
+            }
+
+            chars_length += 1;
+        }
+
+        OffsetTable::Checkpoints {
+            interval,
+            offsets,
+            chars_length,
+        }
+    }
+
+    fn len(&self, str: &str) -> usize {
+        match self {
+            OffsetTable::Ascii => str.len(),
+            OffsetTable::Narrow(offsets) => offsets.len(),
+            OffsetTable::Wide(offsets) => offsets.len(),
+            OffsetTable::Bitmap(bitmap) => bitmap.ones(),
+            OffsetTable::Checkpoints { chars_length, .. } => *chars_length,
+        }
+    }
+
+    fn byte_offset(&self, str: &str, index: usize) -> usize {
+        match self {
+            OffsetTable::Ascii => index,
+            OffsetTable::Narrow(offsets) => offsets[index] as usize,
+            OffsetTable::Wide(offsets) => offsets[index],
+            OffsetTable::Bitmap(bitmap) => bitmap.select(index),
+            OffsetTable::Checkpoints { interval, offsets, .. } => {
+                let checkpoint: usize = offsets[index / interval];
+
+                match str[checkpoint..].char_indices().nth(index % interval) {
+                    Some((offset, _)) => checkpoint + offset,
+                    None => panic!("Index: ({index}) must be less than the number of UTF-8 characters in the string!"),
+                }
+            },
+        }
+    }
+
+    fn memory_usage(&self) -> usize {
+        match self {
+            OffsetTable::Ascii => 0,
+            OffsetTable::Narrow(offsets) => offsets.capacity() * std::mem::size_of::<u32>(),
+            OffsetTable::Wide(offsets) => offsets.capacity() * std::mem::size_of::<usize>(),
+            OffsetTable::Bitmap(bitmap) => bitmap.memory_usage(),
+            OffsetTable::Checkpoints { offsets, .. } => offsets.capacity() * std::mem::size_of::<usize>(),
+        }
+    }
+
+    fn shrink_to_fit(&mut self) {
+        match self {
+            OffsetTable::Ascii => (),
+            OffsetTable::Narrow(offsets) => offsets.shrink_to_fit(),
+            OffsetTable::Wide(offsets) => offsets.shrink_to_fit(),
+            OffsetTable::Bitmap(bitmap) => bitmap.shrink_to_fit(),
+            OffsetTable::Checkpoints { offsets, .. } => offsets.shrink_to_fit(),
+        }
+    }
+
+    /// Returns the index of the `char` starting at `byte_offset`, which must be less than the length of the string.
+    fn char_index(&self, str: &str, byte_offset: usize) -> Option<usize> {
+        match self {
+            OffsetTable::Ascii => Some(byte_offset),
+            OffsetTable::Narrow(offsets) => offsets.binary_search(&(byte_offset as u32)).ok(),
+            OffsetTable::Wide(offsets) => offsets.binary_search(&byte_offset).ok(),
+            OffsetTable::Bitmap(bitmap) => match bitmap.is_set(byte_offset) {
+                true => Some(bitmap.rank(byte_offset)),
+                false => None,
+            },
+            OffsetTable::Checkpoints { interval, offsets, .. } => {
+                if !str.is_char_boundary(byte_offset) {
+                    return None;
+                }
+
+                let checkpoint_index: usize = match offsets.binary_search(&byte_offset) {
+                    Ok(checkpoint_index) => return Some(checkpoint_index * interval),
+                    Err(checkpoint_index) => checkpoint_index - 1,
+                };
+                let checkpoint: usize = offsets[checkpoint_index];
+
+                Some(checkpoint_index * interval + str[checkpoint..byte_offset].chars().count())
+            },
+        }
+    }
+}
+
+fn byte_offsets<O>(str: &str, to_offset: impl Fn(usize) -> O) -> Vec<O> {
+    let mut offsets: Vec<O> = Vec::new();
+
+    for_each_char(str, |offset, _| offsets.push(to_offset(offset)));
+
+    offsets
+}
+
+/// `CompactIndexableStr` is a more memory efficient alternative to `IndexableStr`. It only stores the starting byte
+/// offset of each `char` and decodes the `char` from the underlying string when it's requested, which roughly halves
+/// the memory that the index needs.
+///
+/// For very large strings, `new_succinct` replaces the offsets with a bitmap that marks the first byte of every
+/// `char`, which needs about 0.16 bytes per byte of the string instead of four or eight bytes per `char`.
+///
+/// When many strings are indexed at once, `with_checkpoints` only stores the offset of every Nth `char` and scans
+/// forward from the nearest one.
+///
+/// Because the `char`s aren't stored, `CompactIndexableStr` can't return a reference to one, so it doesn't implement
+/// `Index<usize>`. Use `char_at` instead. Ranges work the same way as they do for `IndexableStr`.
+///
+/// # Examples
+/// ```
+/// use indexable_str::CompactIndexableStr;
+///
+/// let s = CompactIndexableStr::new("0😀2345678😀");
+///
+/// assert_eq!(s.char_at(1), '😀');
+/// assert_eq!(&s[1..9], "😀2345678");
+/// ```
+pub struct CompactIndexableStr<'a> {
+    str: &'a str,
+    str_length: usize,
+    offset_table: OffsetTable,
+    chars_length: usize,
+}
+
+impl<'a> CompactIndexableStr<'a> {
+    /// Returns a compact indexable string.
+    /// # Arguments
+    ///
+    /// * `str` - A string slice to be indexed.
+    /// # Examples
+    /// ```
+    /// use indexable_str::CompactIndexableStr;
+    ///
+    /// let s = CompactIndexableStr::new("0😀2345678😀");
+    /// ```
+    pub fn new(str: &'a str) -> CompactIndexableStr<'a> {
+        let offset_table: OffsetTable = OffsetTable::new(str);
+        let chars_length: usize = offset_table.len(str);
+
+        CompactIndexableStr {
+            str,
+            str_length: str.len(),
+            offset_table,
+            chars_length,
+        }
+    }
+
+    /// Returns a compact indexable string that locates `char`s with a boundary bitmap instead of an offset table.
+    ///
+    /// Finding the byte offset of a `char` and the `char` index of a byte offset both take constant time, but are a
+    /// little slower than they are with the offset table. Pure ASCII strings still don't need any table.
+    /// # Arguments
+    ///
+    /// * `str` - A string slice to be indexed.
+    /// # Examples
+    /// ```
+    /// use indexable_str::CompactIndexableStr;
+    ///
+    /// let s = CompactIndexableStr::new_succinct("0😀2345678😀");
+    ///
+    /// assert_eq!(s.char_at(9), '😀');
+    /// assert_eq!(&s[1..9], "😀2345678");
+    /// ```
+    pub fn new_succinct(str: &'a str) -> CompactIndexableStr<'a> {
+        let offset_table: OffsetTable = OffsetTable::new_succinct(str);
+        let chars_length: usize = offset_table.len(str);
+
+        CompactIndexableStr {
+            str,
+            str_length: str.len(),
+            offset_table,
+            chars_length,
+        }
+    }
+
+    /// Returns a compact indexable string that only stores the byte offset of every `interval`th `char`.
+    ///
+    /// Locating a `char` scans forward from the nearest checkpoint before it, so a larger interval trades lookup
+    /// speed for memory. An interval of 1 stores every offset. Pure ASCII strings still don't need any table.
+    /// # Arguments
+    ///
+    /// * `str` - A string slice to be indexed.
+    /// * `interval` - The number of `char`s between checkpoints.
+    /// # Panics
+    /// * If the interval is zero.
+    /// # Examples
+    /// ```
+    /// use indexable_str::CompactIndexableStr;
+    ///
+    /// let s = CompactIndexableStr::with_checkpoints("0😀2345678😀", 4);
+    ///
+    /// assert_eq!(s.char_at(9), '😀');
+    /// assert_eq!(&s[1..9], "😀2345678");
+    /// ```
+    pub fn with_checkpoints(str: &'a str, interval: usize) -> CompactIndexableStr<'a> {
+        let offset_table: OffsetTable = OffsetTable::with_checkpoints(str, interval);
+        let chars_length: usize = offset_table.len(str);
+
+        CompactIndexableStr {
+            str,
+            str_length: str.len(),
+            offset_table,
+            chars_length,
+        }
+    }
+
+    /// Returns the original string slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::CompactIndexableStr;
+    ///
+    /// let s = CompactIndexableStr::new("0😀2345678😀");
+    ///
+    /// assert_eq!(s.as_str(), "0😀2345678😀");
+    /// ```
+    pub fn as_str(&self) -> &'a str {
+        self.str
+    }
+
+    /// Returns a `usize` for the number of `char`s in the string.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::CompactIndexableStr;
+    ///
+    /// let s = CompactIndexableStr::new("0😀2345678😀");
+    ///
+    /// assert_eq!(s.len(), 10);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.chars_length
+    }
+
+    /// Returns `true` if the string contains no `char`s.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::CompactIndexableStr;
+    ///
+    /// assert!(CompactIndexableStr::new("").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.chars_length == 0
+    }
+
+    /// Returns the `char` at the specified index, decoding it from the underlying string.
+    /// # Arguments
+    ///
+    /// * `index` - The `char` index.
+    /// # Panics
+    /// * If the index is greater than or equal to the number of characters in the string.
+    /// # Examples
+    /// ```
+    /// use indexable_str::CompactIndexableStr;
+    ///
+    /// let s = CompactIndexableStr::new("0😀2345678😀");
+    ///
+    /// assert_eq!(s.char_at(9), '😀');
+    /// ```
+    pub fn char_at(&self, index: usize) -> char {
+        if let Err(error) = check_index(index, self.chars_length) {
+            panic!("{error}");
+        }
+
+        self.str[self.offset_table.byte_offset(self.str, index)..].chars().next().unwrap()
+    }
+
+    /// Returns the index of the `char` that starts at the specified byte offset, or `None` if the byte offset isn't
+    /// on a `char` boundary. The byte offset equal to the length of the string maps to `len()`.
+    /// # Arguments
+    ///
+    /// * `byte_offset` - The byte offset in the underlying string.
+    /// # Examples
+    /// ```
+    /// use indexable_str::CompactIndexableStr;
+    ///
+    /// let s = CompactIndexableStr::new_succinct("0😀2");
+    ///
+    /// assert_eq!(s.char_index(5), Some(2));
+    /// assert_eq!(s.char_index(2), None);
+    /// assert_eq!(s.char_index(6), Some(3));
+    /// ```
+    pub fn char_index(&self, byte_offset: usize) -> Option<usize> {
+        match byte_offset {
+            _val if byte_offset > self.str_length => None,
+            _val if byte_offset == self.str_length => Some(self.chars_length),
+            _ => self.offset_table.char_index(self.str, byte_offset),
+        }
+    }
+
+    /// Returns the number of bytes allocated on the heap for the index. The underlying string isn't included, because
+    /// it's borrowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::CompactIndexableStr;
+    ///
+    /// assert_eq!(CompactIndexableStr::new("0123456789").memory_usage(), 0);
+    /// assert!(CompactIndexableStr::new("0😀2345678😀").memory_usage() >= 40);
+    /// ```
+    pub fn memory_usage(&self) -> usize {
+        self.offset_table.memory_usage()
+    }
+
+    /// Shrinks the allocations of the index as much as possible.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::CompactIndexableStr;
+    ///
+    /// let mut s = CompactIndexableStr::new("0😀2345678😀");
+    ///
+    /// s.shrink_to_fit();
+    ///
+    /// assert_eq!(s.memory_usage(), 40);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.offset_table.shrink_to_fit();
+    }
+
+    pub(crate) fn byte_offset(&self, index: usize) -> usize {
+        match index {
+            _val if self.chars_length == index => self.str_length,
+            _ => self.offset_table.byte_offset(self.str, index),
+        }
+    }
+
+    fn create_str_from_range(&self, start_index: usize, end_index: usize) -> &str {
+        if let Err(error) = check_range(start_index, end_index, self.chars_length) {
+            panic!("{error}");
+        }
+
+        let bytes_start: usize = self.byte_offset(start_index);
+        let bytes_end: usize = self.byte_offset(end_index);
+
+        &self.str[bytes_start..bytes_end]
+    }
+}
+
+impl<'a> Display for CompactIndexableStr<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.str)
+    }
+}
+
+/// # Panics
+/// * If the range end is greater than the number of characters in the string.
+/// * If the range end is less than the range start.
+impl<'a> Index<Range<usize>> for CompactIndexableStr<'a> {
+    type Output = str;
+
+    fn index(&self, range: Range<usize>) -> &Self::Output {
+        self.create_str_from_range(range.start, range.end)
+    }
+}
+
+/// # Panics
+/// * If the range end is less than the range start.
+impl<'a> Index<RangeFrom<usize>> for CompactIndexableStr<'a> {
+    type Output = str;
+
+    fn index(&self, index: RangeFrom<usize>) -> &Self::Output {
+        self.create_str_from_range(index.start, self.chars_length)
+    }
+}
+
+/// # Panics
+/// * If the range end is greater than the number of characters in the string.
+impl<'a> Index<RangeTo<usize>> for CompactIndexableStr<'a> {
+    type Output = str;
+
+    fn index(&self, index: RangeTo<usize>) -> &Self::Output {
+        self.create_str_from_range(0, index.end)
+    }
+}
+
+/// # Panics
+/// * If the span end is greater than the number of characters in the string.
+/// * If the span end is less than the span start.
+impl<'a> Index<Span> for CompactIndexableStr<'a> {
+    type Output = str;
+
+    fn index(&self, span: Span) -> &Self::Output {
+        self.create_str_from_range(span.start, span.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_char_at_works() {
+        let s = CompactIndexableStr::new("0😀23456789");
+
+        assert_eq!(s.char_at(0), '0');
+        assert_eq!(s.char_at(1), '😀');
+        assert_eq!(s.char_at(2), '2');
+    }
+
+    #[test]
+    fn test_char_at_out_of_bounds_panics() {
+        let s = CompactIndexableStr::new("0😀23456789");
+
+        let result = std::panic::catch_unwind(|| s.char_at(10));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_ranges_work() {
+        let s = CompactIndexableStr::new("0😀2345678😀");
+
+        assert_eq!(&s[1..9], "😀2345678");
+        assert_eq!(&s[1..], "😀2345678😀");
+        assert_eq!(&s[..10], "0😀2345678😀");
+        assert_eq!(&s[10..], "");
+    }
+
+    #[test]
+    fn test_ascii_string_does_not_create_an_offset_table() {
+        let s = CompactIndexableStr::new("0123456789");
+
+        assert!(matches!(s.offset_table, OffsetTable::Ascii));
+        assert_eq!(s.char_at(9), '9');
+        assert_eq!(&s[2..5], "234");
+    }
+
+    #[test]
+    fn test_succinct_string_works() {
+        let s = CompactIndexableStr::new_succinct("0😀2345678😀");
+
+        assert!(matches!(s.offset_table, OffsetTable::Bitmap(_)));
+        assert_eq!(s.len(), 10);
+        assert_eq!(s.char_at(1), '😀');
+        assert_eq!(&s[1..], "😀2345678😀");
+        assert_eq!(&s[..0], "");
+    }
+
+    #[test]
+    fn test_checkpoints_work() {
+        let str: String = "0😀é中a".repeat(20);
+        let s = CompactIndexableStr::with_checkpoints(&str, 7);
+
+        assert_eq!(s.len(), 100);
+
+        for (index, (offset, chr)) in str.char_indices().enumerate() {
+            assert_eq!(s.char_at(index), chr);
+            assert_eq!(s.char_index(offset), Some(index));
+        }
+
+        assert_eq!(&s[98..], "中a");
+    }
+
+    #[test]
+    fn test_checkpoints_with_zero_interval_panics() {
+        let result = std::panic::catch_unwind(|| CompactIndexableStr::with_checkpoints("0😀2", 0));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_char_index_works_for_every_table() {
+        let str = "0😀2é";
+
+        for s in [CompactIndexableStr::new(str), CompactIndexableStr::new_succinct(str), CompactIndexableStr::with_checkpoints(str, 2)] {
+            assert_eq!(s.char_index(0), Some(0));
+            assert_eq!(s.char_index(1), Some(1));
+            assert_eq!(s.char_index(3), None);
+            assert_eq!(s.char_index(5), Some(2));
+            assert_eq!(s.char_index(6), Some(3));
+            assert_eq!(s.char_index(8), Some(4));
+            assert_eq!(s.char_index(9), None);
+        }
+    }
+
+    #[test]
+    fn test_range_with_ending_index_too_large() {
+        let s = CompactIndexableStr::new("0😀2345678😀");
+
+        let result = std::panic::catch_unwind(|| s.create_str_from_range(0, 11));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use crate::{IndexableString, Pattern, Span};
+
+/// `DocumentId` is a stable handle to a document in a `Corpus`. It stays valid while the document is replaced, and
+/// isn't reused after the document is removed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DocumentId {
+    id: usize,
+}
+
+/// `Corpus` holds many named, indexed documents, and searches them all with results addressed by `DocumentId` and
+/// the `Span` of `char`s in the document.
+///
+/// # Examples
+/// ```
+/// use indexable_str::{Corpus, Span};
+///
+/// let mut corpus = Corpus::new();
+/// let main = corpus.insert("src/main.rs", "fn main() { run(); }");
+/// let lib = corpus.insert("src/lib.rs", "// 😀\npub fn run() {}");
+///
+/// assert_eq!(corpus.find_all("run"), vec![(main, Span::new(12, 15)), (lib, Span::new(12, 15))]);
+/// assert_eq!(corpus.name(lib), Some("src/lib.rs"));
+/// assert_eq!(&corpus.get(lib).unwrap()[3..4], "😀");
+/// ```
+#[derive(Clone, Default)]
+pub struct Corpus {
+    documents: Vec<Option<(String, IndexableString)>>,
+    ids: HashMap<String, DocumentId>,
+}
+
+impl Corpus {
+    /// Returns an empty corpus.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::Corpus;
+    ///
+    /// assert!(Corpus::new().is_empty());
+    /// ```
+    pub fn new() -> Corpus {
+        Corpus::default()
+    }
+
+    /// Adds a document and returns its id. If there's already a document called `name`, its text is replaced and its
+    /// id is returned.
+    /// # Arguments
+    ///
+    /// * `name` - The name of the document, such as its path.
+    /// * `text` - The text of the document.
+    /// # Examples
+    /// ```
+    /// use indexable_str::Corpus;
+    ///
+    /// let mut corpus = Corpus::new();
+    /// let id = corpus.insert("a.txt", "old");
+    ///
+    /// assert_eq!(corpus.insert("a.txt", "new"), id);
+    /// assert_eq!(corpus.get(id).unwrap().as_str(), "new");
+    /// ```
+    pub fn insert(&mut self, name: impl Into<String>, text: impl Into<IndexableString>) -> DocumentId {
+        let name: String = name.into();
+        let text: IndexableString = text.into();
+
+        if let Some(&id) = self.ids.get(&name) {
+            self.documents[id.id] = Some((name, text));
+
+            return id;
+        }
+
+        let id: DocumentId = DocumentId { id: self.documents.len() };
+
+        self.ids.insert(name.clone(), id);
+        self.documents.push(Some((name, text)));
+
+        id
+    }
+
+    /// Removes a document and returns its text, or `None` if there's no document with the id.
+    /// # Arguments
+    ///
+    /// * `id` - The id of the document.
+    /// # Examples
+    /// ```
+    /// use indexable_str::Corpus;
+    ///
+    /// let mut corpus = Corpus::new();
+    /// let id = corpus.insert("a.txt", "text");
+    ///
+    /// assert_eq!(corpus.remove(id).unwrap().as_str(), "text");
+    /// assert!(corpus.get(id).is_none());
+    /// assert!(corpus.id("a.txt").is_none());
+    /// ```
+    pub fn remove(&mut self, id: DocumentId) -> Option<IndexableString> {
+        let (name, text) = self.documents.get_mut(id.id)?.take()?;
+
+        self.ids.remove(&name);
+
+        Some(text)
+    }
+
+    /// Returns the text of a document, or `None` if there's no document with the id.
+    /// # Arguments
+    ///
+    /// * `id` - The id of the document.
+    pub fn get(&self, id: DocumentId) -> Option<&IndexableString> {
+        self.documents.get(id.id)?.as_ref().map(|(_, text)| text)
+    }
+
+    /// Returns the name of a document, or `None` if there's no document with the id.
+    /// # Arguments
+    ///
+    /// * `id` - The id of the document.
+    pub fn name(&self, id: DocumentId) -> Option<&str> {
+        self.documents.get(id.id)?.as_ref().map(|(name, _)| name.as_str())
+    }
+
+    /// Returns the id of the document called `name`, or `None` if there isn't one.
+    /// # Arguments
+    ///
+    /// * `name` - The name of the document.
+    /// # Examples
+    /// ```
+    /// use indexable_str::Corpus;
+    ///
+    /// let mut corpus = Corpus::new();
+    /// let id = corpus.insert("a.txt", "text");
+    ///
+    /// assert_eq!(corpus.id("a.txt"), Some(id));
+    /// ```
+    pub fn id(&self, name: &str) -> Option<DocumentId> {
+        self.ids.get(name).copied()
+    }
+
+    /// Returns the number of documents.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns `true` if the corpus has no documents.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Returns an iterator over the documents in the order they were added, with their ids and names.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::Corpus;
+    ///
+    /// let mut corpus = Corpus::new();
+    ///
+    /// corpus.insert("a.txt", "1");
+    /// corpus.insert("b.txt", "2");
+    ///
+    /// assert_eq!(corpus.iter().map(|(_, name, _)| name).collect::<Vec<&str>>(), vec!["a.txt", "b.txt"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (DocumentId, &str, &IndexableString)> + '_ {
+        self.documents
+            .iter()
+            .enumerate()
+            .filter_map(|(id, document)| document.as_ref().map(|(name, text)| (DocumentId { id }, name.as_str(), text)))
+    }
+
+    /// Returns the non-overlapping matches of `pattern` in every document, in the order the documents were added and
+    /// then by position, as the document id and the `Span` of `char`s of each match.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure to look for.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{Corpus, Span};
+    ///
+    /// let mut corpus = Corpus::new();
+    /// let id = corpus.insert("a.txt", "😀x😀");
+    ///
+    /// assert_eq!(corpus.find_all('😀'), vec![(id, Span::new(0, 1)), (id, Span::new(2, 3))]);
+    /// ```
+    pub fn find_all<P: Pattern + Clone>(&self, pattern: P) -> Vec<(DocumentId, Span)> {
+        let mut matches: Vec<(DocumentId, Span)> = Vec::new();
+
+        for (id, _, text) in self.iter() {
+            let str = text.as_indexable_str();
+
+            for range in pattern.clone().byte_matches(text.as_str()) {
+                let start: usize = str.char_index_at_byte(range.start).unwrap();
+                let end: usize = str.char_index_at_byte(range.end).unwrap();
+
+                matches.push((id, Span::new(start, end)));
+            }
+        }
+
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ids_are_stable_across_removal_and_replacement() {
+        let mut corpus = Corpus::new();
+        let a = corpus.insert("a", "x😀x");
+        let b = corpus.insert("b", "xx");
+
+        assert!(corpus.remove(a).is_some());
+        assert!(corpus.remove(a).is_none());
+
+        let c = corpus.insert("a", "😀x");
+
+        assert_ne!(a, c);
+        assert_eq!(corpus.insert("b", "x"), b);
+        assert_eq!(corpus.len(), 2);
+        assert_eq!(corpus.find_all("x"), vec![(b, Span::new(0, 1)), (c, Span::new(1, 2))]);
+    }
+}
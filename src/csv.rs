@@ -0,0 +1,198 @@
+use std::{borrow::Cow, iter::Peekable, str::CharIndices};
+
+use crate::{IndexableStr, IndexableString, Offset, Span};
+
+/// `CsvField` is one field of CSV text: its decoded value, the `Span` of `char`s it was read from, including any
+/// quotes, and the record and column it's in, both counted from 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvField<'a> {
+    pub value: Cow<'a, str>,
+    pub span: Span,
+    pub record: usize,
+    pub column: usize,
+}
+
+/// Reads the fields of RFC 4180 CSV text one at a time.
+struct CsvFields<'a> {
+    str: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+    index: usize,
+    record: usize,
+    column: usize,
+    after_comma: bool,
+}
+
+impl<'a> CsvFields<'a> {
+    fn byte_offset(&mut self) -> usize {
+        match self.chars.peek() {
+            Some(&(offset, _)) => offset,
+            None => self.str.len(),
+        }
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let (_, chr) = self.chars.next()?;
+
+        self.index += 1;
+
+        Some(chr)
+    }
+
+    fn at_field_end(&mut self) -> bool {
+        matches!(self.chars.peek(), None | Some((_, ',' | '\n' | '\r')))
+    }
+
+    /// Reads the rest of a field that started with a quote, up to the next comma or line break.
+    fn quoted_value(&mut self, start_byte: usize) -> Cow<'a, str> {
+        self.next_char();
+
+        let mut escaped: bool = false;
+
+        let value_end: usize = loop {
+            let offset: usize = self.byte_offset();
+
+            match self.next_char() {
+                None => break offset,
+                Some('"') if matches!(self.chars.peek(), Some((_, '"'))) => {
+                    self.next_char();
+                    escaped = true;
+                },
+                Some('"') => break offset,
+                Some(_) => (),
+            }
+        };
+
+        let mut value: Cow<'a, str> = match escaped {
+            true => Cow::Owned(self.str[start_byte + 1..value_end].replace("\"\"", "\"")),
+            false => Cow::Borrowed(&self.str[start_byte + 1..value_end]),
+        };
+
+        while !self.at_field_end() {
+            value.to_mut().push(self.next_char().unwrap());
+        }
+
+        value
+    }
+}
+
+impl<'a> Iterator for CsvFields<'a> {
+    type Item = CsvField<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.chars.peek().is_none() && !self.after_comma {
+            return None;
+        }
+
+        let start: usize = self.index;
+        let start_byte: usize = self.byte_offset();
+
+        let value: Cow<'a, str> = match self.chars.peek() {
+            Some((_, '"')) => self.quoted_value(start_byte),
+            _ => {
+                while !self.at_field_end() {
+                    self.next_char();
+                }
+
+                Cow::Borrowed(&self.str[start_byte..self.byte_offset()])
+            },
+        };
+
+        let field: CsvField<'a> = CsvField {
+            value,
+            span: Span::new(start, self.index),
+            record: self.record,
+            column: self.column,
+        };
+
+        self.after_comma = false;
+
+        match self.next_char() {
+            Some(',') => {
+                self.after_comma = true;
+                self.column += 1;
+            },
+            Some(chr) => {
+                if chr == '\r' && matches!(self.chars.peek(), Some((_, '\n'))) {
+                    self.next_char();
+                }
+
+                self.record += 1;
+                self.column = 0;
+            },
+            None => (),
+        }
+
+        Some(field)
+    }
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns an iterator over the fields of RFC 4180 CSV text. Quoted fields may contain commas, line breaks and
+    /// doubled quotes, which are decoded in the field's value. Records end with `\n` or `\r\n`, and a line break at
+    /// the end of the text doesn't start another record. Text after the closing quote of a field is kept in its
+    /// value instead of being rejected, as is an unterminated quoted field. Requires the `csv` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("name,note\r\n😀,\"say \"\"hi\"\",\nbye\"\r\n");
+    /// let fields: Vec<_> = s.csv_fields().collect();
+    ///
+    /// assert_eq!(fields.len(), 4);
+    /// assert_eq!(fields[3].value, "say \"hi\",\nbye");
+    /// assert_eq!(fields[3].span, Span::new(13, 30));
+    /// assert_eq!((fields[3].record, fields[3].column), (1, 1));
+    /// ```
+    pub fn csv_fields(&self) -> impl Iterator<Item = CsvField<'a>> + 'a {
+        CsvFields {
+            str: self.str,
+            chars: self.str.char_indices().peekable(),
+            index: 0,
+            record: 0,
+            column: 0,
+            after_comma: false,
+        }
+    }
+}
+
+impl IndexableString {
+    /// Returns an iterator over the fields of RFC 4180 CSV text. Requires the `csv` feature. See
+    /// `IndexableStr::csv_fields`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("a,😀\n");
+    ///
+    /// assert_eq!(s.csv_fields().map(|field| field.value).collect::<Vec<_>>(), vec!["a", "😀"]);
+    /// ```
+    pub fn csv_fields(&self) -> impl Iterator<Item = CsvField<'_>> + '_ {
+        self.as_indexable_str().csv_fields()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records_columns_and_empty_fields() {
+        let s = IndexableStr::new("a,,\"\"\n\"x\"y,\"open\n");
+        let fields: Vec<(String, usize, usize)> = s.csv_fields().map(|field| (field.value.into_owned(), field.record, field.column)).collect();
+
+        assert_eq!(
+            fields,
+            vec![
+                ("a".to_string(), 0, 0),
+                ("".to_string(), 0, 1),
+                ("".to_string(), 0, 2),
+                ("xy".to_string(), 1, 0),
+                ("open\n".to_string(), 1, 1),
+            ]
+        );
+        assert_eq!(IndexableStr::new("").csv_fields().count(), 0);
+        assert_eq!(IndexableStr::new("a,").csv_fields().count(), 2);
+    }
+}
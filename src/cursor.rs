@@ -0,0 +1,442 @@
+use std::{borrow::Cow, error::Error, fmt::Display};
+
+use crate::{IndexableStr, IndexableString, Offset, Pattern, Position, Span};
+
+/// `Expected` is what a `Cursor::expect` method was looking for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Expected {
+    Char(char),
+    Str(String),
+    /// A description of the `char`s a predicate accepts, such as `"a digit"`.
+    Description(String),
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Char(chr) => write!(f, "{chr:?}"),
+            Expected::Str(str) => write!(f, "{str:?}"),
+            Expected::Description(description) => write!(f, "{description}"),
+        }
+    }
+}
+
+/// `ExpectError` is returned by the `Cursor::expect` methods when the text at the cursor isn't what was expected. It
+/// holds the `Position` of the cursor, what was expected, and the text that was found instead, which is `None` at
+/// the end of the string. Nothing is consumed when an error is returned.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExpectError {
+    pub position: Position,
+    pub expected: Expected,
+    pub found: Option<String>,
+}
+
+impl ExpectError {
+    /// Returns the `Span` of the text that was found instead of what was expected. It's empty at the end of the
+    /// string.
+    pub fn span(&self) -> Span {
+        let found_length: usize = self.found.as_ref().map_or(0, |found| found.chars().count());
+
+        Span::new(self.position.index, self.position.index + found_length)
+    }
+}
+
+impl Display for ExpectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Position { line, column, .. } = self.position;
+
+        match &self.found {
+            Some(found) => write!(f, "Expected {} at line ({line}), column ({column}), but found {found:?}!", self.expected),
+            None => write!(f, "Expected {} at line ({line}), column ({column}), but found the end of the string!", self.expected),
+        }
+    }
+}
+
+impl Error for ExpectError {}
+
+/// `Cursor` walks an indexable string from the start, consuming `char`s and reporting the `Span` of what it
+/// consumed, so a lexer can be written without keeping track of byte offsets or `char` indices itself.
+///
+/// # Examples
+/// ```
+/// use indexable_str::{IndexableStr, Span};
+///
+/// let s = IndexableStr::new("let 😀 = \"a b\";");
+/// let mut cursor = s.cursor();
+///
+/// assert_eq!(cursor.take_while(char::is_alphabetic), Span::new(0, 3));
+/// cursor.take_until('"');
+/// cursor.advance();
+///
+/// let literal = cursor.take_until('"');
+///
+/// assert_eq!(&s[literal], "a b");
+/// assert_eq!(cursor.index(), 12);
+/// ```
+pub struct Cursor<'a, O: Offset = u32> {
+    str: IndexableStr<'a, O>,
+    index: usize,
+    byte_offset: usize,
+    line_column: Option<(usize, usize)>,
+}
+
+impl<'a, O: Offset> Cursor<'a, O> {
+    fn new(str: IndexableStr<'a, O>) -> Cursor<'a, O> {
+        Cursor {
+            str,
+            index: 0,
+            byte_offset: 0,
+            line_column: None,
+        }
+    }
+
+    /// Returns the cursor with line and column tracking turned on, so that `position` takes O(1) time instead of
+    /// scanning the string from the start.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Position};
+    ///
+    /// let s = IndexableStr::new("a\r\n😀 b");
+    /// let mut cursor = s.cursor().track_lines();
+    ///
+    /// cursor.take_until('b');
+    ///
+    /// assert_eq!(cursor.position(), Position { index: 5, line: 1, column: 2 });
+    /// ```
+    pub fn track_lines(mut self) -> Cursor<'a, O> {
+        if self.line_column.is_none() {
+            let position: Position = self.position();
+
+            self.line_column = Some((position.line, position.column));
+        }
+
+        self
+    }
+
+    /// Returns the `Position` of the next `char` to be consumed. Unless line tracking was turned on with
+    /// `track_lines`, this scans the string from the start.
+    pub fn position(&self) -> Position {
+        match self.line_column {
+            Some((line, column)) => Position { index: self.index, line, column },
+            None => self.str.position_at(self.index).unwrap(),
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.str.str[self.byte_offset..]
+    }
+
+    /// Consumes the next `byte_length` bytes, which end on a `char` boundary.
+    fn consume(&mut self, byte_length: usize) -> Span {
+        let start: usize = self.index;
+        let consumed: &str = &self.rest()[..byte_length];
+
+        match &mut self.line_column {
+            Some((line, column)) => {
+                for chr in consumed.chars() {
+                    self.index += 1;
+
+                    match chr {
+                        '\n' => {
+                            *line += 1;
+                            *column = 0;
+                        },
+                        _ => *column += 1,
+                    }
+                }
+            },
+            None => self.index += consumed.chars().count(),
+        }
+
+        self.byte_offset += byte_length;
+
+        Span::new(start, self.index)
+    }
+
+    /// Returns the `char` index of the next `char` to be consumed.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns `true` if every `char` has been consumed.
+    pub fn is_at_end(&self) -> bool {
+        self.byte_offset == self.str.str_length
+    }
+
+    /// Returns the next `char` without consuming it, or `None` at the end of the string.
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Returns an iterator over the next `n` `char`s without consuming them. There are fewer of them near the end of
+    /// the string.
+    /// # Arguments
+    ///
+    /// * `n` - The number of `char`s to look ahead.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("😀<<");
+    /// let mut cursor = s.cursor();
+    ///
+    /// cursor.advance();
+    ///
+    /// assert_eq!(cursor.peek_n(3).collect::<Vec<char>>(), vec!['<', '<']);
+    /// assert_eq!(cursor.index(), 1);
+    /// ```
+    pub fn peek_n(&self, n: usize) -> impl Iterator<Item = char> + 'a {
+        self.rest().chars().take(n)
+    }
+
+    /// Returns the text of the next `len` `char`s without consuming them, or of the rest of the string if it's
+    /// shorter.
+    /// # Arguments
+    ///
+    /// * `len` - The number of `char`s to look ahead.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("x <<= 😀");
+    /// let mut cursor = s.cursor();
+    ///
+    /// cursor.take_until('<');
+    ///
+    /// let operator: &str = match cursor.peek_slice(3) {
+    ///     "<<=" => "shift assign",
+    ///     slice if slice.starts_with("<<") => "shift",
+    ///     _ => "less than",
+    /// };
+    ///
+    /// assert_eq!(operator, "shift assign");
+    /// assert_eq!(cursor.peek_slice(10), "<<= 😀");
+    /// ```
+    pub fn peek_slice(&self, len: usize) -> &'a str {
+        let rest: &'a str = self.rest();
+
+        match rest.char_indices().nth(len) {
+            Some((byte_length, _)) => &rest[..byte_length],
+            None => rest,
+        }
+    }
+
+    /// Consumes and returns the next `char`, or returns `None` at the end of the string.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("😀");
+    /// let mut cursor = s.cursor();
+    ///
+    /// assert_eq!(cursor.advance(), Some('😀'));
+    /// assert_eq!(cursor.advance(), None);
+    /// ```
+    pub fn advance(&mut self) -> Option<char> {
+        let chr: char = self.peek()?;
+
+        self.consume(chr.len_utf8());
+
+        Some(chr)
+    }
+
+    /// Consumes `char`s while `predicate` returns `true`, and returns the `Span` of the `char`s consumed.
+    /// # Arguments
+    ///
+    /// * `predicate` - A closure that returns `true` for the `char`s to consume.
+    pub fn take_while(&mut self, mut predicate: impl FnMut(char) -> bool) -> Span {
+        let rest: &str = self.rest();
+        let byte_length: usize = rest.find(|chr: char| !predicate(chr)).unwrap_or(rest.len());
+
+        self.consume(byte_length)
+    }
+
+    /// Consumes `char`s up to, but not including, the first match of `pattern`, or to the end of the string if there
+    /// isn't one, and returns the `Span` of the `char`s consumed.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure to stop at.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("/* 😀 */ x");
+    /// let mut cursor = s.cursor();
+    ///
+    /// assert_eq!(cursor.take_until("*/"), Span::new(0, 5));
+    /// assert_eq!(cursor.take_until("*/"), Span::new(5, 5));
+    /// assert_eq!(cursor.take_until("/*"), Span::new(5, 9));
+    /// assert!(cursor.is_at_end());
+    /// ```
+    pub fn take_until<P: Pattern>(&mut self, pattern: P) -> Span {
+        let rest: &str = self.rest();
+        let byte_length: usize = pattern.byte_matches(rest).next().map_or(rest.len(), |range| range.start);
+
+        self.consume(byte_length)
+    }
+
+    /// Consumes `chr` if it's the next `char`, and returns its `Span`.
+    /// # Arguments
+    ///
+    /// * `chr` - The expected `char`.
+    /// # Errors
+    /// * If the next `char` isn't `chr`, or the cursor is at the end of the string.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{Expected, IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("😀;x");
+    /// let mut cursor = s.cursor();
+    ///
+    /// cursor.advance();
+    ///
+    /// assert_eq!(cursor.expect_char(';'), Ok(Span::new(1, 2)));
+    ///
+    /// let error = cursor.expect_char(';').err().unwrap();
+    ///
+    /// assert_eq!(error.expected, Expected::Char(';'));
+    /// assert_eq!(error.found.as_deref(), Some("x"));
+    /// assert_eq!(error.to_string(), "Expected ';' at line (0), column (2), but found \"x\"!");
+    /// ```
+    pub fn expect_char(&mut self, chr: char) -> Result<Span, ExpectError> {
+        match self.peek() == Some(chr) {
+            true => Ok(self.consume(chr.len_utf8())),
+            false => Err(self.expect_error(Expected::Char(chr), 1)),
+        }
+    }
+
+    /// Consumes `str` if the text at the cursor starts with it, and returns its `Span`.
+    /// # Arguments
+    ///
+    /// * `str` - The expected text.
+    /// # Errors
+    /// * If the text at the cursor doesn't start with `str`. The error holds as many `char`s as `str` has.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("<=😀");
+    /// let mut cursor = s.cursor();
+    ///
+    /// assert_eq!(cursor.expect_str("<<=").err().unwrap().found.as_deref(), Some("<=😀"));
+    /// assert!(cursor.expect_str("<=").is_ok());
+    /// ```
+    pub fn expect_str(&mut self, str: &str) -> Result<Span, ExpectError> {
+        match self.rest().starts_with(str) {
+            true => Ok(self.consume(str.len())),
+            false => Err(self.expect_error(Expected::Str(str.to_string()), str.chars().count())),
+        }
+    }
+
+    /// Consumes `char`s while `predicate` returns `true`, and returns their `Span` if there was at least one.
+    /// # Arguments
+    ///
+    /// * `description` - A description of the `char`s `predicate` accepts, for the error.
+    /// * `predicate` - A closure that returns `true` for the `char`s to consume.
+    /// # Errors
+    /// * If `predicate` returns `false` for the next `char`, or the cursor is at the end of the string.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Position, Span};
+    ///
+    /// let s = IndexableStr::new("x = \n😀");
+    /// let mut cursor = s.cursor().track_lines();
+    ///
+    /// cursor.take_until('\n');
+    /// cursor.advance();
+    ///
+    /// let error = cursor.expect_while("a digit", |chr| chr.is_ascii_digit()).err().unwrap();
+    ///
+    /// assert_eq!(error.position, Position { index: 5, line: 1, column: 0 });
+    /// assert_eq!(error.span(), Span::new(5, 6));
+    /// ```
+    pub fn expect_while(&mut self, description: &str, mut predicate: impl FnMut(char) -> bool) -> Result<Span, ExpectError> {
+        match self.peek() {
+            Some(chr) if predicate(chr) => Ok(self.take_while(predicate)),
+            _ => Err(self.expect_error(Expected::Description(description.to_string()), 1)),
+        }
+    }
+
+    fn expect_error(&self, expected: Expected, found_length: usize) -> ExpectError {
+        ExpectError {
+            position: self.position(),
+            expected,
+            found: match self.is_at_end() {
+                true => None,
+                false => Some(self.peek_slice(found_length).to_string()),
+            },
+        }
+    }
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns a `Cursor` at the start of the string.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("😀");
+    ///
+    /// assert_eq!(s.cursor().peek(), Some('😀'));
+    /// ```
+    pub fn cursor(&self) -> Cursor<'_, O> {
+        Cursor::new(IndexableStr {
+            str: self.str,
+            str_length: self.str_length,
+            char_table: Cow::Borrowed(&self.char_table),
+            chars_length: self.chars_length,
+            graphemes: Cow::Borrowed(&self.graphemes),
+        })
+    }
+}
+
+impl IndexableString {
+    /// Returns a `Cursor` at the start of the string. See `IndexableStr::cursor`.
+    pub fn cursor(&self) -> Cursor<'_> {
+        Cursor::new(self.as_indexable_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spans_of_consumed_text_cover_the_string() {
+        let s = IndexableStr::new("ab😀 12\n😀😀");
+        let mut cursor = s.cursor();
+        let mut spans: Vec<Span> = Vec::new();
+
+        while !cursor.is_at_end() {
+            spans.push(cursor.take_while(|chr| chr.is_alphabetic()));
+            spans.push(cursor.take_until(char::is_alphabetic));
+        }
+
+        assert_eq!(spans.iter().map(|span| span.len()).sum::<usize>(), s.len());
+        assert!(spans.windows(2).all(|pair| pair[0].end == pair[1].start));
+        assert_eq!(&s[spans[1]], "😀 12\n😀😀");
+        assert_eq!(cursor.advance(), None);
+        assert_eq!(cursor.take_until('x'), Span::new(9, 9));
+    }
+
+    #[test]
+    fn test_tracked_positions_agree_with_position_at() {
+        let s = IndexableStr::new("0😀\n3\r\n6\n\n😀");
+        let mut cursor = s.cursor();
+
+        cursor.advance();
+
+        let mut cursor = cursor.track_lines();
+
+        loop {
+            assert_eq!(Some(cursor.position()), s.position_at(cursor.index()));
+
+            if cursor.advance().is_none() {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{error::check_index, IndexableStr, IndexableString, Offset};
+
+/// Returns the `char` that matches `delimiter`, and whether it comes after `delimiter`, or `None` if `delimiter`
+/// isn't a bracket or a quote.
+fn matching_delimiter(delimiter: char) -> Option<(char, bool)> {
+    match delimiter {
+        '(' => Some((')', true)),
+        '[' => Some((']', true)),
+        '{' => Some(('}', true)),
+        '<' => Some(('>', true)),
+        ')' => Some(('(', false)),
+        ']' => Some(('[', false)),
+        '}' => Some(('{', false)),
+        '>' => Some(('<', false)),
+        '"' | '\'' | '`' => Some((delimiter, true)),
+        _ => None,
+    }
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns the `char` index of the delimiter that matches the one at `index`, or `None` if the `char` at `index`
+    /// isn't a delimiter or isn't matched.
+    ///
+    /// Opening brackets `(`, `[`, `{` and `<` are matched with the next closing bracket of the same kind that isn't
+    /// closing a nested pair, and closing brackets with the opening bracket before them in the same way. Quotes `"`,
+    /// `'` and `` ` `` are matched with the next quote of the same kind, because quotes don't nest. When there's an
+    /// `escape` `char`, a delimiter after it is ignored, as is an escape `char` after another one. Brackets between
+    /// quotes aren't skipped.
+    /// # Arguments
+    ///
+    /// * `index` - The `char` index of a delimiter.
+    /// * `escape` - The `char` that escapes the `char` after it, such as `\`, or `None` if nothing is escaped.
+    /// # Panics
+    /// * If `index` is greater than or equal to the number of characters in the string.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new(r#"f(x[0], "😀\"b", g())"#);
+    ///
+    /// assert_eq!(s.find_matching(1, None), Some(19));
+    /// assert_eq!(s.find_matching(19, None), Some(1));
+    /// assert_eq!(s.find_matching(3, None), Some(5));
+    /// assert_eq!(s.find_matching(8, Some('\\')), Some(13));
+    /// assert_eq!(s.find_matching(8, None), Some(11));
+    /// assert_eq!(s.find_matching(0, None), None);
+    /// ```
+    pub fn find_matching(&self, index: usize, escape: Option<char>) -> Option<usize> {
+        if let Err(error) = check_index(index, self.chars_length) {
+            panic!("{error}");
+        }
+
+        let delimiter: char = self[index];
+        let (matching, forward): (char, bool) = matching_delimiter(delimiter)?;
+        let mut escaped: bool = false;
+
+        if forward {
+            let mut depth: usize = 0;
+
+            for (offset, chr) in self.str[self.byte_offset(index + 1)..].chars().enumerate() {
+                match chr {
+                    _ if escaped => escaped = false,
+                    chr if Some(chr) == escape => escaped = true,
+                    chr if chr == matching && depth == 0 => return Some(index + 1 + offset),
+                    chr if chr == matching => depth -= 1,
+                    chr if chr == delimiter => depth += 1,
+                    _ => (),
+                }
+            }
+
+            return None;
+        }
+
+        let mut openings: Vec<usize> = Vec::new();
+
+        for (offset, chr) in self.str[..self.byte_offset(index)].chars().enumerate() {
+            match chr {
+                _ if escaped => escaped = false,
+                chr if Some(chr) == escape => escaped = true,
+                chr if chr == matching => openings.push(offset),
+                chr if chr == delimiter => {
+                    openings.pop();
+                },
+                _ => (),
+            }
+        }
+
+        match escaped {
+            true => None,
+            false => openings.pop(),
+        }
+    }
+}
+
+impl IndexableString {
+    /// Returns the `char` index of the delimiter that matches the one at `index`. See `IndexableStr::find_matching`.
+    /// # Arguments
+    ///
+    /// * `index` - The `char` index of a delimiter.
+    /// * `escape` - The `char` that escapes the `char` after it, such as `\`, or `None` if nothing is escaped.
+    /// # Panics
+    /// * If `index` is greater than or equal to the number of characters in the string.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("{😀 {} }");
+    ///
+    /// assert_eq!(s.find_matching(0, None), Some(6));
+    /// ```
+    pub fn find_matching(&self, index: usize, escape: Option<char>) -> Option<usize> {
+        self.as_indexable_str().find_matching(index, escape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_matching_round_trips_nested_brackets() {
+        let s = IndexableStr::new("([😀{}]) {[(] \\)");
+
+        for (open, close) in [(0, 6), (1, 5), (3, 4), (9, 11)] {
+            assert_eq!(s.find_matching(open, None), Some(close));
+            assert_eq!(s.find_matching(close, None), Some(open));
+        }
+
+        assert_eq!(s.find_matching(8, None), None);
+        assert_eq!(s.find_matching(10, Some('\\')), None);
+        assert_eq!(s.find_matching(10, None), Some(14));
+        assert_eq!(s.find_matching(14, Some('\\')), None);
+        assert_eq!(s.find_matching(14, None), Some(10));
+
+        let result = std::panic::catch_unwind(|| s.find_matching(15, None));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,338 @@
+use crate::{IndexableStr, IndexableString, Offset, Span};
+
+/// `DiffKind` is what a `DiffHunk` does to the old string.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DiffKind {
+    /// The `char`s are in both strings.
+    Equal,
+    /// The `char`s are only in the old string.
+    Delete,
+    /// The `char`s are only in the new string.
+    Insert,
+}
+
+/// `DiffHunk` is a run of `char`s that were kept, deleted or inserted. `old` and `new` are the `char` ranges of the
+/// run in each string. The range in the string that doesn't contain the run is empty, and marks where it would be.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DiffHunk {
+    pub kind: DiffKind,
+    pub old: Span,
+    pub new: Span,
+}
+
+/// Returns the hunks that turn `old` into `new`, in order, using Myers' algorithm on `char`s, so multibyte
+/// characters are never split. Consecutive edits of the same kind are merged into one hunk.
+///
+/// The common prefix and suffix are skipped before searching. The search then takes O((N + M) * D) time, and O(D²)
+/// memory for the history of the search, where D is the number of inserted and deleted `char`s.
+/// # Arguments
+///
+/// * `old` - The original string.
+/// * `new` - The changed string.
+/// # Examples
+/// ```
+/// use indexable_str::{diff, DiffKind, IndexableStr, Span};
+///
+/// let old = IndexableStr::new("0😀2345");
+/// let new = IndexableStr::new("0😃2345");
+/// let hunks = diff(&old, &new);
+///
+/// assert_eq!(hunks.len(), 4);
+/// assert_eq!(hunks[1].kind, DiffKind::Delete);
+/// assert_eq!(&old[hunks[1].old], "😀");
+/// assert_eq!(hunks[2].kind, DiffKind::Insert);
+/// assert_eq!(&new[hunks[2].new], "😃");
+/// assert_eq!(hunks[3].old, Span::new(2, 6));
+/// ```
+pub fn diff(old: &IndexableStr, new: &IndexableStr) -> Vec<DiffHunk> {
+    let prefix_length: usize = old.common_prefix_len(new);
+    let suffix_length: usize = old.common_suffix_len(new).min(old.len().min(new.len()) - prefix_length);
+    let old_middle: usize = old.len() - prefix_length - suffix_length;
+    let new_middle: usize = new.len() - prefix_length - suffix_length;
+
+    let middle_script = edit_script(old_middle, new_middle, |old_index, new_index| {
+        old[prefix_length + old_index] == new[prefix_length + new_index]
+    });
+    let script = std::iter::repeat_n(DiffKind::Equal, prefix_length)
+        .chain(middle_script)
+        .chain(std::iter::repeat_n(DiffKind::Equal, suffix_length));
+
+    let mut hunks: Vec<DiffHunk> = Vec::new();
+    let mut old_index: usize = 0;
+    let mut new_index: usize = 0;
+
+    for kind in script {
+        let (old_length, new_length) = match kind {
+            DiffKind::Equal => (1, 1),
+            DiffKind::Delete => (1, 0),
+            DiffKind::Insert => (0, 1),
+        };
+
+        match hunks.last_mut() {
+            Some(hunk) if hunk.kind == kind => {
+                hunk.old.end += old_length;
+                hunk.new.end += new_length;
+            },
+            _ => hunks.push(DiffHunk {
+                kind,
+                old: Span::new(old_index, old_index + old_length),
+                new: Span::new(new_index, new_index + new_length),
+            }),
+        }
+
+        old_index += old_length;
+        new_index += new_length;
+    }
+
+    hunks
+}
+
+/// Returns the shortest edit script between sequences of `old_length` and `new_length` `char`s, one step per `char`.
+/// `equal` compares the `char` at an index in the old sequence with the `char` at an index in the new one.
+fn edit_script(old_length: usize, new_length: usize, equal: impl Fn(usize, usize) -> bool) -> Vec<DiffKind> {
+    let old_length: isize = old_length as isize;
+    let new_length: isize = new_length as isize;
+    let max_edits: isize = old_length + new_length;
+
+    // `furthest[max_edits + k]` is the furthest `old` index reached on diagonal `k`, where `k` is the `old` index
+    // minus the `new` index. `history[d]` keeps diagonals `-d..=d` as they were before round `d`.
+    let mut furthest: Vec<isize> = vec![0; 2 * max_edits as usize + 2];
+    let mut history: Vec<Vec<isize>> = Vec::new();
+
+    'search: for edits in 0..=max_edits {
+        history.push(furthest[(max_edits - edits) as usize..=(max_edits + edits) as usize].to_vec());
+
+        for diagonal in (-edits..=edits).step_by(2) {
+            let at = |diagonal: isize| furthest[(max_edits + diagonal) as usize];
+
+            let mut old_index: isize = match diagonal == -edits || (diagonal != edits && at(diagonal - 1) < at(diagonal + 1)) {
+                true => at(diagonal + 1),
+                false => at(diagonal - 1) + 1,
+            };
+            let mut new_index: isize = old_index - diagonal;
+
+            while old_index < old_length && new_index < new_length && equal(old_index as usize, new_index as usize) {
+                old_index += 1;
+                new_index += 1;
+            }
+
+            furthest[(max_edits + diagonal) as usize] = old_index;
+
+            if old_index >= old_length && new_index >= new_length {
+                break 'search;
+            }
+        }
+    }
+
+    let mut script: Vec<DiffKind> = Vec::new();
+    let mut old_index: isize = old_length;
+    let mut new_index: isize = new_length;
+
+    for (edits, furthest) in history.iter().enumerate().rev() {
+        let edits: isize = edits as isize;
+        let at = |diagonal: isize| furthest[(edits + diagonal) as usize];
+        let diagonal: isize = old_index - new_index;
+
+        let previous_diagonal: isize = match diagonal == -edits || (diagonal != edits && at(diagonal - 1) < at(diagonal + 1)) {
+            true => diagonal + 1,
+            false => diagonal - 1,
+        };
+        let previous_old_index: isize = match edits {
+            0 => 0,
+            _ => at(previous_diagonal),
+        };
+        let previous_new_index: isize = previous_old_index - previous_diagonal;
+
+        while old_index > previous_old_index && new_index > previous_new_index.max(0) {
+            script.push(DiffKind::Equal);
+            old_index -= 1;
+            new_index -= 1;
+        }
+
+        if edits > 0 {
+            match old_index == previous_old_index {
+                true => script.push(DiffKind::Insert),
+                false => script.push(DiffKind::Delete),
+            }
+        }
+
+        old_index = previous_old_index;
+        new_index = previous_new_index;
+    }
+
+    script.reverse();
+    script
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns the number of `char`s at the start of this string that are the same as the start of `other`.
+    ///
+    /// The strings are compared byte by byte, and the result is converted to `char`s with the index.
+    /// # Arguments
+    ///
+    /// * `other` - The string to compare with.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("0😀2345678😀");
+    ///
+    /// assert_eq!(s.common_prefix_len(&IndexableStr::new("0😀23é")), 4);
+    /// assert_eq!(s.common_prefix_len(&IndexableStr::new("0😃")), 1);
+    /// ```
+    pub fn common_prefix_len(&self, other: &IndexableStr) -> usize {
+        let bytes_length: usize = self.str.bytes().zip(other.str.bytes()).take_while(|(byte, other_byte)| byte == other_byte).count();
+
+        match bytes_length == self.str_length {
+            true => self.chars_length,
+            // The first byte that differs may be in the middle of a `char`, which then isn't shared.
+            false => match self.char_table.char_index(bytes_length) {
+                Ok(index) => index,
+                Err(index) => index - 1,
+            },
+        }
+    }
+
+    /// Returns the number of `char`s at the end of this string that are the same as the end of `other`.
+    ///
+    /// The common suffix may overlap the common prefix, for example when one string is a repetition of the other.
+    /// # Arguments
+    ///
+    /// * `other` - The string to compare with.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("0😀2345678😀");
+    ///
+    /// assert_eq!(s.common_suffix_len(&IndexableStr::new("é78😀")), 3);
+    /// assert_eq!(s.common_suffix_len(&IndexableStr::new("😃")), 0);
+    /// ```
+    pub fn common_suffix_len(&self, other: &IndexableStr) -> usize {
+        let bytes_length: usize = self.str.bytes().rev().zip(other.str.bytes().rev()).take_while(|(byte, other_byte)| byte == other_byte).count();
+
+        match bytes_length == self.str_length {
+            true => self.chars_length,
+            // The shared bytes may start in the middle of a `char`, which then isn't shared.
+            false => match self.char_table.char_index(self.str_length - bytes_length) {
+                Ok(index) | Err(index) => self.chars_length - index,
+            },
+        }
+    }
+}
+
+impl IndexableString {
+    /// Returns the number of `char`s at the start of this string that are the same as the start of `other`.
+    /// # Arguments
+    ///
+    /// * `other` - The string to compare with.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("0😀2345678😀");
+    ///
+    /// assert_eq!(s.common_prefix_len(&IndexableString::from("0😀23é")), 4);
+    /// ```
+    pub fn common_prefix_len(&self, other: &IndexableString) -> usize {
+        self.as_indexable_str().common_prefix_len(&other.as_indexable_str())
+    }
+
+    /// Returns the number of `char`s at the end of this string that are the same as the end of `other`.
+    /// # Arguments
+    ///
+    /// * `other` - The string to compare with.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("0😀2345678😀");
+    ///
+    /// assert_eq!(s.common_suffix_len(&IndexableString::from("é78😀")), 3);
+    /// ```
+    pub fn common_suffix_len(&self, other: &IndexableString) -> usize {
+        self.as_indexable_str().common_suffix_len(&other.as_indexable_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rebuilds both strings from the hunks, and returns the number of inserted and deleted `char`s.
+    fn check_hunks(old: &str, new: &str) -> usize {
+        let old = IndexableStr::new(old);
+        let new = IndexableStr::new(new);
+        let hunks: Vec<DiffHunk> = diff(&old, &new);
+        let mut rebuilt_old: String = String::new();
+        let mut rebuilt_new: String = String::new();
+        let mut edits: usize = 0;
+
+        for hunk in &hunks {
+            match hunk.kind {
+                DiffKind::Equal => {
+                    assert_eq!(&old[hunk.old], &new[hunk.new]);
+                    rebuilt_old.push_str(&old[hunk.old]);
+                    rebuilt_new.push_str(&new[hunk.new]);
+                },
+                DiffKind::Delete => {
+                    assert!(hunk.new.is_empty());
+                    rebuilt_old.push_str(&old[hunk.old]);
+                    edits += hunk.old.len();
+                },
+                DiffKind::Insert => {
+                    assert!(hunk.old.is_empty());
+                    rebuilt_new.push_str(&new[hunk.new]);
+                    edits += hunk.new.len();
+                },
+            }
+        }
+
+        assert_eq!(rebuilt_old, old.as_str());
+        assert_eq!(rebuilt_new, new.as_str());
+
+        edits
+    }
+
+    #[test]
+    fn test_diff_rebuilds_both_strings() {
+        assert_eq!(check_hunks("", ""), 0);
+        assert_eq!(check_hunks("", "😀é"), 2);
+        assert_eq!(check_hunks("😀é", ""), 2);
+        assert_eq!(check_hunks("0😀2345678😀", "0😀2345678😀"), 0);
+        assert_eq!(check_hunks("abcabba", "cbabac"), 5);
+        assert_eq!(check_hunks("中文😀abc", "abc中文"), 5);
+        assert_eq!(check_hunks("0😀2345678😀", "😀0234é678"), 5);
+    }
+
+    #[test]
+    fn test_common_prefix_and_suffix_agree_with_chars() {
+        let strs = ["", "0", "😀", "😃", "0😀2", "0😃2", "é中😀", "é中", "中😀", "😀😀😀"];
+
+        for str in strs {
+            for other_str in strs {
+                let chars: Vec<char> = str.chars().collect();
+                let other_chars: Vec<char> = other_str.chars().collect();
+                let prefix_length: usize = chars.iter().zip(&other_chars).take_while(|(chr, other_chr)| chr == other_chr).count();
+                let suffix_length: usize = chars.iter().rev().zip(other_chars.iter().rev()).take_while(|(chr, other_chr)| chr == other_chr).count();
+
+                let s = IndexableStr::new(str);
+                let other = IndexableStr::new(other_str);
+
+                assert_eq!(s.common_prefix_len(&other), prefix_length);
+                assert_eq!(s.common_suffix_len(&other), suffix_length);
+            }
+        }
+    }
+
+    #[test]
+    fn test_diff_of_equal_strings_is_one_hunk() {
+        let s = IndexableStr::new("0😀2");
+
+        assert_eq!(diff(&s, &s), vec![DiffHunk {
+            kind: DiffKind::Equal,
+            old: Span::new(0, 3),
+            new: Span::new(0, 3),
+        }]);
+    }
+}
@@ -0,0 +1,269 @@
+use crate::{IndexableStr, IndexableString, Offset};
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns the Levenshtein distance to `other`, which is the number of `char`s that have to be inserted, deleted
+    /// or substituted to turn this string into `other`.
+    ///
+    /// The common prefix and suffix are skipped, and the rest takes O(N * M) time and O(M) memory.
+    /// # Arguments
+    ///
+    /// * `other` - The string to compare with.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("kitten😀");
+    ///
+    /// assert_eq!(s.edit_distance(&IndexableStr::new("sitting😀")), 3);
+    /// assert_eq!(s.edit_distance(&IndexableStr::new("kitten😃")), 1);
+    /// ```
+    pub fn edit_distance(&self, other: &IndexableStr) -> usize {
+        let prefix_length: usize = self.common_prefix_len(other);
+        let suffix_length: usize = self.common_suffix_len(other).min(self.len().min(other.len()) - prefix_length);
+        let length: usize = self.len() - prefix_length - suffix_length;
+        let other_length: usize = other.len() - prefix_length - suffix_length;
+
+        // `distances[j]` is the distance between the first `i` `char`s of this string and the first `j` of `other`.
+        let mut distances: Vec<usize> = (0..=other_length).collect();
+
+        for i in 1..=length {
+            let chr: char = self[prefix_length + i - 1];
+            let mut diagonal: usize = distances[0];
+
+            distances[0] = i;
+
+            for j in 1..=other_length {
+                let substitution: usize = diagonal + (chr != other[prefix_length + j - 1]) as usize;
+
+                diagonal = distances[j];
+                distances[j] = substitution.min(distances[j] + 1).min(distances[j - 1] + 1);
+            }
+        }
+
+        distances[other_length]
+    }
+
+    /// Returns how similar this string is to `other`, from `0.0` for completely different strings to `1.0` for equal
+    /// ones. This is one minus the edit distance divided by the number of `char`s in the longer string.
+    /// # Arguments
+    ///
+    /// * `other` - The string to compare with.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("中文😀😀");
+    ///
+    /// assert_eq!(s.similarity(&IndexableStr::new("中文😀😀")), 1.0);
+    /// assert_eq!(s.similarity(&IndexableStr::new("中文")), 0.5);
+    /// assert_eq!(s.similarity(&IndexableStr::new("abc")), 0.0);
+    /// ```
+    pub fn similarity(&self, other: &IndexableStr) -> f64 {
+        let length: usize = self.len().max(other.len());
+
+        match length {
+            0 => 1.0,
+            _ => 1.0 - self.edit_distance(other) as f64 / length as f64,
+        }
+    }
+
+    /// Returns the Levenshtein distance to `other` in grapheme clusters, so that a `char` with combining marks or an
+    /// emoji sequence counts as one edit, as a user would see it.
+    ///
+    /// The common prefix and suffix are skipped, and the rest takes O(N * M) time and O(M) memory.
+    /// # Arguments
+    ///
+    /// * `other` - The string to compare with.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("cafe\u{301}👍🏽");
+    ///
+    /// assert_eq!(s.edit_distance(&IndexableStr::new("café👍")), 3);
+    /// assert_eq!(s.grapheme_edit_distance(&IndexableStr::new("café👍")), 2);
+    /// assert_eq!(s.grapheme_edit_distance(&IndexableStr::new("cafe👍🏽")), 1);
+    /// ```
+    pub fn grapheme_edit_distance(&self, other: &IndexableStr) -> usize {
+        let graphemes: Vec<&str> = self.grapheme_ranges().map(|range| &self.str[range]).collect();
+        let other_graphemes: Vec<&str> = other.grapheme_ranges().map(|range| &other.str[range]).collect();
+        let prefix_length: usize = graphemes.iter().zip(&other_graphemes).take_while(|(grapheme, other)| grapheme == other).count();
+        let graphemes: &[&str] = &graphemes[prefix_length..];
+        let other_graphemes: &[&str] = &other_graphemes[prefix_length..];
+        let suffix_length: usize = graphemes.iter().rev().zip(other_graphemes.iter().rev()).take_while(|(grapheme, other)| grapheme == other).count();
+        let graphemes: &[&str] = &graphemes[..graphemes.len() - suffix_length];
+        let other_graphemes: &[&str] = &other_graphemes[..other_graphemes.len() - suffix_length];
+
+        // `distances[j]` is the distance between the first `i` graphemes of this string and the first `j` of `other`.
+        let mut distances: Vec<usize> = (0..=other_graphemes.len()).collect();
+
+        for (i, grapheme) in graphemes.iter().enumerate() {
+            let mut diagonal: usize = distances[0];
+
+            distances[0] = i + 1;
+
+            for (j, other_grapheme) in other_graphemes.iter().enumerate() {
+                let substitution: usize = diagonal + (grapheme != other_grapheme) as usize;
+
+                diagonal = distances[j + 1];
+                distances[j + 1] = substitution.min(distances[j + 1] + 1).min(distances[j] + 1);
+            }
+        }
+
+        distances[other_graphemes.len()]
+    }
+
+    /// Returns how similar this string is to `other` in grapheme clusters, from `0.0` for completely different strings
+    /// to `1.0` for equal ones. This is one minus the grapheme edit distance divided by the number of grapheme
+    /// clusters in the longer string.
+    /// # Arguments
+    ///
+    /// * `other` - The string to compare with.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("e\u{301}👨\u{200D}👩\u{200D}👧");
+    ///
+    /// assert_eq!(s.grapheme_similarity(&IndexableStr::new("é👨\u{200D}👩\u{200D}👧")), 0.5);
+    /// assert_eq!(s.grapheme_similarity(&IndexableStr::new("e\u{301}")), 0.5);
+    /// ```
+    pub fn grapheme_similarity(&self, other: &IndexableStr) -> f64 {
+        let length: usize = self.grapheme_ranges().count().max(other.grapheme_ranges().count());
+
+        match length {
+            0 => 1.0,
+            _ => 1.0 - self.grapheme_edit_distance(other) as f64 / length as f64,
+        }
+    }
+}
+
+impl IndexableString {
+    /// Returns the Levenshtein distance to `other` in `char`s. See `IndexableStr::edit_distance`.
+    /// # Arguments
+    ///
+    /// * `other` - The string to compare with.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("kitten😀");
+    ///
+    /// assert_eq!(s.edit_distance(&IndexableString::from("sitting😀")), 3);
+    /// ```
+    pub fn edit_distance(&self, other: &IndexableString) -> usize {
+        self.as_indexable_str().edit_distance(&other.as_indexable_str())
+    }
+
+    /// Returns how similar this string is to `other`, from `0.0` to `1.0`. See `IndexableStr::similarity`.
+    /// # Arguments
+    ///
+    /// * `other` - The string to compare with.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("中文😀😀");
+    ///
+    /// assert_eq!(s.similarity(&IndexableString::from("中文")), 0.5);
+    /// ```
+    pub fn similarity(&self, other: &IndexableString) -> f64 {
+        self.as_indexable_str().similarity(&other.as_indexable_str())
+    }
+
+    /// Returns the Levenshtein distance to `other` in grapheme clusters. See `IndexableStr::grapheme_edit_distance`.
+    /// # Arguments
+    ///
+    /// * `other` - The string to compare with.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("👍🏽👍🏽");
+    ///
+    /// assert_eq!(s.grapheme_edit_distance(&IndexableString::from("👍🏽👍")), 1);
+    /// ```
+    pub fn grapheme_edit_distance(&self, other: &IndexableString) -> usize {
+        self.as_indexable_str().grapheme_edit_distance(&other.as_indexable_str())
+    }
+
+    /// Returns how similar this string is to `other` in grapheme clusters, from `0.0` to `1.0`. See
+    /// `IndexableStr::grapheme_similarity`.
+    /// # Arguments
+    ///
+    /// * `other` - The string to compare with.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("👍🏽👍🏽");
+    ///
+    /// assert_eq!(s.grapheme_similarity(&IndexableString::from("👍🏽👍")), 0.5);
+    /// ```
+    pub fn grapheme_similarity(&self, other: &IndexableString) -> f64 {
+        self.as_indexable_str().grapheme_similarity(&other.as_indexable_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_edit_distance(str: &str, other_str: &str) -> usize {
+        let chars: Vec<char> = str.chars().collect();
+        let other_chars: Vec<char> = other_str.chars().collect();
+        let mut distances: Vec<Vec<usize>> = vec![vec![0; other_chars.len() + 1]; chars.len() + 1];
+
+        for i in 0..=chars.len() {
+            for j in 0..=other_chars.len() {
+                distances[i][j] = match (i, j) {
+                    (0, _) => j,
+                    (_, 0) => i,
+                    _ => (distances[i - 1][j - 1] + (chars[i - 1] != other_chars[j - 1]) as usize)
+                        .min(distances[i - 1][j] + 1)
+                        .min(distances[i][j - 1] + 1),
+                };
+            }
+        }
+
+        distances[chars.len()][other_chars.len()]
+    }
+
+    #[test]
+    fn test_edit_distance_agrees_with_naive_distance() {
+        let strs = ["", "a", "😀", "😃", "aa", "0😀2", "0😃2", "é中😀", "中😀é", "😀😀😀", "abcabba", "cbabac"];
+
+        for str in strs {
+            for other_str in strs {
+                let distance: usize = IndexableStr::new(str).edit_distance(&IndexableStr::new(other_str));
+
+                assert_eq!(distance, naive_edit_distance(str, other_str), "{str} -> {other_str}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_grapheme_edit_distance_agrees_with_edit_distance_of_single_char_graphemes() {
+        let strs = ["", "a", "😀", "aa", "0😀2", "0😃2", "é中😀", "中😀é", "abcabba", "cbabac"];
+
+        for str in strs {
+            for other_str in strs {
+                let s = IndexableStr::new(str);
+                let other = IndexableStr::new(other_str);
+
+                assert_eq!(s.grapheme_edit_distance(&other), s.edit_distance(&other), "{str} -> {other_str}");
+            }
+        }
+
+        let s = IndexableStr::new("a👨\u{200D}👩\u{200D}👧b");
+
+        assert_eq!(s.grapheme_edit_distance(&IndexableStr::new("ab")), 1);
+        assert_eq!(s.grapheme_edit_distance(&IndexableStr::new("a👨\u{200D}👩b")), 1);
+        assert_eq!(IndexableStr::new("").grapheme_similarity(&IndexableStr::new("")), 1.0);
+    }
+
+    #[test]
+    fn test_similarity_of_empty_strings_is_one() {
+        assert_eq!(IndexableStr::new("").similarity(&IndexableStr::new("")), 1.0);
+    }
+}
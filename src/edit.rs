@@ -0,0 +1,920 @@
+use std::ops::Range;
+
+use crate::{error::check_range, unicode, IndexableStr, IndexableString, IndexableStringBuilder, Offset, Span};
+
+/// `MappedEdit` records that the `char`s in `old` were replaced by the `char`s in `new`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct MappedEdit {
+    pub(crate) old: Span,
+    pub(crate) new: Span,
+}
+
+/// `IndexMapping` translates `char` indices between a string and an edited copy of it.
+///
+/// Indices outside of the edited regions are shifted by the edits before them. An index inside a region that was
+/// deleted or replaced has no counterpart, so it maps to `None`, as does an index inside inserted or replacement
+/// text when mapping back to the original.
+///
+/// # Examples
+/// ```
+/// use indexable_str::IndexableStr;
+///
+/// let s = IndexableStr::new("a😀b😀c");
+/// let (replaced, mapping) = s.replace("😀", "--");
+///
+/// assert_eq!(replaced.as_str(), "a--b--c");
+/// assert_eq!(mapping.to_new(2), Some(3));
+/// assert_eq!(mapping.to_new(1), None);
+/// assert_eq!(mapping.to_old(6), Some(4));
+/// assert_eq!(mapping.to_old(5), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IndexMapping {
+    pub(crate) edits: Vec<MappedEdit>,
+}
+
+impl IndexMapping {
+    /// Returns the new index of the `char` at `old_index` in the original string, or `None` if that `char` was
+    /// deleted or replaced. The index equal to the length of the original maps to the length of the new string.
+    /// # Arguments
+    ///
+    /// * `old_index` - A `char` index in the original string.
+    pub fn to_new(&self, old_index: usize) -> Option<usize> {
+        let edit_count: usize = self.edits.partition_point(|edit| edit.old.start <= old_index);
+
+        if edit_count == 0 {
+            return Some(old_index);
+        }
+
+        let edit: MappedEdit = self.edits[edit_count - 1];
+
+        match old_index < edit.old.end {
+            true => None,
+            false => Some(edit.new.end + old_index - edit.old.end),
+        }
+    }
+
+    /// Returns the original index of the `char` at `new_index` in the new string, or `None` if that `char` was
+    /// inserted by an edit.
+    /// # Arguments
+    ///
+    /// * `new_index` - A `char` index in the new string.
+    pub fn to_old(&self, new_index: usize) -> Option<usize> {
+        let edit_count: usize = self.edits.partition_point(|edit| edit.new.start <= new_index);
+
+        if edit_count == 0 {
+            return Some(new_index);
+        }
+
+        let edit: MappedEdit = self.edits[edit_count - 1];
+
+        match new_index < edit.new.end {
+            true => None,
+            false => Some(edit.old.end + new_index - edit.new.end),
+        }
+    }
+
+    /// Returns the new index of the `char` at `old_index` in the original string. If that `char` was deleted or
+    /// replaced, this is the index where its replacement starts.
+    /// # Arguments
+    ///
+    /// * `old_index` - A `char` index in the original string.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let (replaced, mapping) = IndexableStr::new("a😀b").replace("😀", "--");
+    ///
+    /// assert_eq!(mapping.to_new(1), None);
+    /// assert_eq!(mapping.to_new_start(1), 1);
+    /// assert_eq!(mapping.to_new_start(2), 3);
+    /// ```
+    pub fn to_new_start(&self, old_index: usize) -> usize {
+        let edit_count: usize = self.edits.partition_point(|edit| edit.old.start <= old_index);
+
+        match edit_count {
+            0 => old_index,
+            _ => {
+                let edit: MappedEdit = self.edits[edit_count - 1];
+
+                match old_index < edit.old.end {
+                    true => edit.new.start,
+                    false => edit.new.end + old_index - edit.old.end,
+                }
+            },
+        }
+    }
+
+    /// Returns the original index of the `char` at `new_index` in the new string. If that `char` was inserted by an
+    /// edit, this is the index where the text that the edit replaced starts.
+    /// # Arguments
+    ///
+    /// * `new_index` - A `char` index in the new string.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let (replaced, mapping) = IndexableStr::new("a😀b").replace("😀", "--");
+    ///
+    /// assert_eq!(mapping.to_old(2), None);
+    /// assert_eq!(mapping.to_old_start(2), 1);
+    /// assert_eq!(mapping.to_old_start(3), 2);
+    /// ```
+    pub fn to_old_start(&self, new_index: usize) -> usize {
+        let edit_count: usize = self.edits.partition_point(|edit| edit.new.start <= new_index);
+
+        match edit_count {
+            0 => new_index,
+            _ => {
+                let edit: MappedEdit = self.edits[edit_count - 1];
+
+                match new_index < edit.new.end {
+                    true => edit.old.start,
+                    false => edit.old.end + new_index - edit.new.end,
+                }
+            },
+        }
+    }
+
+    /// Returns `true` if the mapping doesn't contain any edits.
+    pub fn is_identity(&self) -> bool {
+        self.edits.is_empty()
+    }
+}
+
+/// `MappedSpan` is where a `Span` of the original string ended up after the edits of a `SpanMapper`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MappedSpan {
+    /// No edit touched the text of the span, which was only shifted to this span.
+    Moved(Span),
+    /// Some of the text of the span was edited. The new span covers what's left of it and the text that replaced the
+    /// edited parts.
+    Edited(Span),
+    /// The text of the span was all inside a single deleted or replaced range.
+    Deleted,
+}
+
+/// `SpanMapper` translates `Span`s of a string into the coordinates of the string after a set of edits, such as the
+/// positions of diagnostics that remain after some fixes were applied.
+///
+/// # Examples
+/// ```
+/// use indexable_str::{IndexableStr, MappedSpan, Span, SpanMapper};
+///
+/// let s = IndexableStr::new("let x = 😀; let y = 1;");
+/// let edits = [(8..9, "\"smile\""), (11..15, "")];
+/// let (fixed, _) = s.apply_edits(&edits);
+/// let mapper = SpanMapper::new(&edits);
+///
+/// assert_eq!(fixed.as_str(), "let x = \"smile\"; y = 1;");
+/// assert_eq!(mapper.map_span(Span::new(19, 20)), MappedSpan::Moved(Span::new(21, 22)));
+/// assert_eq!(mapper.map_span(Span::new(4, 9)), MappedSpan::Edited(Span::new(4, 15)));
+/// assert_eq!(mapper.map_span(Span::new(11, 14)), MappedSpan::Deleted);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpanMapper {
+    mapping: IndexMapping,
+}
+
+impl SpanMapper {
+    /// Returns a mapper for the same edits as `IndexableStr::apply_edits`, without needing the string.
+    /// # Arguments
+    ///
+    /// * `edits` - The ranges of `char`s of the original string that were replaced, and their replacements.
+    /// # Panics
+    /// * If a range end is less than its range start.
+    /// * If two ranges overlap.
+    pub fn new(edits: &[(Range<usize>, &str)]) -> SpanMapper {
+        let mut sorted_edits: Vec<&(Range<usize>, &str)> = edits.iter().collect();
+
+        sorted_edits.sort_by_key(|(range, _)| (range.start, range.end));
+
+        for (range, _) in &sorted_edits {
+            if let Err(error) = check_range(range.start, range.end, usize::MAX) {
+                panic!("{error}");
+            }
+        }
+
+        for pair in sorted_edits.windows(2) {
+            let (previous_range, _) = pair[0];
+            let (range, _) = pair[1];
+
+            if previous_range.end > range.start {
+                panic!("Edit range: ({range:?}) must not overlap edit range: ({previous_range:?})!");
+            }
+        }
+
+        let mut mapping: IndexMapping = IndexMapping::default();
+        let mut new_end: usize = 0;
+        let mut old_end: usize = 0;
+
+        for (range, replacement) in sorted_edits {
+            let new_start: usize = new_end + range.start - old_end;
+
+            new_end = new_start + replacement.chars().count();
+            old_end = range.end;
+
+            mapping.edits.push(MappedEdit {
+                old: Span::from(range.clone()),
+                new: Span::new(new_start, new_end),
+            });
+        }
+
+        SpanMapper { mapping }
+    }
+
+    /// Returns the `IndexMapping` of the edits.
+    pub fn mapping(&self) -> &IndexMapping {
+        &self.mapping
+    }
+
+    /// Returns where `span` of the original string is after the edits. An empty span, such as a cursor position, is
+    /// always `Moved`, to the start of the replacement if it was inside a replaced range.
+    /// # Arguments
+    ///
+    /// * `span` - A span of the original string.
+    pub fn map_span(&self, span: Span) -> MappedSpan {
+        let edits: &[MappedEdit] = &self.mapping.edits;
+        let first: usize = edits.partition_point(|edit| edit.old.end <= span.start);
+        let last: usize = edits.partition_point(|edit| edit.old.start < span.end);
+        let new_start: usize = self.mapping.to_new_start(span.start);
+
+        if span.is_empty() || first >= last {
+            return MappedSpan::Moved(Span::new(new_start, new_start + span.len()));
+        }
+
+        let edit: MappedEdit = edits[last - 1];
+
+        if last - first == 1 && edit.old.start <= span.start && span.end <= edit.old.end && !edit.old.is_empty() {
+            return MappedSpan::Deleted;
+        }
+
+        // An insertion at `span.end` isn't part of the span, so the end is counted from the last edit inside it.
+        let new_end: usize = match span.end < edit.old.end {
+            true => edit.new.end,
+            false => edit.new.end + span.end - edit.old.end,
+        };
+
+        MappedSpan::Edited(Span::new(new_start, new_end))
+    }
+}
+
+impl From<IndexMapping> for SpanMapper {
+    fn from(mapping: IndexMapping) -> Self {
+        SpanMapper { mapping }
+    }
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns a copy of the string with every occurrence of `from` replaced by `to`, along with an `IndexMapping`
+    /// between the `char` indices of the two strings.
+    /// # Arguments
+    ///
+    /// * `from` - The text to replace. An empty pattern doesn't match anything.
+    /// * `to` - The replacement text.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("0😀2345678😀");
+    /// let (replaced, mapping) = s.replace("😀", "ab");
+    ///
+    /// assert_eq!(replaced.as_str(), "0ab2345678ab");
+    /// assert_eq!(mapping.to_new(2), Some(3));
+    /// ```
+    pub fn replace(&self, from: &str, to: &str) -> (IndexableString, IndexMapping) {
+        let mut builder: IndexableStringBuilder = IndexableStringBuilder::with_capacity(self.str_length);
+        let mut mapping: IndexMapping = IndexMapping::default();
+        let from_length: usize = from.chars().count();
+        let to_length: usize = to.chars().count();
+        let mut bytes_copied: usize = 0;
+
+        if !from.is_empty() {
+            for (byte_offset, _) in self.str.match_indices(from) {
+                let old_start: usize = self.char_index_at_byte(byte_offset).unwrap();
+
+                builder.push_str(&self.str[bytes_copied..byte_offset]);
+
+                let new_start: usize = builder.len();
+
+                builder.push_str(to);
+                bytes_copied = byte_offset + from.len();
+
+                mapping.edits.push(MappedEdit {
+                    old: Span::new(old_start, old_start + from_length),
+                    new: Span::new(new_start, new_start + to_length),
+                });
+            }
+        }
+
+        builder.push_str(&self.str[bytes_copied..]);
+
+        (builder.finish(), mapping)
+    }
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns a copy of the string with several edits applied in one pass, along with an `IndexMapping` between the
+    /// `char` indices of the two strings.
+    ///
+    /// The edits may be given in any order, but their ranges must not overlap. Insertions at the same index are
+    /// applied in the order they're given.
+    /// # Arguments
+    ///
+    /// * `edits` - The ranges of `char`s in this string to replace, and the text to replace each of them with.
+    /// # Panics
+    /// * If a range end is greater than the number of characters in the string.
+    /// * If a range end is less than its range start.
+    /// * If two ranges overlap.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("0😀2345678😀");
+    /// let (edited, mapping) = s.apply_edits(&[(9..10, "9"), (1..2, "1"), (5..5, "!")]);
+    ///
+    /// assert_eq!(edited.as_str(), "01234!56789");
+    /// assert_eq!(mapping.to_new(5), Some(6));
+    /// assert_eq!(mapping.to_old(5), None);
+    /// assert_eq!(mapping.to_old(10), None);
+    /// ```
+    pub fn apply_edits(&self, edits: &[(Range<usize>, &str)]) -> (IndexableString, IndexMapping) {
+        let mut sorted_edits: Vec<&(Range<usize>, &str)> = edits.iter().collect();
+
+        sorted_edits.sort_by_key(|(range, _)| (range.start, range.end));
+
+        for (range, _) in &sorted_edits {
+            self.create_str_from_range(range.start, range.end);
+        }
+
+        for pair in sorted_edits.windows(2) {
+            let (previous_range, _) = pair[0];
+            let (range, _) = pair[1];
+
+            if previous_range.end > range.start {
+                panic!("Edit range: ({range:?}) must not overlap edit range: ({previous_range:?})!");
+            }
+        }
+
+        let mut builder: IndexableStringBuilder = IndexableStringBuilder::with_capacity(self.str_length);
+        let mut mapping: IndexMapping = IndexMapping::default();
+        let mut bytes_copied: usize = 0;
+
+        for (range, replacement) in sorted_edits {
+            let bytes_start: usize = self.byte_offset(range.start);
+
+            builder.push_str(&self.str[bytes_copied..bytes_start]);
+
+            let new_start: usize = builder.len();
+
+            builder.push_str(replacement);
+            bytes_copied = self.byte_offset(range.end);
+
+            mapping.edits.push(MappedEdit {
+                old: Span::from(range.clone()),
+                new: Span::new(new_start, builder.len()),
+            });
+        }
+
+        builder.push_str(&self.str[bytes_copied..]);
+
+        (builder.finish(), mapping)
+    }
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns the leading whitespace shared by every line that isn't blank. Lines are separated by `'\n'`, and the
+    /// whitespace has to match exactly, so a tab and a space are different.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("    a\n\n  \t b\n    c");
+    ///
+    /// assert_eq!(s.common_indent(), "  ");
+    /// assert_eq!(IndexableStr::new("    😀\n    x").common_indent(), "    ");
+    /// ```
+    pub fn common_indent(&self) -> &'a str {
+        let str: &'a str = self.str;
+        let mut common_indent: Option<&'a str> = None;
+
+        for line in str.split('\n') {
+            let indent: &'a str = &line[..line.len() - line.trim_start().len()];
+
+            if indent.len() == line.len() {
+                continue;
+            }
+
+            common_indent = Some(match common_indent {
+                None => indent,
+                Some(common_indent) => {
+                    let length: usize = common_indent.bytes().zip(indent.bytes()).take_while(|(byte, other_byte)| byte == other_byte).count();
+
+                    // The shared bytes could end in the middle of a multibyte whitespace `char`.
+                    let length: usize = (0..=length).rev().find(|length| common_indent.is_char_boundary(*length)).unwrap();
+
+                    &common_indent[..length]
+                },
+            });
+        }
+
+        common_indent.unwrap_or("")
+    }
+
+    /// Returns a copy of the string with the `common_indent` removed from the start of every line, along with an
+    /// `IndexMapping` between the `char` indices of the two strings. Blank lines lose as much of the indentation as
+    /// they have.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("  a😀\n\n    b\n");
+    /// let (dedented, mapping) = s.dedent();
+    ///
+    /// assert_eq!(dedented.as_str(), "a😀\n\n  b\n");
+    /// assert_eq!(mapping.to_old(6), Some(10));
+    /// assert_eq!(mapping.to_new(0), None);
+    /// ```
+    pub fn dedent(&self) -> (IndexableString, IndexMapping) {
+        let common_indent: &str = self.common_indent();
+        let indent_length: usize = common_indent.chars().count();
+        let mut edits: Vec<(Range<usize>, &str)> = Vec::new();
+        let mut line_start: usize = 0;
+
+        if indent_length > 0 {
+            for line in self.str.split('\n') {
+                let matching_length: usize = line.chars().zip(common_indent.chars()).take_while(|(chr, indent_chr)| chr == indent_chr).count();
+
+                if matching_length > 0 {
+                    edits.push((line_start..line_start + matching_length, ""));
+                }
+
+                line_start += line.chars().count() + 1;
+            }
+        }
+
+        self.apply_edits(&edits)
+    }
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns a copy of the string with each tab replaced by spaces up to the next tab stop, along with an
+    /// `IndexMapping` between the `char` indices of the two strings. Columns are counted in terminal columns from the
+    /// last line break, the same way as `IndexableStr::display_width`, and there is a tab stop every `tab_width`
+    /// columns.
+    ///
+    /// The visual column of a `char` in the original string is its column in the expanded string, which is found by
+    /// mapping its index with `IndexMapping::to_new_start`.
+    /// # Arguments
+    ///
+    /// * `tab_width` - The number of columns between tab stops.
+    /// # Panics
+    /// * If `tab_width` is 0.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("a\tb\n\t😀");
+    /// let (expanded, mapping) = s.expand_tabs(4);
+    ///
+    /// assert_eq!(expanded.as_str(), "a   b\n    😀");
+    /// assert_eq!(expanded.as_indexable_str().position_at(mapping.to_new_start(5)).unwrap().column, 4);
+    /// assert_eq!(expanded.as_indexable_str().position_at(mapping.to_new_start(1)).unwrap().column, 1);
+    /// ```
+    pub fn expand_tabs(&self, tab_width: usize) -> (IndexableString, IndexMapping) {
+        if tab_width == 0 {
+            panic!("Tab width must be greater than 0!");
+        }
+
+        let spaces: String = " ".repeat(tab_width);
+        let mut edits: Vec<(Range<usize>, &str)> = Vec::new();
+        let mut column: usize = 0;
+
+        let mut index: usize = 0;
+
+        for range in self.grapheme_ranges() {
+            let grapheme: &str = &self.str[range];
+
+            match grapheme {
+                "\t" => {
+                    let space_count: usize = tab_width - column % tab_width;
+
+                    edits.push((index..index + 1, &spaces[..space_count]));
+                    column += space_count;
+                },
+                "\n" | "\r\n" => column = 0,
+                _ => column += unicode::grapheme_width(grapheme),
+            }
+
+            index += grapheme.chars().count();
+        }
+
+        self.apply_edits(&edits)
+    }
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns a copy of the string with every `"\r\n"` and lone `'\r'` replaced by `'\n'`, along with an
+    /// `IndexMapping` between the `char` indices of the two strings.
+    ///
+    /// The `'\r'` of a `"\r\n"` is deleted, so its `'\n'` maps back to the original `'\n'`. A lone `'\r'` is replaced,
+    /// so use `IndexMapping::to_old_start` to map its `'\n'` back.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("a\r\n😀\rb");
+    /// let (normalized, mapping) = s.normalize_newlines();
+    ///
+    /// assert_eq!(normalized.as_str(), "a\n😀\nb");
+    /// assert_eq!(mapping.to_old(1), Some(2));
+    /// assert_eq!(mapping.to_old_start(3), 4);
+    /// assert_eq!(mapping.to_old(4), Some(5));
+    /// ```
+    pub fn normalize_newlines(&self) -> (IndexableString, IndexMapping) {
+        let mut edits: Vec<(Range<usize>, &str)> = Vec::new();
+        let mut chars = self.str.chars().enumerate().peekable();
+
+        while let Some((index, chr)) = chars.next() {
+            if chr == '\r' {
+                match chars.peek() {
+                    Some((_, '\n')) => edits.push((index..index + 1, "")),
+                    _ => edits.push((index..index + 1, "\n")),
+                }
+            }
+        }
+
+        self.apply_edits(&edits)
+    }
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns a copy of the string with its `char`s in reverse order. The `char` at index `i` moves to index
+    /// `len() - 1 - i`.
+    ///
+    /// Combining marks and the other parts of a grapheme cluster are reversed too, so they end up attached to a
+    /// different `char`. Use `reversed_graphemes` to keep each cluster intact.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let reversed = IndexableStr::new("0😀2").reversed();
+    ///
+    /// assert_eq!(reversed.as_str(), "2😀0");
+    /// assert_eq!(reversed[0], '2');
+    /// ```
+    pub fn reversed(&self) -> IndexableString {
+        let mut builder: IndexableStringBuilder = IndexableStringBuilder::with_capacity(self.str.len());
+
+        for chr in self.str.chars().rev() {
+            builder.push_char(chr);
+        }
+
+        builder.finish()
+    }
+
+    /// Returns a copy of the string with its grapheme clusters in reverse order. The `char`s inside each cluster keep
+    /// their order, so accents, emoji modifiers, ZWJ sequences and flags survive the reversal.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("ae\u{301}🇯🇵\r\n");
+    ///
+    /// assert_eq!(s.reversed_graphemes().as_str(), "\r\n🇯🇵e\u{301}a");
+    /// assert_eq!(s.reversed().as_str(), "\n\r🇵🇯\u{301}ea");
+    /// ```
+    pub fn reversed_graphemes(&self) -> IndexableString {
+        let ranges: Vec<Range<usize>> = self.grapheme_ranges().collect();
+        let mut builder: IndexableStringBuilder = IndexableStringBuilder::with_capacity(self.str.len());
+
+        for range in ranges.into_iter().rev() {
+            builder.push_str(&self.str[range]);
+        }
+
+        builder.finish()
+    }
+}
+
+impl IndexableString {
+    /// Returns a copy of the string with its `char`s in reverse order. See `IndexableStr::reversed`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("0😀2").reversed().as_str(), "2😀0");
+    /// ```
+    pub fn reversed(&self) -> IndexableString {
+        self.as_indexable_str().reversed()
+    }
+
+    /// Returns a copy of the string with its grapheme clusters in reverse order. See
+    /// `IndexableStr::reversed_graphemes`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("👍🏽!").reversed_graphemes().as_str(), "!👍🏽");
+    /// ```
+    pub fn reversed_graphemes(&self) -> IndexableString {
+        self.as_indexable_str().reversed_graphemes()
+    }
+
+    /// Returns a copy of the string with every `"\r\n"` and lone `'\r'` replaced by `'\n'`, along with an
+    /// `IndexMapping` between the `char` indices of the two strings. See `IndexableStr::normalize_newlines`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let (normalized, mapping) = IndexableString::from("a\r\nb").normalize_newlines();
+    ///
+    /// assert_eq!(normalized.as_str(), "a\nb");
+    /// assert_eq!(mapping.to_old(2), Some(3));
+    /// ```
+    pub fn normalize_newlines(&self) -> (IndexableString, IndexMapping) {
+        self.as_indexable_str().normalize_newlines()
+    }
+
+    /// Returns a copy of the string with each tab replaced by spaces up to the next tab stop, along with an
+    /// `IndexMapping` between the `char` indices of the two strings. See `IndexableStr::expand_tabs`.
+    /// # Arguments
+    ///
+    /// * `tab_width` - The number of columns between tab stops.
+    /// # Panics
+    /// * If `tab_width` is 0.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let (expanded, mapping) = IndexableString::from("中\t!").expand_tabs(8);
+    ///
+    /// assert_eq!(expanded.as_str(), "中      !");
+    /// assert_eq!(mapping.to_new(2), Some(7));
+    /// ```
+    pub fn expand_tabs(&self, tab_width: usize) -> (IndexableString, IndexMapping) {
+        self.as_indexable_str().expand_tabs(tab_width)
+    }
+
+    /// Returns the leading whitespace shared by every line that isn't blank. See `IndexableStr::common_indent`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("  a\n   b").common_indent(), "  ");
+    /// ```
+    pub fn common_indent(&self) -> &str {
+        self.as_indexable_str().common_indent()
+    }
+
+    /// Returns a copy of the string with the `common_indent` removed from the start of every line, along with an
+    /// `IndexMapping` between the `char` indices of the two strings. See `IndexableStr::dedent`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let (dedented, mapping) = IndexableString::from("  a\n   b").dedent();
+    ///
+    /// assert_eq!(dedented.as_str(), "a\n b");
+    /// assert_eq!(mapping.to_old(3), Some(7));
+    /// ```
+    pub fn dedent(&self) -> (IndexableString, IndexMapping) {
+        self.as_indexable_str().dedent()
+    }
+
+    /// Returns a copy of the string with several edits applied in one pass, along with an `IndexMapping` between the
+    /// `char` indices of the two strings. See `IndexableStr::apply_edits`.
+    /// # Arguments
+    ///
+    /// * `edits` - The ranges of `char`s in this string to replace, and the text to replace each of them with.
+    /// # Panics
+    /// * If a range end is greater than the number of characters in the string.
+    /// * If a range end is less than its range start.
+    /// * If two ranges overlap.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("0😀2");
+    /// let (edited, mapping) = s.apply_edits(&[(0..1, "é中"), (3..3, "😀")]);
+    ///
+    /// assert_eq!(edited.as_str(), "é中😀2😀");
+    /// assert_eq!(mapping.to_new(1), Some(2));
+    /// ```
+    pub fn apply_edits(&self, edits: &[(Range<usize>, &str)]) -> (IndexableString, IndexMapping) {
+        self.as_indexable_str().apply_edits(edits)
+    }
+
+    /// Returns a copy of the string with every occurrence of `from` replaced by `to`, along with an `IndexMapping`
+    /// between the `char` indices of the two strings.
+    /// # Arguments
+    ///
+    /// * `from` - The text to replace. An empty pattern doesn't match anything.
+    /// * `to` - The replacement text.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("a😀b");
+    /// let (replaced, mapping) = s.replace("😀", "");
+    ///
+    /// assert_eq!(replaced.as_str(), "ab");
+    /// assert_eq!(mapping.to_new(2), Some(1));
+    /// ```
+    pub fn replace(&self, from: &str, to: &str) -> (IndexableString, IndexMapping) {
+        self.as_indexable_str().replace(from, to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_maps_every_index() {
+        let s = IndexableStr::new("😀ab😀😀c");
+        let (replaced, mapping) = s.replace("😀", "xyz");
+
+        assert_eq!(replaced.as_str(), "xyzabxyzxyzc");
+
+        let expected_to_new: [Option<usize>; 7] = [None, Some(3), Some(4), None, None, Some(11), Some(12)];
+
+        for (old_index, expected) in expected_to_new.iter().enumerate() {
+            assert_eq!(mapping.to_new(old_index), *expected);
+        }
+
+        for new_index in 0..=replaced.len() {
+            match mapping.to_old(new_index) {
+                Some(old_index) => assert_eq!(mapping.to_new(old_index), Some(new_index)),
+                None => assert!([0, 1, 2, 5, 6, 7, 8, 9, 10].contains(&new_index)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_apply_edits_agrees_with_char_splicing() {
+        let s = IndexableStr::new("0😀2345678😀");
+        let edits: [(Range<usize>, &str); 4] = [(8..10, ""), (0..0, "ab"), (2..5, "中"), (0..0, "c")];
+        let (edited, mapping) = s.apply_edits(&edits);
+
+        assert_eq!(edited.as_str(), "abc0😀中567");
+        assert_eq!(edited.len(), 9);
+
+        let expected_to_new: [Option<usize>; 11] = [Some(3), Some(4), None, None, None, Some(6), Some(7), Some(8), None, None, Some(9)];
+
+        for (old_index, expected) in expected_to_new.iter().enumerate() {
+            assert_eq!(mapping.to_new(old_index), *expected);
+        }
+
+        let expected_to_old: [Option<usize>; 10] = [None, None, None, Some(0), Some(1), None, Some(5), Some(6), Some(7), Some(10)];
+
+        for (new_index, expected) in expected_to_old.iter().enumerate() {
+            assert_eq!(mapping.to_old(new_index), *expected);
+        }
+    }
+
+    #[test]
+    fn test_apply_edits_with_overlapping_ranges_panics() {
+        let s = IndexableStr::new("0😀2345678😀");
+
+        let result = std::panic::catch_unwind(|| s.apply_edits(&[(4..6, "x"), (1..5, "y")]));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_apply_edits_with_ending_index_too_large_panics() {
+        let s = IndexableStr::new("0😀2");
+
+        let result = std::panic::catch_unwind(|| s.apply_edits(&[(2..4, "x")]));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_dedent_maps_back_to_the_original() {
+        let str: &str = "\t\u{3000}if x:\n\t\u{3000}  😀\n\n\t \n\t\u{3000}end";
+        let s = IndexableStr::new(str);
+        let (dedented, mapping) = s.dedent();
+
+        assert_eq!(s.common_indent(), "\t\u{3000}");
+        assert_eq!(dedented.as_str(), "if x:\n  😀\n\n \nend");
+
+        let chars: Vec<char> = str.chars().collect();
+
+        for new_index in 0..dedented.len() {
+            assert_eq!(chars[mapping.to_old(new_index).unwrap()], dedented[new_index]);
+        }
+    }
+
+    #[test]
+    fn test_dedent_without_indent_is_identity() {
+        let (dedented, mapping) = IndexableStr::new("a\n  b\n").dedent();
+
+        assert_eq!(dedented.as_str(), "a\n  b\n");
+        assert!(mapping.is_identity());
+    }
+
+    #[test]
+    fn test_expand_tabs_aligns_to_tab_stops() {
+        let s = IndexableStr::new("\tab\t\tc\n123\t😀");
+        let (expanded, mapping) = s.expand_tabs(3);
+
+        assert_eq!(expanded.as_str(), "   ab    c\n123   😀");
+
+        let columns: Vec<usize> = (0..s.len())
+            .map(|index| expanded.as_indexable_str().position_at(mapping.to_new_start(index)).unwrap().column)
+            .collect();
+
+        assert_eq!(columns, vec![0, 3, 4, 5, 6, 9, 10, 0, 1, 2, 3, 6]);
+
+        let (expanded, _) = IndexableStr::new("中\tx\ne\u{301}\t|").expand_tabs(4);
+
+        assert_eq!(expanded.as_str(), "中  x\ne\u{301}   |");
+        assert_eq!(IndexableStr::new("中\tx").field_at_cols(4..5, 4).as_str(), "x");
+    }
+
+    #[test]
+    fn test_expand_tabs_with_zero_width_panics() {
+        let s = IndexableStr::new("\t");
+
+        let result = std::panic::catch_unwind(|| s.expand_tabs(0));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_normalize_newlines_maps_back_to_the_original() {
+        let str: &str = "\r\r\n😀\r\n\n\r";
+        let s = IndexableStr::new(str);
+        let (normalized, mapping) = s.normalize_newlines();
+
+        assert_eq!(normalized.as_str(), "\n\n😀\n\n\n");
+
+        let old_indices: Vec<usize> = (0..=normalized.len()).map(|new_index| mapping.to_old_start(new_index)).collect();
+
+        assert_eq!(old_indices, vec![0, 2, 3, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_replace_without_matches_is_identity() {
+        let s = IndexableStr::new("0😀2");
+        let (replaced, mapping) = s.replace("x", "y");
+
+        assert_eq!(replaced.as_str(), "0😀2");
+        assert!(mapping.is_identity());
+        assert_eq!(mapping.to_new(2), Some(2));
+    }
+
+    #[test]
+    fn test_replace_with_empty_pattern_is_identity() {
+        let s = IndexableStr::new("0😀2");
+        let (replaced, mapping) = s.replace("", "y");
+
+        assert_eq!(replaced.as_str(), "0😀2");
+        assert!(mapping.is_identity());
+    }
+
+    #[test]
+    fn test_reversing_twice_returns_the_original() {
+        let s = IndexableStr::new("0😀2中e\u{301}👨\u{200D}👩\u{200D}👧");
+        let reversed: IndexableString = s.reversed();
+
+        for index in 0..s.len() {
+            assert_eq!(reversed[s.len() - 1 - index], s[index]);
+        }
+
+        assert_eq!(reversed.reversed().as_str(), s.as_str());
+        assert_eq!(s.reversed_graphemes().reversed_graphemes().as_str(), s.as_str());
+        assert_eq!(s.reversed_graphemes().as_str(), "👨\u{200D}👩\u{200D}👧e\u{301}中2😀0");
+        assert_eq!(IndexableStr::new("").reversed().as_str(), "");
+    }
+
+    #[test]
+    fn test_span_mapper_agrees_with_apply_edits() {
+        let s = IndexableStr::new("0😀23456789");
+        let edits = [(6..8, "x"), (2..2, "😀😀"), (3..5, "")];
+        let (edited, _) = s.apply_edits(&edits);
+        let mapper = SpanMapper::new(&edits);
+
+        assert_eq!(edited.as_str(), "0😀😀😀25x89");
+        assert_eq!(mapper.map_span(Span::new(0, 2)), MappedSpan::Moved(Span::new(0, 2)));
+        assert_eq!(mapper.map_span(Span::new(8, 10)), MappedSpan::Moved(Span::new(7, 9)));
+        assert_eq!(mapper.map_span(Span::new(1, 3)), MappedSpan::Edited(Span::new(1, 5)));
+        assert_eq!(mapper.map_span(Span::new(4, 7)), MappedSpan::Edited(Span::new(5, 7)));
+        assert_eq!(mapper.map_span(Span::new(3, 5)), MappedSpan::Deleted);
+        assert_eq!(mapper.map_span(Span::new(7, 7)), MappedSpan::Moved(Span::new(6, 6)));
+        assert_eq!(SpanMapper::from(s.apply_edits(&edits).1), mapper);
+        assert_eq!(SpanMapper::new(&[(4..4, "INS")]).map_span(Span::new(0, 4)), MappedSpan::Moved(Span::new(0, 4)));
+        assert_eq!(SpanMapper::new(&[(1..2, "x"), (4..4, "INS")]).map_span(Span::new(0, 4)), MappedSpan::Edited(Span::new(0, 4)));
+
+        let result = std::panic::catch_unwind(|| SpanMapper::new(&[(0..2, ""), (1..3, "")]));
+        assert!(result.is_err());
+    }
+}
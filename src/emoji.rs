@@ -0,0 +1,72 @@
+use crate::{unicode, IndexableStr, IndexableString, Offset, Span};
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns an iterator over the `Span`s of the emoji in the string. Each span covers a whole emoji sequence: a
+    /// pictograph with its presentation selector or skin tone modifier, a ZWJ sequence such as a family, a flag or a
+    /// keycap. Symbols such as `'©'` that are shown as text by default are only included when they are followed by
+    /// the emoji presentation selector `'\u{FE0F}'`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("hi👋🏽 👨\u{200D}👩\u{200D}👧 🇯🇵!");
+    /// let spans: Vec<Span> = s.emoji_spans().collect();
+    ///
+    /// assert_eq!(spans, vec![Span::new(2, 4), Span::new(5, 10), Span::new(11, 13)]);
+    /// assert_eq!(&s[spans[1]], "👨\u{200D}👩\u{200D}👧");
+    /// ```
+    pub fn emoji_spans(&self) -> impl Iterator<Item = Span> + 'a {
+        let str: &'a str = self.str;
+        let mut index: usize = 0;
+
+        unicode::grapheme_ranges(str).filter_map(move |range| {
+            let grapheme: &str = &str[range];
+            let start: usize = index;
+
+            index += grapheme.chars().count();
+
+            match unicode::is_emoji(grapheme) {
+                true => Some(Span::new(start, index)),
+                false => None,
+            }
+        })
+    }
+}
+
+impl IndexableString {
+    /// Returns an iterator over the `Span`s of the emoji in the string. See `IndexableStr::emoji_spans`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableString, Span};
+    ///
+    /// let s = IndexableString::from("ok 👍");
+    ///
+    /// assert_eq!(s.emoji_spans().collect::<Vec<Span>>(), vec![Span::new(3, 4)]);
+    /// ```
+    pub fn emoji_spans(&self) -> impl Iterator<Item = Span> + '_ {
+        self.as_indexable_str().emoji_spans()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_removing_emoji_spans_leaves_the_text() {
+        let s = IndexableStr::new("a😀b\u{2764}\u{FE0F}c©d#\u{FE0F}\u{20E3}e");
+        let mut text: String = String::new();
+        let mut index: usize = 0;
+
+        for span in s.emoji_spans() {
+            text.push_str(&s[index..span.start]);
+            index = span.end;
+        }
+
+        text.push_str(&s[index..]);
+
+        assert_eq!(text, "abc©de");
+    }
+}
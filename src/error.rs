@@ -0,0 +1,107 @@
+use std::{error::Error, fmt::Display, str::Utf8Error, string::FromUtf8Error};
+
+/// `IndexError` is every way that an index, range or byte offset into an indexable string can be invalid, and the
+/// UTF-8 or UTF-16 error of a constructor that validates its input. The panics of the `Index` impls use the same
+/// messages.
+///
+/// # Examples
+/// ```
+/// use indexable_str::{Checked, IndexError, IndexableStr};
+///
+/// let s = Checked::new(IndexableStr::new("0😀2"));
+///
+/// assert_eq!(s.char_at(3), Err(IndexError::IndexOutOfBounds { index: 3, len: 3 }));
+/// assert_eq!(s.slice(2..1), Err(IndexError::RangeStartAfterEnd { start: 2, end: 1 }));
+/// assert_eq!(
+///     s.char_at(3).unwrap_err().to_string(),
+///     "Index: (3) must be less than the number of UTF-8 characters in the string (3)!"
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IndexError {
+    /// A `char` index is greater than or equal to the number of `char`s in the string.
+    IndexOutOfBounds { index: usize, len: usize },
+    /// The end of a range is greater than the number of `char`s in the string.
+    RangeEndOutOfBounds { end: usize, len: usize },
+    /// The start of a range is greater than its end.
+    RangeStartAfterEnd { start: usize, end: usize },
+    /// A byte offset isn't on a `char` boundary, or is past the end of the string.
+    NotCharBoundary { byte_offset: usize },
+    /// The input isn't valid UTF-8, starting at `byte_offset`.
+    InvalidUtf8 { byte_offset: usize },
+    /// The input isn't valid UTF-16, because of the unpaired surrogate at `unit_offset`.
+    InvalidUtf16 { unit_offset: usize },
+}
+
+impl Display for IndexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IndexError::IndexOutOfBounds { index, len } => {
+                write!(f, "Index: ({index}) must be less than the number of UTF-8 characters in the string ({len})!")
+            },
+            IndexError::RangeEndOutOfBounds { end, len } => {
+                write!(f, "Range end: ({end}) must be less than or equal to the number of UTF-8 characters in the string ({len})!")
+            },
+            IndexError::RangeStartAfterEnd { start, end } => {
+                write!(f, "Range end: ({end}) must be greater than or equal to Range start: ({start})!")
+            },
+            IndexError::NotCharBoundary { byte_offset } => {
+                write!(f, "Byte offset: ({byte_offset}) must be on a UTF-8 character boundary!")
+            },
+            IndexError::InvalidUtf8 { byte_offset } => write!(f, "Invalid UTF-8 at byte offset ({byte_offset})!"),
+            IndexError::InvalidUtf16 { unit_offset } => write!(f, "Unpaired UTF-16 surrogate at code unit offset ({unit_offset})!"),
+        }
+    }
+}
+
+impl Error for IndexError {}
+
+impl From<Utf8Error> for IndexError {
+    fn from(error: Utf8Error) -> Self {
+        IndexError::InvalidUtf8 {
+            byte_offset: error.valid_up_to(),
+        }
+    }
+}
+
+impl From<FromUtf8Error> for IndexError {
+    fn from(error: FromUtf8Error) -> Self {
+        IndexError::from(error.utf8_error())
+    }
+}
+
+/// Returns an error if `index` isn't the index of a `char` in a string of `len` `char`s.
+pub(crate) fn check_index(index: usize, len: usize) -> Result<(), IndexError> {
+    match index < len {
+        true => Ok(()),
+        false => Err(IndexError::IndexOutOfBounds { index, len }),
+    }
+}
+
+/// Returns an error if `start..end` isn't a range of `char`s in a string of `len` `char`s.
+pub(crate) fn check_range(start: usize, end: usize, len: usize) -> Result<(), IndexError> {
+    if end > len {
+        return Err(IndexError::RangeEndOutOfBounds { end, len });
+    }
+
+    if end < start {
+        return Err(IndexError::RangeStartAfterEnd { start, end });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utf8_errors_convert_to_invalid_utf8() {
+        let error: IndexError = String::from_utf8(vec![b'0', b'1', 0xFF]).unwrap_err().into();
+
+        assert_eq!(error, IndexError::InvalidUtf8 { byte_offset: 2 });
+        assert_eq!(error.to_string(), "Invalid UTF-8 at byte offset (2)!");
+        assert_eq!(check_range(1, 0, 2), Err(IndexError::RangeStartAfterEnd { start: 1, end: 0 }));
+        assert_eq!(check_range(1, 3, 2), Err(IndexError::RangeEndOutOfBounds { end: 3, len: 2 }));
+    }
+}
@@ -0,0 +1,364 @@
+use std::{error::Error, fmt::Display};
+
+use crate::{IndexableStr, IndexableString, IndexableStringBuilder, Offset, Span};
+
+/// `EscapeStyle` chooses the escape sequences that `IndexableStr::unescape` decodes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EscapeStyle {
+    /// Rust string literals: `\n`, `\r`, `\t`, `\\`, `\0`, `\'`, `\"`, `\x7F`, `\u{1F600}`, and a backslash before a
+    /// newline, which skips the newline and the whitespace after it.
+    Rust,
+    /// JSON strings: `\"`, `\\`, `\/`, `\b`, `\f`, `\n`, `\r`, `\t` and `\u0041`, with surrogate pairs such as
+    /// `\uD83D\uDE00` decoded to one `char`.
+    Json,
+    /// C string literals: `\a`, `\b`, `\f`, `\n`, `\r`, `\t`, `\v`, `\\`, `\'`, `\"`, `\?`, octal escapes such as `\101`,
+    /// hexadecimal escapes such as `\x41`, and `\u0041` and `\U0001F600`. Octal and hexadecimal escapes are decoded as
+    /// Unicode code points.
+    C,
+}
+
+/// `UnescapeError` is an escape sequence that `IndexableStr::unescape` couldn't decode. Its `span` is the range of
+/// `char`s of the escape sequence in the original string.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum UnescapeError {
+    /// The `char` after the backslash doesn't start an escape sequence in the `EscapeStyle`.
+    UnknownEscape { span: Span },
+    /// The escape sequence is missing `char`s, such as hexadecimal digits or a closing brace, or the string ends with
+    /// a backslash.
+    MalformedEscape { span: Span },
+    /// The escape sequence is well formed, but its value isn't a `char`, such as a lone surrogate or a value above
+    /// `0x10FFFF`.
+    InvalidCodePoint { span: Span },
+}
+
+impl UnescapeError {
+    /// Returns the range of `char`s of the escape sequence in the original string.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{EscapeStyle, IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new(r"a\qb");
+    ///
+    /// assert_eq!(s.unescape(EscapeStyle::Rust).err().unwrap().span(), Span::new(1, 3));
+    /// ```
+    pub fn span(&self) -> Span {
+        match self {
+            UnescapeError::UnknownEscape { span } | UnescapeError::MalformedEscape { span } | UnescapeError::InvalidCodePoint { span } => *span,
+        }
+    }
+}
+
+impl Display for UnescapeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let span: Span = self.span();
+
+        match self {
+            UnescapeError::UnknownEscape { .. } => write!(f, "Unknown escape sequence at chars ({}..{})!", span.start, span.end),
+            UnescapeError::MalformedEscape { .. } => write!(f, "Malformed escape sequence at chars ({}..{})!", span.start, span.end),
+            UnescapeError::InvalidCodePoint { .. } => write!(f, "Invalid code point in escape sequence at chars ({}..{})!", span.start, span.end),
+        }
+    }
+}
+
+impl Error for UnescapeError {}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns the string with its escape sequences decoded, along with the `Span` of the original `char`s that each
+    /// decoded `char` came from. A `char` that isn't escaped has a span of one `char`, and an escaped `char` has the
+    /// span of its whole escape sequence, so errors found in the decoded value can point at the source text.
+    /// # Arguments
+    ///
+    /// * `style` - The escape sequences to decode.
+    /// # Errors
+    /// * If an escape sequence is unknown, malformed or doesn't encode a `char`. The error holds its span.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{EscapeStyle, IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new(r"a\u{1F600}\n");
+    /// let (decoded, spans) = s.unescape(EscapeStyle::Rust).unwrap();
+    ///
+    /// assert_eq!(decoded.as_str(), "a😀\n");
+    /// assert_eq!(spans, vec![Span::new(0, 1), Span::new(1, 10), Span::new(10, 12)]);
+    ///
+    /// let (decoded, spans) = IndexableStr::new(r"\uD83D\uDE00!").unescape(EscapeStyle::Json).unwrap();
+    ///
+    /// assert_eq!(decoded.as_str(), "😀!");
+    /// assert_eq!(spans[0], Span::new(0, 12));
+    /// ```
+    pub fn unescape(&self, style: EscapeStyle) -> Result<(IndexableString, Vec<Span>), UnescapeError> {
+        let chars: Vec<char> = self.str.chars().collect();
+        let mut builder: IndexableStringBuilder = IndexableStringBuilder::with_capacity(self.str_length);
+        let mut spans: Vec<Span> = Vec::with_capacity(chars.len());
+        let mut index: usize = 0;
+
+        while index < chars.len() {
+            let (decoded, end) = match chars[index] {
+                '\\' => unescape_sequence(&chars, index, style)?,
+                chr => (Some(chr), index + 1),
+            };
+
+            if let Some(chr) = decoded {
+                builder.push_char(chr);
+                spans.push(Span::new(index, end));
+            }
+
+            index = end;
+        }
+
+        Ok((builder.finish(), spans))
+    }
+
+    /// Returns the string with its URL percent escapes decoded, along with the `Span` of the original `char`s that
+    /// each decoded `char` came from.
+    ///
+    /// Consecutive escapes are decoded together as UTF-8, so `"%E2%82%AC"` becomes one `'€'` with a span of nine
+    /// `char`s. Bytes that aren't valid UTF-8 become `char::REPLACEMENT_CHARACTER`, and a `'%'` that isn't followed by
+    /// two hexadecimal digits is kept as it is. `'+'` isn't decoded to a space.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("/a%20b/%E2%82%AC%zz");
+    /// let (decoded, spans) = s.percent_decode();
+    ///
+    /// assert_eq!(decoded.as_str(), "/a b/€%zz");
+    /// assert_eq!(spans[2], Span::new(2, 5));
+    /// assert_eq!(spans[5], Span::new(7, 16));
+    /// assert_eq!(spans[6], Span::new(16, 17));
+    /// ```
+    pub fn percent_decode(&self) -> (IndexableString, Vec<Span>) {
+        let chars: Vec<char> = self.str.chars().collect();
+        let mut builder: IndexableStringBuilder = IndexableStringBuilder::with_capacity(self.str_length);
+        let mut spans: Vec<Span> = Vec::with_capacity(chars.len());
+        let mut bytes: Vec<u8> = Vec::new();
+        let mut bytes_start: usize = 0;
+        let mut index: usize = 0;
+
+        while index < chars.len() {
+            let byte: Option<u32> = match chars[index] {
+                '%' => match (chars.get(index + 1).and_then(|chr| chr.to_digit(16)), chars.get(index + 2).and_then(|chr| chr.to_digit(16))) {
+                    (Some(high), Some(low)) => Some(high * 16 + low),
+                    _ => None,
+                },
+                _ => None,
+            };
+
+            match byte {
+                Some(byte) => {
+                    if bytes.is_empty() {
+                        bytes_start = index;
+                    }
+
+                    bytes.push(byte as u8);
+                    index += 3;
+                },
+                None => {
+                    push_percent_decoded(&mut builder, &mut spans, &bytes, bytes_start);
+                    bytes.clear();
+                    builder.push_char(chars[index]);
+                    spans.push(Span::new(index, index + 1));
+                    index += 1;
+                },
+            }
+        }
+
+        push_percent_decoded(&mut builder, &mut spans, &bytes, bytes_start);
+
+        (builder.finish(), spans)
+    }
+}
+
+impl IndexableString {
+    /// Returns the string with its escape sequences decoded, along with the `Span` of the original `char`s that each
+    /// decoded `char` came from. See `IndexableStr::unescape`.
+    /// # Arguments
+    ///
+    /// * `style` - The escape sequences to decode.
+    /// # Errors
+    /// * If an escape sequence is unknown, malformed or doesn't encode a `char`. The error holds its span.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{EscapeStyle, IndexableString};
+    ///
+    /// let (decoded, _) = IndexableString::from(r"\101\x42\?").unescape(EscapeStyle::C).unwrap();
+    ///
+    /// assert_eq!(decoded.as_str(), "AB?");
+    /// ```
+    pub fn unescape(&self, style: EscapeStyle) -> Result<(IndexableString, Vec<Span>), UnescapeError> {
+        self.as_indexable_str().unescape(style)
+    }
+
+    /// Returns the string with its URL percent escapes decoded, along with the `Span` of the original `char`s that
+    /// each decoded `char` came from. See `IndexableStr::percent_decode`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableString, Span};
+    ///
+    /// let (decoded, spans) = IndexableString::from("%C3%A9!").percent_decode();
+    ///
+    /// assert_eq!(decoded.as_str(), "é!");
+    /// assert_eq!(spans, vec![Span::new(0, 6), Span::new(6, 7)]);
+    /// ```
+    pub fn percent_decode(&self) -> (IndexableString, Vec<Span>) {
+        self.as_indexable_str().percent_decode()
+    }
+}
+
+/// Decodes the bytes of consecutive percent escapes that start at the `char` index `bytes_start`. Each byte came from
+/// three `char`s.
+fn push_percent_decoded(builder: &mut IndexableStringBuilder, spans: &mut Vec<Span>, bytes: &[u8], bytes_start: usize) {
+    let mut byte_offset: usize = 0;
+
+    for chunk in bytes.utf8_chunks() {
+        for chr in chunk.valid().chars() {
+            builder.push_char(chr);
+            spans.push(Span::new(bytes_start + byte_offset * 3, bytes_start + (byte_offset + chr.len_utf8()) * 3));
+            byte_offset += chr.len_utf8();
+        }
+
+        if !chunk.invalid().is_empty() {
+            builder.push_char(char::REPLACEMENT_CHARACTER);
+            spans.push(Span::new(bytes_start + byte_offset * 3, bytes_start + (byte_offset + chunk.invalid().len()) * 3));
+            byte_offset += chunk.invalid().len();
+        }
+    }
+}
+
+/// Decodes the escape sequence whose backslash is at `chars[start]`. Returns the decoded `char`, which is `None` for a
+/// line continuation, and the index after the escape sequence.
+fn unescape_sequence(chars: &[char], start: usize, style: EscapeStyle) -> Result<(Option<char>, usize), UnescapeError> {
+    let escape: char = match chars.get(start + 1) {
+        Some(&chr) => chr,
+        None => return Err(UnescapeError::MalformedEscape { span: Span::new(start, start + 1) }),
+    };
+
+    let simple: Option<char> = match (style, escape) {
+        (_, 'n') => Some('\n'),
+        (_, 'r') => Some('\r'),
+        (_, 't') => Some('\t'),
+        (_, '\\' | '"') => Some(escape),
+        (EscapeStyle::Rust | EscapeStyle::C, '\'') => Some('\''),
+        (EscapeStyle::Rust, '0') => Some('\0'),
+        (EscapeStyle::Json | EscapeStyle::C, 'b') => Some('\u{8}'),
+        (EscapeStyle::Json | EscapeStyle::C, 'f') => Some('\u{C}'),
+        (EscapeStyle::Json, '/') => Some('/'),
+        (EscapeStyle::C, 'a') => Some('\u{7}'),
+        (EscapeStyle::C, 'v') => Some('\u{B}'),
+        (EscapeStyle::C, '?') => Some('?'),
+        _ => None,
+    };
+
+    if simple.is_some() {
+        return Ok((simple, start + 2));
+    }
+
+    let (code_point, end) = match (style, escape) {
+        (EscapeStyle::Rust, '\n') => {
+            let end: usize = start + 2 + chars[start + 2..].iter().take_while(|chr| chr.is_whitespace()).count();
+
+            return Ok((None, end));
+        },
+        (EscapeStyle::Rust, 'x') => match digits(chars, start, start + 2, 16, 2, 2)? {
+            (code_point, end) if code_point > 0x7F => return Err(UnescapeError::InvalidCodePoint { span: Span::new(start, end) }),
+            value => value,
+        },
+        (EscapeStyle::Rust, 'u') => {
+            if chars.get(start + 2) != Some(&'{') {
+                return Err(UnescapeError::MalformedEscape { span: Span::new(start, start + 2) });
+            }
+
+            let (code_point, end) = digits(chars, start, start + 3, 16, 1, 6)?;
+
+            match chars.get(end) {
+                Some('}') => (code_point, end + 1),
+                _ => return Err(UnescapeError::MalformedEscape { span: Span::new(start, end) }),
+            }
+        },
+        (EscapeStyle::Json, 'u') => json_code_point(chars, start)?,
+        (EscapeStyle::C, 'x') => digits(chars, start, start + 2, 16, 1, usize::MAX)?,
+        (EscapeStyle::C, '0'..='7') => digits(chars, start, start + 1, 8, 1, 3)?,
+        (EscapeStyle::C, 'u') => digits(chars, start, start + 2, 16, 4, 4)?,
+        (EscapeStyle::C, 'U') => digits(chars, start, start + 2, 16, 8, 8)?,
+        _ => return Err(UnescapeError::UnknownEscape { span: Span::new(start, start + 2) }),
+    };
+
+    match char::from_u32(code_point) {
+        Some(chr) => Ok((Some(chr), end)),
+        None => Err(UnescapeError::InvalidCodePoint { span: Span::new(start, end) }),
+    }
+}
+
+/// Reads between `min` and `max` digits in `radix` from `chars[digits_start..]`, for the escape sequence that starts at
+/// `start`. Returns their value, saturated at `u32::MAX`, and the index after them.
+fn digits(chars: &[char], start: usize, digits_start: usize, radix: u32, min: usize, max: usize) -> Result<(u32, usize), UnescapeError> {
+    let mut value: u32 = 0;
+    let mut end: usize = digits_start;
+
+    while end - digits_start < max {
+        match chars.get(end).and_then(|chr| chr.to_digit(radix)) {
+            Some(digit) => value = value.saturating_mul(radix).saturating_add(digit),
+            None => break,
+        }
+
+        end += 1;
+    }
+
+    match end - digits_start < min {
+        true => Err(UnescapeError::MalformedEscape { span: Span::new(start, end) }),
+        false => Ok((value, end)),
+    }
+}
+
+/// Reads a JSON `\u` escape that starts at `start`, and the low surrogate escape after it if it's a high surrogate.
+fn json_code_point(chars: &[char], start: usize) -> Result<(u32, usize), UnescapeError> {
+    let (high, end) = digits(chars, start, start + 2, 16, 4, 4)?;
+
+    match high {
+        0xD800..=0xDBFF if chars.get(end) == Some(&'\\') && chars.get(end + 1) == Some(&'u') => match digits(chars, end, end + 2, 16, 4, 4)? {
+            (low @ 0xDC00..=0xDFFF, low_end) => Ok((0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00), low_end)),
+            _ => Err(UnescapeError::InvalidCodePoint { span: Span::new(start, end) }),
+        },
+        _ => Ok((high, end)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unescaped(str: &str, style: EscapeStyle) -> Result<String, UnescapeError> {
+        IndexableStr::new(str).unescape(style).map(|(decoded, _)| decoded.into_string())
+    }
+
+    #[test]
+    fn test_each_style_decodes_its_escapes() {
+        assert_eq!(unescaped(r#"\t\\\"\'\0\x41\u{e9}"#, EscapeStyle::Rust), Ok("\t\\\"'\0Aé".to_string()));
+        assert_eq!(unescaped("a\\\n   b", EscapeStyle::Rust), Ok("ab".to_string()));
+        assert_eq!(unescaped(r#"\/\b\f\u00e9\ud83d\ude00"#, EscapeStyle::Json), Ok("/\u{8}\u{C}é😀".to_string()));
+        assert_eq!(unescaped(r"\a\v\0\7\1234\x1F600\u00e9\U0001F600", EscapeStyle::C), Ok("\u{7}\u{B}\0\u{7}S4😀é😀".to_string()));
+    }
+
+    #[test]
+    fn test_percent_decode_replaces_invalid_utf8() {
+        let (decoded, spans) = IndexableStr::new("%41%FF%e2%82😀%").percent_decode();
+
+        assert_eq!(decoded.as_str(), "A\u{FFFD}\u{FFFD}😀%");
+        assert_eq!(spans, vec![Span::new(0, 3), Span::new(3, 6), Span::new(6, 12), Span::new(12, 13), Span::new(13, 14)]);
+    }
+
+    #[test]
+    fn test_errors_hold_the_span_of_the_escape() {
+        assert_eq!(unescaped(r"ab\", EscapeStyle::Rust), Err(UnescapeError::MalformedEscape { span: Span::new(2, 3) }));
+        assert_eq!(unescaped(r"😀\/", EscapeStyle::Rust), Err(UnescapeError::UnknownEscape { span: Span::new(1, 3) }));
+        assert_eq!(unescaped(r"\x80", EscapeStyle::Rust), Err(UnescapeError::InvalidCodePoint { span: Span::new(0, 4) }));
+        assert_eq!(unescaped(r"\u{123", EscapeStyle::Rust), Err(UnescapeError::MalformedEscape { span: Span::new(0, 6) }));
+        assert_eq!(unescaped(r"\u{D800}", EscapeStyle::Rust), Err(UnescapeError::InvalidCodePoint { span: Span::new(0, 8) }));
+        assert_eq!(unescaped(r"\ud83dx", EscapeStyle::Json), Err(UnescapeError::InvalidCodePoint { span: Span::new(0, 6) }));
+        assert_eq!(unescaped(r"\u12", EscapeStyle::Json), Err(UnescapeError::MalformedEscape { span: Span::new(0, 4) }));
+        assert_eq!(unescaped(r"\xFFFFFFFFF", EscapeStyle::C), Err(UnescapeError::InvalidCodePoint { span: Span::new(0, 11) }));
+    }
+}
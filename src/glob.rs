@@ -0,0 +1,143 @@
+use crate::{IndexableStr, IndexableString, Offset, Span};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum GlobToken {
+    Char(char),
+    AnyChar,
+    AnyRun,
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Matches the whole string against a glob pattern, and returns the `Span` of the `char`s that each wildcard
+    /// matched, in the order of the wildcards in the pattern. Returns `None` if the string doesn't match.
+    ///
+    /// `'?'` matches any one `char`, and `'*'` matches any run of `char`s, including an empty one. When a string can
+    /// be matched in more than one way, each `'*'` matches as few `char`s as it can, from left to right. A `'\\'`
+    /// makes the `char` after it match itself.
+    /// # Arguments
+    ///
+    /// * `pattern` - The glob pattern.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("GET /😀/items/42");
+    /// let captures: Vec<Span> = s.match_glob("GET /*/items/*").unwrap();
+    ///
+    /// assert_eq!(captures, vec![Span::new(5, 6), Span::new(13, 15)]);
+    /// assert_eq!(&s[captures[1]], "42");
+    /// assert_eq!(s.match_glob("GET /?/*/4?").unwrap()[1], Span::new(7, 12));
+    /// assert_eq!(s.match_glob("POST *"), None);
+    /// assert_eq!(IndexableStr::new("a*").match_glob(r"a\*"), Some(Vec::new()));
+    /// ```
+    pub fn match_glob(&self, pattern: &str) -> Option<Vec<Span>> {
+        let tokens: Vec<GlobToken> = glob_tokens(pattern);
+        let capture_indices: Vec<usize> = tokens
+            .iter()
+            .scan(0, |count, token| {
+                let index: usize = *count;
+
+                *count += !matches!(token, GlobToken::Char(_)) as usize;
+
+                Some(index)
+            })
+            .collect();
+        let mut captures: Vec<Span> = vec![Span::default(); tokens.iter().filter(|token| !matches!(token, GlobToken::Char(_))).count()];
+        let chars: Vec<char> = self.str.chars().collect();
+        // The token index of the last `'*'`, where its match started, and where it ends.
+        let mut backtrack: Option<(usize, usize, usize)> = None;
+        let mut token_index: usize = 0;
+        let mut index: usize = 0;
+
+        while index < chars.len() {
+            match tokens.get(token_index) {
+                Some(GlobToken::Char(chr)) if *chr == chars[index] => {
+                    token_index += 1;
+                    index += 1;
+                },
+                Some(GlobToken::AnyChar) => {
+                    captures[capture_indices[token_index]] = Span::new(index, index + 1);
+                    token_index += 1;
+                    index += 1;
+                },
+                Some(GlobToken::AnyRun) => {
+                    captures[capture_indices[token_index]] = Span::new(index, index);
+                    backtrack = Some((token_index, index, index));
+                    token_index += 1;
+                },
+                _ => {
+                    let (star_index, start, end) = backtrack?;
+
+                    captures[capture_indices[star_index]] = Span::new(start, end + 1);
+                    backtrack = Some((star_index, start, end + 1));
+                    token_index = star_index + 1;
+                    index = end + 1;
+                },
+            }
+        }
+
+        while tokens.get(token_index) == Some(&GlobToken::AnyRun) {
+            captures[capture_indices[token_index]] = Span::new(index, index);
+            token_index += 1;
+        }
+
+        match token_index == tokens.len() {
+            true => Some(captures),
+            false => None,
+        }
+    }
+}
+
+impl IndexableString {
+    /// Matches the whole string against a glob pattern, and returns the `Span` of the `char`s that each wildcard
+    /// matched. See `IndexableStr::match_glob`.
+    /// # Arguments
+    ///
+    /// * `pattern` - The glob pattern.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableString, Span};
+    ///
+    /// let s = IndexableString::from("error: disk full");
+    ///
+    /// assert_eq!(s.match_glob("*: *"), Some(vec![Span::new(0, 5), Span::new(7, 16)]));
+    /// ```
+    pub fn match_glob(&self, pattern: &str) -> Option<Vec<Span>> {
+        self.as_indexable_str().match_glob(pattern)
+    }
+}
+
+fn glob_tokens(pattern: &str) -> Vec<GlobToken> {
+    let mut tokens: Vec<GlobToken> = Vec::new();
+    let mut chars = pattern.chars();
+
+    while let Some(chr) = chars.next() {
+        tokens.push(match chr {
+            '?' => GlobToken::AnyChar,
+            '*' => GlobToken::AnyRun,
+            '\\' => GlobToken::Char(chars.next().unwrap_or('\\')),
+            _ => GlobToken::Char(chr),
+        });
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_glob_backtracks_into_the_last_star() {
+        let s = IndexableStr::new("a😀b😀b😀c");
+
+        assert_eq!(s.match_glob("a*b*c"), Some(vec![Span::new(1, 2), Span::new(3, 6)]));
+        assert_eq!(s.match_glob("*😀?"), Some(vec![Span::new(0, 5), Span::new(6, 7)]));
+        assert_eq!(s.match_glob("a*b*d"), None);
+        assert_eq!(s.match_glob("*"), Some(vec![Span::new(0, 7)]));
+        assert_eq!(s.match_glob("a😀b😀b😀c**"), Some(vec![Span::new(7, 7), Span::new(7, 7)]));
+        assert_eq!(IndexableStr::new("").match_glob("*"), Some(vec![Span::new(0, 0)]));
+        assert_eq!(IndexableStr::new("").match_glob("?"), None);
+        assert_eq!(IndexableStr::new("ab").match_glob("a"), None);
+    }
+}
@@ -0,0 +1,74 @@
+use std::{ops::Range, sync::OnceLock};
+
+use crate::{unicode, IndexableStr, IndexableString, Offset};
+
+/// `GraphemeBoundary` is the start of a grapheme cluster, or the end of the string.
+#[derive(Copy, Clone)]
+pub(crate) struct GraphemeBoundary {
+    pub(crate) byte_offset: usize,
+    pub(crate) index: usize,
+}
+
+/// `GraphemeCache` holds the grapheme cluster boundaries of a string, from its start to its end. The string is only
+/// segmented the first time a grapheme API needs them, and the boundaries are kept until the string is edited.
+pub(crate) type GraphemeCache = OnceLock<Box<[GraphemeBoundary]>>;
+
+/// Returns the grapheme cluster boundaries of `str` from `cache`, segmenting `str` if they haven't been yet.
+fn boundaries<'c>(cache: &'c GraphemeCache, str: &str) -> &'c [GraphemeBoundary] {
+    cache.get_or_init(|| {
+        let mut boundaries: Vec<GraphemeBoundary> = vec![GraphemeBoundary { byte_offset: 0, index: 0 }];
+
+        for range in unicode::grapheme_ranges(str) {
+            let index: usize = boundaries[boundaries.len() - 1].index + str[range.clone()].chars().count();
+
+            boundaries.push(GraphemeBoundary { byte_offset: range.end, index });
+        }
+
+        boundaries.into_boxed_slice()
+    })
+}
+
+/// Returns the number of bytes allocated on the heap for `cache`.
+pub(crate) fn memory_usage(cache: &GraphemeCache) -> usize {
+    cache.get().map_or(0, |boundaries| std::mem::size_of_val(&**boundaries))
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns the grapheme cluster boundaries of the string, segmenting it on first use.
+    pub(crate) fn grapheme_boundaries(&self) -> &[GraphemeBoundary] {
+        boundaries(&self.graphemes, self.str)
+    }
+
+    /// Returns an iterator over the byte ranges of the grapheme clusters of the string.
+    pub(crate) fn grapheme_ranges(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        self.grapheme_boundaries().windows(2).map(|pair| pair[0].byte_offset..pair[1].byte_offset)
+    }
+}
+
+impl IndexableString {
+    /// Returns the grapheme cluster boundaries of the string, segmenting it on first use.
+    pub(crate) fn grapheme_boundaries(&self) -> &[GraphemeBoundary] {
+        boundaries(self.graphemes(), self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_graphemes_are_segmented_once_until_an_edit() {
+        let mut s = IndexableString::from("ae\u{301}👍🏽");
+
+        assert!(s.graphemes().get().is_none());
+        assert_eq!(s.display_width(), 4);
+        assert_eq!(s.grapheme_boundaries().iter().map(|boundary| boundary.index).collect::<Vec<usize>>(), vec![0, 1, 3, 5]);
+        assert!(s.graphemes().get().is_some());
+
+        s.edit(1..3, "中");
+
+        assert!(s.graphemes().get().is_none());
+        assert_eq!(s.display_width(), 5);
+        assert_eq!(s.as_indexable_str().grapheme_ranges().collect::<Vec<Range<usize>>>(), vec![0..1, 1..4, 4..12]);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{IndexableStr, IndexableString, Offset, Span};
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns an iterator over the maximal runs of consecutive `char`s that `classifier` puts in the same class, with
+    /// the class, the `Span` and the text of each run.
+    /// # Arguments
+    ///
+    /// * `classifier` - A closure that returns the class of a `char`.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Class { Digit, Letter, Other }
+    ///
+    /// let s = IndexableStr::new("x42😀😀ab");
+    /// let runs: Vec<_> = s
+    ///     .group_by(|chr| match chr {
+    ///         '0'..='9' => Class::Digit,
+    ///         chr if chr.is_alphabetic() => Class::Letter,
+    ///         _ => Class::Other,
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(runs[1], (Class::Digit, Span::new(1, 3), "42"));
+    /// assert_eq!(runs[2], (Class::Other, Span::new(3, 5), "😀😀"));
+    /// assert_eq!(runs.len(), 4);
+    /// ```
+    pub fn group_by<C: PartialEq + 'a>(&self, mut classifier: impl FnMut(char) -> C + 'a) -> impl Iterator<Item = (C, Span, &'a str)> + 'a {
+        let str: &'a str = self.str;
+        let mut classes = str.char_indices().map(move |(byte_offset, chr)| (byte_offset, classifier(chr))).peekable();
+        let mut index: usize = 0;
+
+        std::iter::from_fn(move || {
+            let (byte_start, class) = classes.next()?;
+            let start: usize = index;
+            let mut byte_end: usize = str.len();
+
+            index += 1;
+
+            while let Some((byte_offset, next)) = classes.peek() {
+                if *next != class {
+                    byte_end = *byte_offset;
+                    break;
+                }
+
+                index += 1;
+                classes.next();
+            }
+
+            Some((class, Span::new(start, index), &str[byte_start..byte_end]))
+        })
+    }
+}
+
+impl IndexableString {
+    /// Returns an iterator over the maximal runs of consecutive `char`s in the same class. See
+    /// `IndexableStr::group_by`.
+    /// # Arguments
+    ///
+    /// * `classifier` - A closure that returns the class of a `char`.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableString, Span};
+    ///
+    /// let s = IndexableString::from("😀  b");
+    ///
+    /// assert_eq!(s.group_by(char::is_whitespace).nth(1), Some((true, Span::new(1, 3), "  ")));
+    /// ```
+    pub fn group_by<'s, C: PartialEq + 's>(&'s self, classifier: impl FnMut(char) -> C + 's) -> impl Iterator<Item = (C, Span, &'s str)> + 's {
+        self.as_indexable_str().group_by(classifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runs_cover_the_string_and_alternate_classes() {
+        let s = IndexableStr::new("aa😀😀b中文 1");
+        let runs: Vec<(bool, Span, &str)> = s.group_by(|chr| chr.is_ascii()).collect();
+
+        assert_eq!(runs.iter().map(|&(_, _, text)| text).collect::<String>(), s.as_str());
+        assert!(runs.windows(2).all(|pair| pair[0].0 != pair[1].0 && pair[0].1.end == pair[1].1.start));
+        assert!(runs.iter().all(|&(_, span, text)| &s[span] == text));
+        assert_eq!(IndexableStr::new("").group_by(|chr| chr).count(), 0);
+    }
+}
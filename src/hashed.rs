@@ -0,0 +1,119 @@
+use std::{
+    borrow::Cow,
+    collections::hash_map::DefaultHasher,
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
+
+use crate::IndexableText;
+
+/// `Hashed` wraps an indexable string with the hash of its text, computed once when it's created. Hashing the
+/// wrapper only writes the stored hash, and comparing two of them only compares their text when the hashes are
+/// equal, so repeated `HashMap` and `HashSet` operations don't read the text again.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use indexable_str::{Hashed, IndexableString};
+///
+/// let mut documents: HashSet<Hashed<IndexableString>> = HashSet::new();
+///
+/// assert!(documents.insert(Hashed::new(IndexableString::from("0😀2"))));
+/// assert!(documents.insert(Hashed::new(IndexableString::from("012"))));
+/// assert!(!documents.insert(Hashed::new(IndexableString::from("0😀2"))));
+/// assert_eq!(documents.len(), 2);
+/// ```
+#[derive(Clone)]
+pub struct Hashed<T> {
+    value: T,
+    hash: u64,
+}
+
+impl<T: IndexableText> Hashed<T> {
+    /// Returns the wrapped string after hashing its text.
+    /// # Arguments
+    ///
+    /// * `value` - The indexable string to wrap.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{Hashed, IndexableStr};
+    ///
+    /// let a = Hashed::new(IndexableStr::new("0😀2"));
+    /// let b = Hashed::new(IndexableStr::new("0😀2"));
+    ///
+    /// assert_eq!(a.hash_value(), b.hash_value());
+    /// assert!(a == b);
+    /// ```
+    pub fn new(value: T) -> Hashed<T> {
+        let mut hasher: DefaultHasher = DefaultHasher::new();
+
+        Hashed::text(&value).hash(&mut hasher);
+
+        Hashed { value, hash: hasher.finish() }
+    }
+
+    fn text(value: &T) -> Cow<'_, str> {
+        value.char_slice(0..value.char_count()).unwrap()
+    }
+}
+
+impl<T> Hashed<T> {
+    /// Returns the stored hash of the text.
+    pub fn hash_value(&self) -> u64 {
+        self.hash
+    }
+
+    /// Returns the wrapped string.
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the wrapped string, consuming the wrapper.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Hash for Hashed<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+impl<T: IndexableText> PartialEq for Hashed<T> {
+    fn eq(&self, other: &Hashed<T>) -> bool {
+        self.hash == other.hash && Hashed::text(&self.value) == Hashed::text(&other.value)
+    }
+}
+
+impl<T: IndexableText> Eq for Hashed<T> {}
+
+impl<T: Display> Display for Hashed<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{IndexablePieceTable, IndexableString};
+
+    #[test]
+    fn test_hash_depends_only_on_the_text() {
+        let mut piece_table = IndexablePieceTable::new("0😀");
+
+        piece_table.edit(2..2, "2");
+
+        let mut counts: HashMap<Hashed<IndexablePieceTable>, usize> = HashMap::new();
+
+        *counts.entry(Hashed::new(piece_table)).or_default() += 1;
+        *counts.entry(Hashed::new(IndexablePieceTable::new("0😀2"))).or_default() += 1;
+
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts.values().next(), Some(&2));
+        assert_eq!(Hashed::new(IndexableString::from("0😀2")).hash_value(), Hashed::new("0😀2").hash_value());
+    }
+}
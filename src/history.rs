@@ -0,0 +1,140 @@
+use crate::IndexableString;
+
+/// One `edit` of an `IndexableString`: the `char`s from `start` that were `removed`, and the text `inserted` in their
+/// place.
+#[derive(Clone)]
+struct Change {
+    start: usize,
+    removed: String,
+    inserted: String,
+}
+
+/// The undo history of an `IndexableString`. Only the text each `edit` replaced is kept, so the unchanged text and
+/// index are shared by every version instead of being copied for each snapshot.
+#[derive(Clone, Default)]
+pub(crate) struct History {
+    undo: Vec<Vec<Change>>,
+    pending: Vec<Change>,
+    redo: Vec<Vec<Change>>,
+}
+
+impl History {
+    /// Records that the `char`s from `start` that were `removed` were replaced by `inserted`.
+    pub(crate) fn record(&mut self, start: usize, removed: &str, inserted: &str) {
+        self.pending.push(Change {
+            start,
+            removed: removed.to_string(),
+            inserted: inserted.to_string(),
+        });
+        self.redo.clear();
+    }
+}
+
+impl IndexableString {
+    /// Marks the current text as a version that `undo` can return to, and starts recording edits if this is the first
+    /// snapshot. Edits made since the previous snapshot are undone and redone together.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let mut s = IndexableString::from("0😀2");
+    ///
+    /// s.snapshot();
+    /// s.edit(1..2, "1");
+    /// s.snapshot();
+    /// s.edit(3..3, "3");
+    /// s.edit(4..4, "4");
+    ///
+    /// assert!(s.undo());
+    /// assert_eq!(s.as_str(), "012");
+    /// assert!(s.undo());
+    /// assert_eq!(s.as_str(), "0😀2");
+    /// assert!(!s.undo());
+    ///
+    /// assert!(s.redo());
+    /// assert!(s.redo());
+    /// assert_eq!(s.as_str(), "01234");
+    /// ```
+    pub fn snapshot(&mut self) {
+        let history: &mut History = self.history_mut().get_or_insert_with(Box::default);
+
+        if !history.pending.is_empty() {
+            let changes: Vec<Change> = std::mem::take(&mut history.pending);
+
+            history.undo.push(changes);
+        }
+    }
+
+    /// Returns the text to the previous snapshot, or to the latest one if there were edits after it. Returns `false`
+    /// if there's nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let changes: Vec<Change> = match self.history_mut().as_deref_mut() {
+            Some(history) if !history.pending.is_empty() => std::mem::take(&mut history.pending),
+            Some(history) => match history.undo.pop() {
+                Some(changes) => changes,
+                None => return false,
+            },
+            None => return false,
+        };
+
+        for change in changes.iter().rev() {
+            self.splice(change.start..change.start + change.inserted.chars().count(), &change.removed);
+        }
+
+        if let Some(history) = self.history_mut() {
+            history.redo.push(changes);
+        }
+
+        true
+    }
+
+    /// Reapplies the edits of the last `undo`. Returns `false` if there's nothing to redo, which is the case after any
+    /// new `edit`.
+    pub fn redo(&mut self) -> bool {
+        let changes: Vec<Change> = match self.history_mut().as_deref_mut().and_then(|history| history.redo.pop()) {
+            Some(changes) => changes,
+            None => return false,
+        };
+
+        for change in changes.iter() {
+            self.splice(change.start..change.start + change.removed.chars().count(), &change.inserted);
+        }
+
+        if let Some(history) = self.history_mut() {
+            history.undo.push(changes);
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Affinity, IndexableString};
+
+    #[test]
+    fn test_undo_restores_text_index_and_anchors() {
+        let mut s = IndexableString::from("ab😀cd");
+        let anchor = s.add_anchor(3, Affinity::Left);
+
+        s.snapshot();
+        s.edit(0..3, "é");
+        s.edit(1..2, "xyz");
+
+        assert_eq!(s.anchor_position(anchor), Some(1));
+        assert!(s.undo());
+        assert_eq!(s.as_str(), "ab😀cd");
+        assert_eq!(s[2], '😀');
+        assert_eq!(s.len(), 5);
+        assert_eq!(s.anchor_position(anchor), Some(3));
+
+        assert!(s.redo());
+        assert_eq!(s.as_str(), "éxyzd");
+
+        s.undo();
+        s.edit(4..5, "!");
+
+        assert!(!s.redo());
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{IndexableStr, IndexableString, IndexableStringBuilder, Offset, Span};
+
+/// The named character references that are decoded, sorted by name.
+const ENTITIES: &[(&str, char)] = &[
+    ("AElig", 'Æ'),
+    ("Eacute", 'É'),
+    ("aacute", 'á'),
+    ("aelig", 'æ'),
+    ("agrave", 'à'),
+    ("amp", '&'),
+    ("apos", '\''),
+    ("auml", 'ä'),
+    ("bull", '•'),
+    ("ccedil", 'ç'),
+    ("cent", '¢'),
+    ("copy", '©'),
+    ("deg", '°'),
+    ("divide", '÷'),
+    ("eacute", 'é'),
+    ("egrave", 'è'),
+    ("euro", '€'),
+    ("gt", '>'),
+    ("hellip", '…'),
+    ("iexcl", '¡'),
+    ("iquest", '¿'),
+    ("laquo", '«'),
+    ("ldquo", '“'),
+    ("lsquo", '‘'),
+    ("lt", '<'),
+    ("mdash", '—'),
+    ("middot", '·'),
+    ("nbsp", '\u{A0}'),
+    ("ndash", '–'),
+    ("ntilde", 'ñ'),
+    ("ouml", 'ö'),
+    ("para", '¶'),
+    ("plusmn", '±'),
+    ("pound", '£'),
+    ("quot", '"'),
+    ("raquo", '»'),
+    ("rdquo", '”'),
+    ("reg", '®'),
+    ("rsquo", '’'),
+    ("sect", '§'),
+    ("shy", '\u{AD}'),
+    ("szlig", 'ß'),
+    ("times", '×'),
+    ("trade", '™'),
+    ("uuml", 'ü'),
+    ("yen", '¥'),
+    ("zwj", '\u{200D}'),
+    ("zwnj", '\u{200C}'),
+];
+
+/// The longest reference that is looked for, not counting the `'&'`. It's long enough for a hexadecimal reference
+/// with leading zeros.
+const MAX_REFERENCE_LENGTH: usize = 32;
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns the string with its HTML character references decoded, along with the `Span` of the original `char`s
+    /// that each decoded `char` came from. Requires the `html` feature.
+    ///
+    /// Decimal and hexadecimal references such as `"&#233;"` and `"&#x1F600;"` are decoded, as are the common named
+    /// references such as `"&amp;"`, `"&nbsp;"` and `"&eacute;"`. A reference must end with `';'`. A numeric
+    /// reference to 0, a surrogate or a value above `0x10FFFF` is decoded as `char::REPLACEMENT_CHARACTER`, like a
+    /// browser does. Anything else that starts with `'&'` is kept as it is.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("a &lt; b&#x1F600;&unknown;");
+    /// let (decoded, spans) = s.decode_html_entities();
+    ///
+    /// assert_eq!(decoded.as_str(), "a < b😀&unknown;");
+    /// assert_eq!(spans[2], Span::new(2, 6));
+    /// assert_eq!(spans[5], Span::new(8, 17));
+    /// assert_eq!(spans[6], Span::new(17, 18));
+    /// ```
+    pub fn decode_html_entities(&self) -> (IndexableString, Vec<Span>) {
+        let chars: Vec<char> = self.str.chars().collect();
+        let mut builder: IndexableStringBuilder = IndexableStringBuilder::with_capacity(self.str_length);
+        let mut spans: Vec<Span> = Vec::with_capacity(chars.len());
+        let mut index: usize = 0;
+
+        while index < chars.len() {
+            let (chr, end) = match chars[index] {
+                '&' => character_reference(&chars, index).unwrap_or(('&', index + 1)),
+                chr => (chr, index + 1),
+            };
+
+            builder.push_char(chr);
+            spans.push(Span::new(index, end));
+            index = end;
+        }
+
+        (builder.finish(), spans)
+    }
+}
+
+impl IndexableString {
+    /// Returns the string with its HTML character references decoded, along with the `Span` of the original `char`s
+    /// that each decoded `char` came from. Requires the `html` feature. See `IndexableStr::decode_html_entities`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let (decoded, _) = IndexableString::from("Caf&eacute; &amp; bar").decode_html_entities();
+    ///
+    /// assert_eq!(decoded.as_str(), "Café & bar");
+    /// ```
+    pub fn decode_html_entities(&self) -> (IndexableString, Vec<Span>) {
+        self.as_indexable_str().decode_html_entities()
+    }
+}
+
+/// Decodes the character reference whose `'&'` is at `chars[start]`. Returns the decoded `char` and the index after
+/// the `';'`, or `None` if there isn't a known reference there.
+fn character_reference(chars: &[char], start: usize) -> Option<(char, usize)> {
+    let search_end: usize = chars.len().min(start + 2 + MAX_REFERENCE_LENGTH);
+    let end: usize = start + 1 + chars[start + 1..search_end].iter().position(|&chr| chr == ';')?;
+    let name: String = chars[start + 1..end].iter().collect();
+
+    let chr: char = match name.strip_prefix('#') {
+        Some(number) => {
+            let code_point: u32 = match number.strip_prefix(['x', 'X']) {
+                Some(hex) if !hex.is_empty() && hex.chars().all(|chr| chr.is_ascii_hexdigit()) => u32::from_str_radix(hex, 16).unwrap_or(u32::MAX),
+                None if !number.is_empty() && number.chars().all(|chr| chr.is_ascii_digit()) => number.parse().unwrap_or(u32::MAX),
+                _ => return None,
+            };
+
+            match code_point {
+                0 => char::REPLACEMENT_CHARACTER,
+                _ => char::from_u32(code_point).unwrap_or(char::REPLACEMENT_CHARACTER),
+            }
+        },
+        None => ENTITIES[ENTITIES.binary_search_by_key(&name.as_str(), |&(entity, _)| entity).ok()?].1,
+    };
+
+    Some((chr, end + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entities_are_sorted() {
+        assert!(ENTITIES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_malformed_references_are_kept() {
+        let s = IndexableStr::new("&amp&#;&#x;&#65;&#0;&#xD800;&#99999999999;&&amp;");
+        let (decoded, spans) = s.decode_html_entities();
+
+        assert_eq!(decoded.as_str(), "&amp&#;&#x;A\u{FFFD}\u{FFFD}\u{FFFD}&&");
+        assert_eq!(spans.last(), Some(&Span::new(43, 48)));
+        assert_eq!(spans.iter().map(|span| span.len()).sum::<usize>(), s.len());
+    }
+}
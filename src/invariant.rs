@@ -0,0 +1,244 @@
+use std::{error::Error, fmt::Display};
+
+use crate::{char_table::CharTable, unicode, IndexableStr, IndexableString, Offset};
+
+/// `InvariantViolation` is one way that the index of a string can disagree with the string, found by
+/// `IndexableStr::check_invariants`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum InvariantViolation {
+    /// The stored byte length isn't the length of the string.
+    StrLength { stored: usize, actual: usize },
+    /// The stored number of `char`s isn't the number of `char`s in the string.
+    CharsLength { stored: usize, actual: usize },
+    /// The table doesn't have one entry for each `char` of the string.
+    TableLength { table: usize, actual: usize },
+    /// The table is the one for pure ASCII strings, but the string isn't pure ASCII. The entries aren't checked.
+    AsciiTable,
+    /// The byte offset of the entry at `index` isn't greater than the byte offset of the entry before it.
+    OffsetNotIncreasing { index: usize, previous: usize, byte_offset: usize },
+    /// The byte offset of the entry at `index` isn't on a `char` boundary, or is past the end of the string.
+    NotCharBoundary { index: usize, byte_offset: usize },
+    /// The byte offset of the entry at `index` isn't where the `char` at `index` starts.
+    WrongOffset { index: usize, expected: usize, found: usize },
+    /// The entry at `index` doesn't hold the `char` at `index`.
+    WrongChar { index: usize, expected: char, found: char },
+    /// The last `char` of the table doesn't end at the end of the string.
+    FinalOffset { end: usize, len: usize },
+    /// The cached grapheme cluster boundaries aren't the boundaries of the string.
+    StaleGraphemes,
+}
+
+impl Display for InvariantViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvariantViolation::StrLength { stored, actual } => {
+                write!(f, "Stored byte length: ({stored}) must equal the length of the string ({actual})!")
+            },
+            InvariantViolation::CharsLength { stored, actual } => {
+                write!(f, "Stored length: ({stored}) must equal the number of UTF-8 characters in the string ({actual})!")
+            },
+            InvariantViolation::TableLength { table, actual } => {
+                write!(f, "Table length: ({table}) must equal the number of UTF-8 characters in the string ({actual})!")
+            },
+            InvariantViolation::AsciiTable => write!(f, "ASCII table must only be used for a pure ASCII string!"),
+            InvariantViolation::OffsetNotIncreasing { index, previous, byte_offset } => {
+                write!(f, "Byte offset: ({byte_offset}) at index: ({index}) must be greater than the previous byte offset ({previous})!")
+            },
+            InvariantViolation::NotCharBoundary { index, byte_offset } => {
+                write!(f, "Byte offset: ({byte_offset}) at index: ({index}) must be on a UTF-8 character boundary!")
+            },
+            InvariantViolation::WrongOffset { index, expected, found } => {
+                write!(f, "Byte offset: ({found}) at index: ({index}) must be ({expected})!")
+            },
+            InvariantViolation::WrongChar { index, expected, found } => {
+                write!(f, "Char: ({found:?}) at index: ({index}) must be ({expected:?})!")
+            },
+            InvariantViolation::FinalOffset { end, len } => {
+                write!(f, "Last UTF-8 character must end at the end of the string ({len}), not at ({end})!")
+            },
+            InvariantViolation::StaleGraphemes => write!(f, "Cached grapheme boundaries must match the string!"),
+        }
+    }
+}
+
+/// `InvariantError` is returned by `IndexableStr::check_invariants` when the index disagrees with the string. It
+/// holds every violation that was found, and displays one on each line.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InvariantError {
+    pub violations: Vec<InvariantViolation>,
+}
+
+impl Display for InvariantError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (number, violation) in self.violations.iter().enumerate() {
+            match number {
+                0 => write!(f, "{violation}")?,
+                _ => write!(f, "\n{violation}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for InvariantError {}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Verifies the index against the string: the stored lengths, that the byte offsets increase and are on `char`
+    /// boundaries, that every entry holds the right `char` and offset, that the last `char` ends at the end of the
+    /// string, and that the grapheme cluster boundaries, if they've been cached, are still right. This takes linear
+    /// time, so it's meant for tests, fuzzing and `debug_assert!`s.
+    /// # Errors
+    /// * If the index disagrees with the string. The error holds every violation that was found.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("0😀2345678😀");
+    ///
+    /// assert!(s.check_invariants().is_ok());
+    /// debug_assert!(s.check_invariants().is_ok());
+    /// ```
+    pub fn check_invariants(&self) -> Result<(), InvariantError> {
+        let mut violations: Vec<InvariantViolation> = Vec::new();
+        let chars_length: usize = self.str.chars().count();
+        let table_length: usize = self.char_table.len(self.str);
+
+        if self.str_length != self.str.len() {
+            violations.push(InvariantViolation::StrLength { stored: self.str_length, actual: self.str.len() });
+        }
+
+        if self.chars_length != chars_length {
+            violations.push(InvariantViolation::CharsLength { stored: self.chars_length, actual: chars_length });
+        }
+
+        if table_length != chars_length {
+            violations.push(InvariantViolation::TableLength { table: table_length, actual: chars_length });
+        }
+
+        if matches!(*self.char_table, CharTable::Ascii) && !self.str.is_ascii() {
+            violations.push(InvariantViolation::AsciiTable);
+
+            return Err(InvariantError { violations });
+        }
+
+        let mut previous: Option<usize> = None;
+
+        for (index, (expected_offset, expected_char)) in self.str.char_indices().enumerate().take(table_length) {
+            let byte_offset: usize = self.char_table.byte_offset(index);
+
+            if let Some(previous) = previous.filter(|&previous| previous >= byte_offset) {
+                violations.push(InvariantViolation::OffsetNotIncreasing { index, previous, byte_offset });
+            }
+
+            previous = Some(byte_offset);
+
+            if !self.str.is_char_boundary(byte_offset) {
+                violations.push(InvariantViolation::NotCharBoundary { index, byte_offset });
+                continue;
+            }
+
+            if byte_offset != expected_offset {
+                violations.push(InvariantViolation::WrongOffset { index, expected: expected_offset, found: byte_offset });
+            }
+
+            let found_char: char = *self.char_table.char_ref(self.str, index);
+
+            if found_char != expected_char {
+                violations.push(InvariantViolation::WrongChar { index, expected: expected_char, found: found_char });
+            }
+        }
+
+        if table_length > 0 {
+            let byte_offset: usize = self.char_table.byte_offset(table_length - 1);
+
+            if self.str.is_char_boundary(byte_offset) {
+                let end: usize = byte_offset + self.char_table.char_ref(self.str, table_length - 1).len_utf8();
+
+                if end != self.str.len() {
+                    violations.push(InvariantViolation::FinalOffset { end, len: self.str.len() });
+                }
+            }
+        }
+
+        if let Some(boundaries) = self.graphemes.get() {
+            let expected: Vec<usize> = unicode::grapheme_ranges(self.str).map(|range| range.end).collect();
+
+            if boundaries.len() != expected.len() + 1 || boundaries[1..].iter().zip(&expected).any(|(boundary, &end)| boundary.byte_offset != end) {
+                violations.push(InvariantViolation::StaleGraphemes);
+            }
+        }
+
+        match violations.is_empty() {
+            true => Ok(()),
+            false => Err(InvariantError { violations }),
+        }
+    }
+}
+
+impl IndexableString {
+    /// Verifies the index against the string. See `IndexableStr::check_invariants`.
+    /// # Errors
+    /// * If the index disagrees with the string. The error holds every violation that was found.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let mut s = IndexableString::from("0😀2");
+    ///
+    /// s.edit(1..2, "é中");
+    ///
+    /// assert!(s.check_invariants().is_ok());
+    /// ```
+    pub fn check_invariants(&self) -> Result<(), InvariantError> {
+        self.as_indexable_str().check_invariants()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::*;
+    use crate::grapheme::GraphemeCache;
+
+    #[test]
+    fn test_check_invariants_reports_a_table_for_another_string() {
+        let str = "0😀2é";
+        let s: IndexableStr = IndexableStr {
+            str,
+            str_length: str.len(),
+            char_table: Cow::Owned(CharTable::new("😀02é")),
+            chars_length: 5,
+            graphemes: Cow::Owned(GraphemeCache::new()),
+        };
+        let error: InvariantError = s.check_invariants().unwrap_err();
+
+        assert_eq!(
+            error.violations,
+            vec![
+                InvariantViolation::CharsLength { stored: 5, actual: 4 },
+                InvariantViolation::WrongChar { index: 0, expected: '0', found: '😀' },
+                InvariantViolation::NotCharBoundary { index: 1, byte_offset: 4 },
+            ]
+        );
+        assert_eq!(error.to_string().lines().nth(2), Some("Byte offset: (4) at index: (1) must be on a UTF-8 character boundary!"));
+    }
+
+    #[test]
+    fn test_check_invariants_reports_an_ascii_table_for_a_non_ascii_string() {
+        let str = "😀";
+        let s: IndexableStr = IndexableStr {
+            str,
+            str_length: str.len(),
+            char_table: Cow::Borrowed(&CharTable::Ascii),
+            chars_length: 1,
+            graphemes: Cow::Owned(GraphemeCache::new()),
+        };
+
+        assert_eq!(
+            s.check_invariants().unwrap_err().violations,
+            vec![InvariantViolation::TableLength { table: 4, actual: 1 }, InvariantViolation::AsciiTable]
+        );
+    }
+}
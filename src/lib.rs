@@ -1,15 +1,102 @@
 #![crate_name = "indexable_str"]
 
+mod anchor;
+mod annotate;
+mod arena;
+mod bitmap;
+mod builder;
+#[cfg(feature = "bytes")]
+mod bytes;
+mod case;
+#[cfg(feature = "category")]
+mod category;
+mod char_table;
+mod checked;
+mod cmp;
+mod compact;
+mod corpus;
+#[cfg(feature = "csv")]
+mod csv;
+mod cursor;
+mod delimiter;
+mod diff;
+mod distance;
+mod edit;
+mod emoji;
+mod error;
+mod escape;
+mod glob;
+mod grapheme;
+mod group;
+mod hashed;
+mod history;
+#[cfg(feature = "html")]
+mod html;
+mod invariant;
+mod location;
+mod markup;
+mod offset;
+mod pairs;
+mod pattern;
+mod piece_table;
+mod redact;
+mod scan;
+mod search;
+#[cfg(feature = "script")]
+mod script;
+mod shared;
+mod string;
+mod substr;
+mod text;
+mod trim;
+mod unicode;
+mod width;
+
+pub use anchor::{Affinity, Anchor};
+pub use annotate::{AnnotatedSegment, OverlapError};
+pub use arena::{ArenaHandle, IndexableArena};
+pub use builder::IndexableStringBuilder;
+#[cfg(feature = "bytes")]
+pub use bytes::IndexableBytes;
+#[cfg(feature = "category")]
+pub use category::GeneralCategory;
+pub use checked::Checked;
+pub use compact::CompactIndexableStr;
+pub use corpus::{Corpus, DocumentId};
+#[cfg(feature = "csv")]
+pub use csv::CsvField;
+pub use cursor::{Cursor, ExpectError, Expected};
+pub use diff::{diff, DiffHunk, DiffKind};
+pub use edit::{IndexMapping, MappedSpan, SpanMapper};
+pub use error::IndexError;
+pub use escape::{EscapeStyle, UnescapeError};
+pub use hashed::Hashed;
+pub use invariant::{InvariantError, InvariantViolation};
+pub use location::{Position, Span};
+pub use markup::{MarkupRun, MarkupStyle, TagKind};
+pub use offset::Offset;
+pub use pairs::KeyValue;
+pub use pattern::Pattern;
+pub use piece_table::IndexablePieceTable;
+#[cfg(feature = "script")]
+pub use script::Script;
+pub use shared::SharedIndexableStr;
+pub use string::IndexableString;
+pub use substr::SubStr;
+pub use text::IndexableText;
+
+use char_table::CharTable;
+use error::{check_index, check_range};
+use grapheme::GraphemeCache;
 use std::{
+    borrow::Cow,
     fmt::Display,
     ops::{Index, Range, RangeFrom, RangeTo},
+    str::{Bytes, Chars},
 };
 
-#[derive(Copy, Clone)]
-struct CharOffset {
-    chr: char,
-    offset: usize,
-}
+/// The UTF-8 byte order mark that some tools put at the start of a file.
+const BYTE_ORDER_MARK: char = '\u{FEFF}';
 
 /// `IndexableStr` is a `struct` for creating immutable string objects that make text parsing with Rust a bit more elegant.
 /// 
@@ -77,12 +164,15 @@ struct CharOffset {
 /// 
 /// `IndexableStr` is designed to work well with all valid UTF-8 characters. 
 /// 
-/// You should note that `IndexableStr` creates a vector of objects that holds a `char` and the starting byte offset of the `char`'s position in the underlying string as a `usize`. This requires additional memory resources. However, the convenience of `IndexableStr` should outweigh the additional memory requirements for most applications.
-pub struct IndexableStr<'a> {
+/// You should note that, unless the string is pure ASCII, `IndexableStr` creates a table that holds each `char` and the starting byte offset of the `char`'s position in the underlying string. The offset is stored as the type parameter `O`, a `u32` by default, unless the string is too long for it, in which case it is stored as a `usize`. `IndexableStr::new_with_offset_width` picks another `Offset` type, such as `u16` for short strings. This requires additional memory resources. However, the convenience of `IndexableStr` should outweigh the additional memory requirements for most applications. A pure ASCII string needs no vector at all, because each `char` index is also its byte offset, and a string of 8 or fewer `char`s keeps them inline in `IndexableStr` itself without allocating a vector. If memory is tight, `CompactIndexableStr` only stores the byte offsets and decodes each `char` on demand.
+/// 
+/// The grapheme cluster boundaries are found the first time a grapheme API such as `display_width` needs them, and kept for later calls, so measuring the same string again doesn't segment it again.
+pub struct IndexableStr<'a, O: Offset = u32> {
     str: &'a str,
     str_length: usize,
-    chars_vec: Vec<CharOffset>,
+    char_table: Cow<'a, CharTable<O>>,
     chars_length: usize,
+    graphemes: Cow<'a, GraphemeCache>,
 }
 
 impl<'a> IndexableStr<'a> {
@@ -96,47 +186,139 @@ impl<'a> IndexableStr<'a> {
     /// 
     /// let s = IndexableStr::new("0😀2345678😀");
     /// ```
-    pub fn new(str: &'a str) -> IndexableStr {
-        let mut current_offset: usize = 0;
-
-        let chars_vec: Vec<CharOffset> = str.chars().map(|c| {
-            let char_offset = CharOffset {
-                chr: c,
-                offset: current_offset,
-            };
-
-            let code_point: u32 = c as u32;
+    pub fn new(str: &'a str) -> IndexableStr<'a> {
+        IndexableStr::new_with_offset_width(str)
+    }
 
-            current_offset += (|| {
-                if code_point <= 0x7F {
-                    return 1;
-                }
+    /// Returns an indexable string for a pure ASCII string slice, in a `const` context. ASCII strings don't need an
+    /// index, so nothing is allocated, and the string can be stored in a `const` or a `static`.
+    /// # Arguments
+    ///
+    /// * `str` - A pure ASCII string slice.
+    /// # Panics
+    /// * If the string isn't pure ASCII, which is a compile time error in a `const` context.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// static KEYWORDS: [IndexableStr<'static>; 2] = [IndexableStr::new_ascii_const("while"), IndexableStr::new_ascii_const("for")];
+    ///
+    /// assert_eq!(KEYWORDS[0][1], 'h');
+    /// assert_eq!(&KEYWORDS[1][1..], "or");
+    /// ```
+    pub const fn new_ascii_const(str: &'static str) -> IndexableStr<'static> {
+        if !str.is_ascii() {
+            panic!("String must be pure ASCII!");
+        }
 
-                if code_point <= 0x7FF {
-                    return 2;
-                }
+        IndexableStr {
+            str,
+            str_length: str.len(),
+            char_table: Cow::Borrowed(&CharTable::Ascii),
+            chars_length: str.len(),
+            graphemes: Cow::Owned(GraphemeCache::new()),
+        }
+    }
 
-                if code_point <= 0xFFFF {
-                    return 3;
-                }
+    /// Returns an indexable string that borrows `bytes`, if they're valid UTF-8.
+    /// # Arguments
+    ///
+    /// * `bytes` - The UTF-8 text.
+    /// # Errors
+    /// * If the bytes aren't valid UTF-8, `IndexError::InvalidUtf8` with the byte offset of the fault.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexError, IndexableStr};
+    ///
+    /// let s = IndexableStr::from_utf8("0😀2".as_bytes()).unwrap();
+    ///
+    /// assert_eq!(s[1], '😀');
+    /// assert_eq!(IndexableStr::from_utf8(&[b'0', 0xFF]).err(), Some(IndexError::InvalidUtf8 { byte_offset: 1 }));
+    /// ```
+    pub fn from_utf8(bytes: &'a [u8]) -> Result<IndexableStr<'a>, IndexError> {
+        Ok(IndexableStr::new(std::str::from_utf8(bytes)?))
+    }
 
-                if code_point <= 0x10FFFF {
-                    return 4;
-                }
+    /// Returns an indexable string without the UTF-8 byte order mark (`U+FEFF`) that some tools put at the start of a
+    /// file, along with whether there was one. Indices and positions are relative to the text after the mark.
+    /// # Arguments
+    ///
+    /// * `str` - A string slice to be indexed.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let (s, had_bom) = IndexableStr::new_strip_bom("\u{FEFF}😀1");
+    ///
+    /// assert!(had_bom);
+    /// assert_eq!(s.as_str(), "😀1");
+    /// assert_eq!(s[1], '1');
+    /// assert!(!IndexableStr::new_strip_bom("😀1").1);
+    /// ```
+    pub fn new_strip_bom(str: &'a str) -> (IndexableStr<'a>, bool) {
+        match str.strip_prefix(BYTE_ORDER_MARK) {
+            Some(str) => (IndexableStr::new(str), true),
+            None => (IndexableStr::new(str), false),
+        }
+    }
 
-                0
-            })();
+    /// Returns an indexable string, building the index on multiple threads.
+    ///
+    /// The string is split into pieces at `char` boundaries, each piece is indexed on its own thread, and the pieces
+    /// are stitched back together. This only pays off for very large strings, so strings smaller than a couple of
+    /// megabytes are indexed on the current thread. Requires the `parallel` feature.
+    /// # Arguments
+    /// 
+    /// * `str` - A string slice to be indexed.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    /// 
+    /// let s = IndexableStr::new_parallel("0😀2345678😀");
+    /// 
+    /// assert_eq!(s[9], '😀');
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn new_parallel(str: &'a str) -> IndexableStr<'a> {
+        let threads: usize = std::thread::available_parallelism().map(|threads| threads.get()).unwrap_or(1);
+        let char_table: CharTable = CharTable::new_parallel(str, threads);
+        let chars_length: usize = char_table.len(str);
 
-            char_offset
-        }).collect();
+        IndexableStr {
+            str,
+            str_length: str.len(),
+            char_table: Cow::Owned(char_table),
+            chars_length,
+            graphemes: Cow::Owned(GraphemeCache::new()),
+        }
+    }
+}
 
-        let chars_length: usize = chars_vec.len();
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns an indexable string whose table stores its byte offsets as an `O`, such as `u16` for the short strings
+    /// of an embedded target. `new` uses the default, `u32`.
+    /// # Arguments
+    ///
+    /// * `str` - A string slice to be indexed.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s: IndexableStr<u16> = IndexableStr::new_with_offset_width("0😀2345678😀");
+    ///
+    /// assert_eq!(s[9], '😀');
+    /// assert!(s.memory_usage() < IndexableStr::new("0😀2345678😀").memory_usage());
+    /// ```
+    pub fn new_with_offset_width(str: &'a str) -> IndexableStr<'a, O> {
+        let char_table: CharTable<O> = CharTable::new(str);
+        let chars_length: usize = char_table.len(str);
 
         IndexableStr {
             str,
             str_length: str.len(),
-            chars_vec,
+            char_table: Cow::Owned(char_table),
             chars_length,
+            graphemes: Cow::Owned(GraphemeCache::new()),
         }
     }
 
@@ -168,43 +350,332 @@ impl<'a> IndexableStr<'a> {
         self.chars_length
     }
 
-    fn create_str_from_range(&self, start_index: usize, end_index: usize) -> &str {
-        if end_index > self.chars_length {
-            panic!("Range end: ({end_index}) must be less than or equal to the number of UTF-8 characters in the string ({})!", self.chars_length);
+    /// Returns `true` if the string contains no `char`s.
+    /// 
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    /// 
+    /// assert!(IndexableStr::new("").is_empty());
+    /// assert!(!IndexableStr::new("😀").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.chars_length == 0
+    }
+
+    /// Returns the first `char`, or `None` if the string is empty.
+    /// 
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    /// 
+    /// assert_eq!(IndexableStr::new("😀12").first(), Some('😀'));
+    /// assert_eq!(IndexableStr::new("").first(), None);
+    /// ```
+    pub fn first(&self) -> Option<char> {
+        self.str.chars().next()
+    }
+
+    /// Returns the last `char`, or `None` if the string is empty.
+    /// 
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    /// 
+    /// assert_eq!(IndexableStr::new("line\n").last(), Some('\n'));
+    /// assert_eq!(IndexableStr::new("").last(), None);
+    /// ```
+    pub fn last(&self) -> Option<char> {
+        self.str.chars().next_back()
+    }
+
+    /// Returns the `char` at `index`, or `None` if the index is greater than or equal to the number of `char`s.
+    /// # Arguments
+    /// 
+    /// * `index` - The `char` index.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    /// 
+    /// let s = IndexableStr::new("0😀2");
+    /// 
+    /// assert_eq!(s.nth(1), Some('😀'));
+    /// assert_eq!(s.nth(3), None);
+    /// ```
+    pub fn nth(&self, index: usize) -> Option<char> {
+        match index < self.chars_length {
+            true => Some(self[index]),
+            false => None,
+        }
+    }
+
+    /// Returns the result of `predicate` for the `char` at `index`, or `None` if the index is greater than or equal
+    /// to the number of `char`s, so lookahead past the end of the string doesn't need a separate bounds check.
+    /// # Arguments
+    /// 
+    /// * `index` - The `char` index.
+    /// * `predicate` - The test for the `char`.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    /// 
+    /// let s = IndexableStr::new("x😀1");
+    /// 
+    /// assert_eq!(s.test_at(2, |chr| chr.is_ascii_digit()), Some(true));
+    /// assert_eq!(s.test_at(3, |chr| chr.is_ascii_digit()), None);
+    /// assert!(!s.test_at(3, |chr| chr == '=').unwrap_or(false));
+    /// ```
+    pub fn test_at(&self, index: usize, predicate: impl FnOnce(char) -> bool) -> Option<bool> {
+        self.nth(index).map(predicate)
+    }
+
+    /// Returns whether the `char` at `index` is whitespace, or `None` if the index is greater than or equal to the
+    /// number of `char`s.
+    /// # Arguments
+    /// 
+    /// * `index` - The `char` index.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    /// 
+    /// let s = IndexableStr::new("a\u{3000}");
+    /// 
+    /// assert_eq!(s.is_whitespace_at(0), Some(false));
+    /// assert_eq!(s.is_whitespace_at(1), Some(true));
+    /// assert_eq!(s.is_whitespace_at(2), None);
+    /// ```
+    pub fn is_whitespace_at(&self, index: usize) -> Option<bool> {
+        self.test_at(index, char::is_whitespace)
+    }
+
+    /// Returns whether the `char` at `index` is alphanumeric, or `None` if the index is greater than or equal to the
+    /// number of `char`s.
+    /// # Arguments
+    /// 
+    /// * `index` - The `char` index.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    /// 
+    /// let s = IndexableStr::new("中_");
+    /// 
+    /// assert_eq!(s.is_alphanumeric_at(0), Some(true));
+    /// assert_eq!(s.is_alphanumeric_at(1), Some(false));
+    /// assert_eq!(s.is_alphanumeric_at(2), None);
+    /// ```
+    pub fn is_alphanumeric_at(&self, index: usize) -> Option<bool> {
+        self.test_at(index, char::is_alphanumeric)
+    }
+
+    /// Returns an iterator over the `char`s that starts at `index` instead of the start of the string. The byte offset
+    /// of the `index` is looked up in the index, so the `char`s before it aren't scanned.
+    /// # Arguments
+    /// 
+    /// * `index` - The index of the first `char` to return. The number of `char`s gives an empty iterator.
+    /// # Panics
+    /// * If `index` is greater than the number of characters in the string.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    /// 
+    /// let s = IndexableStr::new("0😀2345678😀");
+    /// 
+    /// assert_eq!(s.iter_from(8).collect::<String>(), "8😀");
+    /// assert_eq!(s.iter_from(10).next(), None);
+    /// assert_eq!(s.iter_from(1).as_str(), &s[1..]);
+    /// ```
+    pub fn iter_from(&self, index: usize) -> Chars<'a> {
+        self.create_str_from_range(index, self.chars_length).chars()
+    }
+
+    /// Returns an iterator over the UTF-8 bytes of the string.
+    /// 
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    /// 
+    /// assert_eq!(IndexableStr::new("0é").bytes().collect::<Vec<u8>>(), vec![b'0', 0xC3, 0xA9]);
+    /// ```
+    pub fn bytes(&self) -> Bytes<'a> {
+        self.str.bytes()
+    }
+
+    /// Returns the byte at `byte_offset`, or `None` if the byte offset is greater than or equal to the number of
+    /// bytes in the string.
+    /// # Arguments
+    /// 
+    /// * `byte_offset` - The byte offset.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    /// 
+    /// let s = IndexableStr::new("0é");
+    /// 
+    /// assert_eq!(s.byte_at(1), Some(0xC3));
+    /// assert_eq!(s.byte_at(3), None);
+    /// ```
+    pub fn byte_at(&self, byte_offset: usize) -> Option<u8> {
+        self.str.as_bytes().get(byte_offset).copied()
+    }
+
+    /// Returns `true` if `byte_offset` is the start of a `char` or the end of the string.
+    /// # Arguments
+    /// 
+    /// * `byte_offset` - The byte offset.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    /// 
+    /// let s = IndexableStr::new("0😀2");
+    /// 
+    /// assert!(s.is_char_boundary(1));
+    /// assert!(!s.is_char_boundary(2));
+    /// assert!(s.is_char_boundary(6));
+    /// assert!(!s.is_char_boundary(7));
+    /// ```
+    pub fn is_char_boundary(&self, byte_offset: usize) -> bool {
+        self.str.is_char_boundary(byte_offset)
+    }
+
+    /// Returns the index of the `char` that contains `byte_offset`, so a byte offset inside a `char` is rounded down
+    /// to the start of that `char`. A byte offset at or past the end of the string gives `len()`.
+    /// # Arguments
+    /// 
+    /// * `byte_offset` - Any byte offset.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    /// 
+    /// let s = IndexableStr::new("0😀2");
+    /// 
+    /// assert_eq!(s.floor_char_index(1), 1);
+    /// assert_eq!(s.floor_char_index(3), 1);
+    /// assert_eq!(s.floor_char_index(5), 2);
+    /// assert_eq!(s.floor_char_index(100), 3);
+    /// ```
+    pub fn floor_char_index(&self, byte_offset: usize) -> usize {
+        match byte_offset < self.str_length {
+            true => self.char_table.char_index(byte_offset).unwrap_or_else(|next_index| next_index - 1),
+            false => self.chars_length,
         }
+    }
 
-        if end_index < start_index {
-            panic!("Range end: ({end_index} must be greater than or equal to Range start: ({start_index})!")
+    /// Returns the index of the first `char` that starts at or after `byte_offset`, so a byte offset inside a `char`
+    /// is rounded up to the start of the next `char`. A byte offset at or past the end of the string gives `len()`.
+    /// # Arguments
+    /// 
+    /// * `byte_offset` - Any byte offset.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    /// 
+    /// let s = IndexableStr::new("0😀2");
+    /// 
+    /// assert_eq!(s.ceil_char_index(1), 1);
+    /// assert_eq!(s.ceil_char_index(3), 2);
+    /// assert_eq!(s.ceil_char_index(6), 3);
+    /// assert_eq!(s.ceil_char_index(100), 3);
+    /// ```
+    pub fn ceil_char_index(&self, byte_offset: usize) -> usize {
+        match byte_offset < self.str_length {
+            true => self.char_table.char_index(byte_offset).unwrap_or_else(|next_index| next_index),
+            false => self.chars_length,
         }
+    }
 
-        let bytes_start: usize = self.chars_vec[start_index].offset;
-        let bytes_end: usize = match end_index {
-            _val if self.chars_length == end_index => self.str_length,
-            _ => self.chars_vec[end_index].offset,
+    /// Returns the number of bytes allocated on the heap for the index. The underlying string isn't included, because
+    /// it's borrowed.
+    /// 
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    /// 
+    /// assert_eq!(IndexableStr::new("0123456789").memory_usage(), 0);
+    /// assert!(IndexableStr::new("0😀2345678😀").memory_usage() >= 80);
+    /// ```
+    pub fn memory_usage(&self) -> usize {
+        let graphemes_usage: usize = match &self.graphemes {
+            Cow::Borrowed(_) => 0,
+            Cow::Owned(graphemes) => grapheme::memory_usage(graphemes),
         };
 
+        match &self.char_table {
+            Cow::Borrowed(_) => graphemes_usage,
+            Cow::Owned(char_table) => char_table.memory_usage() + graphemes_usage,
+        }
+    }
+
+    /// Shrinks the allocations of the index as much as possible.
+    /// 
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    /// 
+    /// let mut s = IndexableStr::new("0😀2345678😀");
+    /// 
+    /// s.shrink_to_fit();
+    /// 
+    /// assert_eq!(s.memory_usage(), 80);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        if let Cow::Owned(char_table) = &mut self.char_table {
+            char_table.shrink_to_fit();
+        }
+    }
+
+    fn byte_offset(&self, index: usize) -> usize {
+        match index {
+            _val if self.chars_length == index => self.str_length,
+            _ => self.char_table.byte_offset(index),
+        }
+    }
+
+    /// Returns the index of the `char` that starts at `byte_offset`, or `len()` for the end of the string. Returns
+    /// `None` if the byte offset isn't on a `char` boundary.
+    fn char_index_at_byte(&self, byte_offset: usize) -> Option<usize> {
+        match byte_offset {
+            _val if byte_offset > self.str_length => None,
+            _val if byte_offset == self.str_length => Some(self.chars_length),
+            _ => self.char_table.char_index(byte_offset).ok(),
+        }
+    }
+
+    fn create_str_from_range(&self, start_index: usize, end_index: usize) -> &'a str {
+        if let Err(error) = check_range(start_index, end_index, self.chars_length) {
+            panic!("{error}");
+        }
+
+        let bytes_start: usize = self.byte_offset(start_index);
+        let bytes_end: usize = self.byte_offset(end_index);
+
         &self.str[bytes_start..bytes_end]  
     }
 }
 
-impl<'a> Display for IndexableStr<'a> {
+impl<'a, O: Offset> Display for IndexableStr<'a, O> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.str)
     }
 }
 
-impl<'a> Index<usize> for IndexableStr<'a> {
+/// # Panics
+/// * If the index is greater than or equal to the number of characters in the string.
+impl<'a, O: Offset> Index<usize> for IndexableStr<'a, O> {
     type Output = char;
 
     fn index(&self, index: usize) -> &char {
-        &self.chars_vec[index].chr
+        if let Err(error) = check_index(index, self.chars_length) {
+            panic!("{error}");
+        }
+
+        self.char_table.char_ref(self.str, index)
     }
 }
 
 /// # Panics
 /// * If the range end is greater than the number of characters in the string.
 /// * If the range end is less than the range start.
-impl<'a> Index<Range<usize>> for IndexableStr<'a> {
+impl<'a, O: Offset> Index<Range<usize>> for IndexableStr<'a, O> {
     type Output = str;
 
     fn index(&self, range: Range<usize>) -> &Self::Output {
@@ -216,7 +687,7 @@ impl<'a> Index<Range<usize>> for IndexableStr<'a> {
 
 /// # Panics
 /// * If the range end is less than the range start.
-impl<'a> Index<RangeFrom<usize>> for IndexableStr<'a> {
+impl<'a, O: Offset> Index<RangeFrom<usize>> for IndexableStr<'a, O> {
     type Output = str;
 
     fn index(&self, index: RangeFrom<usize>) -> &Self::Output {
@@ -228,7 +699,7 @@ impl<'a> Index<RangeFrom<usize>> for IndexableStr<'a> {
 
 /// # Panics
 /// * If the range end is greater than the number of characters in the string.
-impl<'a> Index<RangeTo<usize>> for IndexableStr<'a> {
+impl<'a, O: Offset> Index<RangeTo<usize>> for IndexableStr<'a, O> {
     type Output = str;
 
     fn index(&self, index: RangeTo<usize>) -> &Self::Output {
@@ -236,6 +707,17 @@ impl<'a> Index<RangeTo<usize>> for IndexableStr<'a> {
     }
 }
 
+/// # Panics
+/// * If the span end is greater than the number of characters in the string.
+/// * If the span end is less than the span start.
+impl<'a, O: Offset> Index<Span> for IndexableStr<'a, O> {
+    type Output = str;
+
+    fn index(&self, span: Span) -> &Self::Output {
+        self.create_str_from_range(span.start, span.end)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -295,6 +777,92 @@ mod tests {
         assert_eq!(&s[..10], "0😀2345678😀");
     }
 
+    #[test]
+    fn test_range_from_at_end_is_empty() {
+        let s = IndexableStr::new("0😀2345678😀");
+
+        assert_eq!(&s[10..], "");
+        assert_eq!(&IndexableStr::new("")[..0], "");
+    }
+
+    #[test]
+    fn test_ascii_string_does_not_create_a_char_table() {
+        let s = IndexableStr::new("0123456789");
+
+        assert!(matches!(*s.char_table, CharTable::Ascii));
+        assert_eq!(s.len(), 10);
+        assert_eq!(s[9], '9');
+        assert_eq!(&s[2..5], "234");
+    }
+
+    #[test]
+    fn test_new_ascii_const_with_non_ascii_string_panics() {
+        let result = std::panic::catch_unwind(|| IndexableStr::new_ascii_const("0😀2").len());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_new_strip_bom_indexes_the_text_after_the_mark() {
+        let (s, had_bom) = IndexableStr::new_strip_bom("\u{FEFF}0123");
+
+        assert!(had_bom);
+        assert!(matches!(*s.char_table, CharTable::Ascii));
+        assert_eq!(s.len(), 4);
+        assert_eq!(s.position_at(4).unwrap().column, 4);
+    }
+
+    #[test]
+    fn test_first_last_and_nth_on_empty_and_wide_strings() {
+        let s = IndexableStr::new("😀0中");
+        let empty = IndexableStr::new("");
+
+        assert_eq!((s.first(), s.last(), s.nth(1), s.nth(3)), (Some('😀'), Some('中'), Some('0'), None));
+        assert_eq!((empty.first(), empty.last(), empty.nth(0)), (None, None, None));
+    }
+
+    #[test]
+    fn test_iter_from_agrees_with_skipping_chars() {
+        let s = IndexableStr::new("0😀2345678😀");
+
+        for index in 0..=s.len() {
+            assert_eq!(s.iter_from(index).collect::<String>(), s.as_str().chars().skip(index).collect::<String>());
+        }
+
+        let result = std::panic::catch_unwind(|| s.iter_from(11).count());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_floor_and_ceil_char_index_agree_with_char_boundaries() {
+        for str in ["0123", "0😀2é中", "😀😀"] {
+            let s = IndexableStr::new(str);
+
+            for byte_offset in 0..=str.len() + 1 {
+                let floor: usize = (0..=byte_offset.min(str.len())).rev().find(|&offset| str.is_char_boundary(offset)).unwrap();
+                let ceil: usize = (byte_offset.min(str.len())..=str.len()).find(|&offset| str.is_char_boundary(offset)).unwrap();
+
+                assert_eq!(s.floor_char_index(byte_offset), str[..floor].chars().count());
+                assert_eq!(s.ceil_char_index(byte_offset), str[..ceil].chars().count());
+            }
+        }
+    }
+
+    #[test]
+    fn test_ascii_index_out_of_bounds_panics() {
+        let s = IndexableStr::new("0123456789");
+
+        let result = std::panic::catch_unwind(|| s[10]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_index_out_of_bounds_panics_with_the_index_error_message() {
+        let s = IndexableStr::new("0😀2");
+
+        let result = std::panic::catch_unwind(|| s[5]);
+        assert_eq!(*result.unwrap_err().downcast::<String>().unwrap(), IndexError::IndexOutOfBounds { index: 5, len: 3 }.to_string());
+    }
+
     #[test]
     fn test_range_with_ending_index_too_large() {
         let s = IndexableStr::new("0😀2345678😀");
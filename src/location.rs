@@ -0,0 +1,218 @@
+use std::ops::Range;
+
+use crate::{IndexableStr, Offset};
+
+/// `Position` is a location within an `IndexableStr` expressed in `char`s.
+///
+/// `index` is the zero-based `char` index, while `line` and `column` are the zero-based line number and `char`
+/// column of that index. A `'\n'` starts a new line, so `"\r\n"` is treated as a single line break.
+///
+/// The default `Position` is the start of any string, which makes it usable as a location type for parser
+/// generators such as LALRPOP.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub index: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// `Span` is a half-open range of `char` indices, from `start` up to but not including `end`.
+///
+/// # Examples
+/// ```
+/// use indexable_str::{IndexableStr, Span};
+///
+/// let s = IndexableStr::new("0😀2345678😀");
+/// let span = Span::new(1, 3);
+///
+/// assert_eq!(&s[span], "😀2");
+/// assert_eq!(span.len(), 2);
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    /// Returns a span from `start` up to but not including `end`.
+    /// # Arguments
+    ///
+    /// * `start` - The `char` index of the first `char` in the span.
+    /// * `end` - The `char` index one past the last `char` in the span.
+    pub fn new(start: usize, end: usize) -> Span {
+        Span { start, end }
+    }
+
+    /// Returns a `usize` for the number of `char`s covered by the span.
+    pub fn len(&self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Returns `true` if the span covers no `char`s.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the span as a `Range<usize>`.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+impl From<Range<usize>> for Span {
+    fn from(range: Range<usize>) -> Self {
+        Span::new(range.start, range.end)
+    }
+}
+
+impl From<Span> for Range<usize> {
+    fn from(span: Span) -> Self {
+        span.range()
+    }
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns the `Position` of the `char` at the specified index, or `None` if the index is greater than the number
+    /// of `char`s in the string. The index equal to `len()` is the position just past the last `char`.
+    ///
+    /// Calculating the line and column requires scanning the string from the start, so prefer `next_position` when
+    /// walking the string `char` by `char`.
+    /// # Arguments
+    ///
+    /// * `index` - The `char` index to locate.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Position};
+    ///
+    /// let s = IndexableStr::new("ab\n😀d");
+    ///
+    /// assert_eq!(s.position_at(4), Some(Position { index: 4, line: 1, column: 1 }));
+    /// assert_eq!(s.position_at(6), None);
+    /// ```
+    pub fn position_at(&self, index: usize) -> Option<Position> {
+        if index > self.len() {
+            return None;
+        }
+
+        let mut position: Position = Position::default();
+
+        while position.index < index {
+            position = self.next_position(position)?;
+        }
+
+        Some(position)
+    }
+
+    /// Returns the `Position` immediately after the `char` at the specified position, or `None` if the position is
+    /// already at the end of the string.
+    ///
+    /// This is intended to be called once per `char` from a lexer, so that each token can be reported to a parser
+    /// generator with `char` based locations.
+    /// # Arguments
+    ///
+    /// * `position` - The current position.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Position};
+    ///
+    /// let s = IndexableStr::new("😀\nb");
+    /// let start = Position::default();
+    /// let after_emoji = s.next_position(start).unwrap();
+    ///
+    /// assert_eq!(after_emoji, Position { index: 1, line: 0, column: 1 });
+    /// assert_eq!(s.next_position(after_emoji), Some(Position { index: 2, line: 1, column: 0 }));
+    /// ```
+    pub fn next_position(&self, position: Position) -> Option<Position> {
+        if position.index >= self.len() {
+            return None;
+        }
+
+        let next_position: Position = match self[position.index] {
+            '\n' => Position {
+                index: position.index + 1,
+                line: position.line + 1,
+                column: 0,
+            },
+            _ => Position {
+                index: position.index + 1,
+                line: position.line,
+                column: position.column + 1,
+            },
+        };
+
+        Some(next_position)
+    }
+
+    /// Returns the `Span` between two positions, such as the `@L` and `@R` locations of a grammar action.
+    /// # Arguments
+    ///
+    /// * `start` - The position of the first `char` in the span.
+    /// * `end` - The position one past the last `char` in the span.
+    /// # Panics
+    /// * If the end index is greater than the number of characters in the string.
+    /// * If the end index is less than the start index.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("let 😀 = 1;");
+    /// let start = s.position_at(4).unwrap();
+    /// let end = s.next_position(start).unwrap();
+    ///
+    /// assert_eq!(s.span_between(start, end), Span::new(4, 5));
+    /// ```
+    pub fn span_between(&self, start: Position, end: Position) -> Span {
+        if end.index > self.len() {
+            panic!("Span end: ({}) must be less than or equal to the number of UTF-8 characters in the string ({})!", end.index, self.len());
+        }
+
+        if end.index < start.index {
+            panic!("Span end: ({}) must be greater than or equal to Span start: ({})!", end.index, start.index);
+        }
+
+        Span::new(start.index, end.index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_at_works() {
+        let s = IndexableStr::new("0😀\n3\r\n6");
+
+        assert_eq!(s.position_at(0), Some(Position { index: 0, line: 0, column: 0 }));
+        assert_eq!(s.position_at(3), Some(Position { index: 3, line: 1, column: 0 }));
+        assert_eq!(s.position_at(6), Some(Position { index: 6, line: 2, column: 0 }));
+        assert_eq!(s.position_at(7), Some(Position { index: 7, line: 2, column: 1 }));
+        assert_eq!(s.position_at(8), None);
+    }
+
+    #[test]
+    fn test_next_position_at_end_is_none() {
+        let s = IndexableStr::new("😀");
+        let end = s.next_position(Position::default()).unwrap();
+
+        assert_eq!(s.next_position(end), None);
+    }
+
+    #[test]
+    fn test_span_between_works() {
+        let s = IndexableStr::new("0😀2345678😀");
+        let span = s.span_between(s.position_at(1).unwrap(), s.position_at(10).unwrap());
+
+        assert_eq!(&s[span], "😀2345678😀");
+    }
+
+    #[test]
+    fn test_span_between_with_end_less_than_start() {
+        let s = IndexableStr::new("0😀2345678😀");
+        let start = s.position_at(5).unwrap();
+        let end = s.position_at(2).unwrap();
+
+        let result = std::panic::catch_unwind(|| s.span_between(start, end));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{IndexableStr, IndexableString, Offset, Span};
+
+/// `MarkupStyle` chooses the brackets around the tags that `IndexableStr::markup_runs` recognizes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MarkupStyle {
+    /// Tags in angle brackets, such as `<b>`, `<color=red>`, `</color>` and `<br/>`.
+    Angle,
+    /// BBCode tags in square brackets, such as `[b]`, `[url=https://example.com]`, `[/url]` and `[hr/]`.
+    BbCode,
+}
+
+/// `TagKind` is whether a tag opens an element, closes one, or is an element on its own.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TagKind {
+    Open,
+    Close,
+    SelfClosing,
+}
+
+/// `MarkupRun` is a piece of a string returned by `IndexableStr::markup_runs`: either a run of text, or a tag with
+/// its name and its value, which is the text after the name without a leading `=`. Both hold the `Span` of `char`s
+/// they cover, including the brackets of a tag.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MarkupRun<'a> {
+    Text { span: Span, text: &'a str },
+    Tag { span: Span, kind: TagKind, name: &'a str, value: Option<&'a str> },
+}
+
+impl<'a> MarkupRun<'a> {
+    /// Returns the range of `char`s of the run in the original string.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, MarkupStyle, Span};
+    ///
+    /// let s = IndexableStr::new("😀<b>");
+    ///
+    /// assert_eq!(s.markup_runs(MarkupStyle::Angle).last().unwrap().span(), Span::new(1, 4));
+    /// ```
+    pub fn span(&self) -> Span {
+        match self {
+            MarkupRun::Text { span, .. } | MarkupRun::Tag { span, .. } => *span,
+        }
+    }
+}
+
+/// A tag at the start of a string: its length in bytes, kind, name and value.
+struct Tag<'a> {
+    length: usize,
+    kind: TagKind,
+    name: &'a str,
+    value: Option<&'a str>,
+}
+
+/// Returns the tag at the start of `str`, or `None` if it doesn't start with one. A tag name is made of alphanumeric
+/// `char`s, `-` and `_`, and must be followed by the closing bracket, a `=` or whitespace.
+fn tag_at_start(str: &str, open: char, close: char) -> Option<Tag<'_>> {
+    let inner_start: usize = open.len_utf8();
+
+    if !str.starts_with(open) {
+        return None;
+    }
+
+    let inner_end: usize = inner_start + str[inner_start..].find([open, close])?;
+
+    if !str[inner_end..].starts_with(close) {
+        return None;
+    }
+
+    let inner: &str = &str[inner_start..inner_end];
+    let (kind, inner): (TagKind, &str) = match (inner.strip_prefix('/'), inner.strip_suffix('/')) {
+        (Some(inner), _) => (TagKind::Close, inner),
+        (None, Some(inner)) => (TagKind::SelfClosing, inner),
+        (None, None) => (TagKind::Open, inner),
+    };
+    let name_length: usize = inner.find(|chr: char| !(chr.is_alphanumeric() || chr == '-' || chr == '_')).unwrap_or(inner.len());
+    let (name, rest): (&str, &str) = inner.split_at(name_length);
+
+    if name.is_empty() || !(rest.is_empty() || rest.starts_with('=') || rest.starts_with(char::is_whitespace)) {
+        return None;
+    }
+
+    let rest: &str = rest.trim_start();
+    let value: &str = rest.strip_prefix('=').unwrap_or(rest).trim();
+
+    if kind == TagKind::Close && !value.is_empty() {
+        return None;
+    }
+
+    Some(Tag {
+        length: inner_end + close.len_utf8(),
+        kind,
+        name,
+        value: match value.is_empty() {
+            true => None,
+            false => Some(value),
+        },
+    })
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns an iterator over the runs of text and the tags of a string with inline markup, in order, without
+    /// building a tree. Brackets that don't form a tag, such as `a < b`, are part of the text, and the nesting of
+    /// the tags isn't checked, so that it can be validated with the spans of the tags.
+    /// # Arguments
+    ///
+    /// * `style` - The brackets around the tags.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, MarkupRun, MarkupStyle, Span, TagKind};
+    ///
+    /// let s = IndexableStr::new("<color=red>😀 < 3</color><br/>");
+    /// let runs: Vec<MarkupRun> = s.markup_runs(MarkupStyle::Angle).collect();
+    ///
+    /// assert_eq!(runs, vec![
+    ///     MarkupRun::Tag { span: Span::new(0, 11), kind: TagKind::Open, name: "color", value: Some("red") },
+    ///     MarkupRun::Text { span: Span::new(11, 16), text: "😀 < 3" },
+    ///     MarkupRun::Tag { span: Span::new(16, 24), kind: TagKind::Close, name: "color", value: None },
+    ///     MarkupRun::Tag { span: Span::new(24, 29), kind: TagKind::SelfClosing, name: "br", value: None },
+    /// ]);
+    ///
+    /// let translated = IndexableStr::new("[b]fett[/i]");
+    /// let mut open: Vec<&str> = Vec::new();
+    /// let mut mismatched: Vec<Span> = Vec::new();
+    ///
+    /// for run in translated.markup_runs(MarkupStyle::BbCode) {
+    ///     match run {
+    ///         MarkupRun::Tag { kind: TagKind::Open, name, .. } => open.push(name),
+    ///         MarkupRun::Tag { span, kind: TagKind::Close, name, .. } if open.pop() != Some(name) => mismatched.push(span),
+    ///         _ => (),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(mismatched, vec![Span::new(7, 11)]);
+    /// ```
+    pub fn markup_runs(&self, style: MarkupStyle) -> impl Iterator<Item = MarkupRun<'a>> + 'a {
+        let (open, close): (char, char) = match style {
+            MarkupStyle::Angle => ('<', '>'),
+            MarkupStyle::BbCode => ('[', ']'),
+        };
+        let str: &'a str = self.str;
+        let mut byte_offset: usize = 0;
+        let mut index: usize = 0;
+
+        std::iter::from_fn(move || {
+            let rest: &'a str = &str[byte_offset..];
+            let start: usize = index;
+
+            if rest.is_empty() {
+                return None;
+            }
+
+            if let Some(tag) = tag_at_start(rest, open, close) {
+                byte_offset += tag.length;
+                index += rest[..tag.length].chars().count();
+
+                return Some(MarkupRun::Tag {
+                    span: Span::new(start, index),
+                    kind: tag.kind,
+                    name: tag.name,
+                    value: tag.value,
+                });
+            }
+
+            let length: usize = rest.match_indices(open)
+                .map(|(offset, _)| offset)
+                .find(|&offset| offset > 0 && tag_at_start(&rest[offset..], open, close).is_some())
+                .unwrap_or(rest.len());
+            let text: &'a str = &rest[..length];
+
+            byte_offset += length;
+            index += text.chars().count();
+
+            Some(MarkupRun::Text {
+                span: Span::new(start, index),
+                text,
+            })
+        })
+    }
+}
+
+impl IndexableString {
+    /// Returns an iterator over the runs of text and the tags of a string with inline markup. See
+    /// `IndexableStr::markup_runs`.
+    /// # Arguments
+    ///
+    /// * `style` - The brackets around the tags.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableString, MarkupRun, MarkupStyle};
+    ///
+    /// let s = IndexableString::from("[b]😀[/b]");
+    ///
+    /// assert_eq!(s.markup_runs(MarkupStyle::BbCode).filter(|run| matches!(run, MarkupRun::Tag { .. })).count(), 2);
+    /// ```
+    pub fn markup_runs(&self, style: MarkupStyle) -> impl Iterator<Item = MarkupRun<'_>> + '_ {
+        self.as_indexable_str().markup_runs(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markup_runs_leave_malformed_tags_in_the_text() {
+        let s = IndexableStr::new("a<<b> <> < c> <x.y> </b=1> <b ");
+        let runs: Vec<MarkupRun> = s.markup_runs(MarkupStyle::Angle).collect();
+
+        assert_eq!(runs, vec![
+            MarkupRun::Text { span: Span::new(0, 2), text: "a<" },
+            MarkupRun::Tag { span: Span::new(2, 5), kind: TagKind::Open, name: "b", value: None },
+            MarkupRun::Text { span: Span::new(5, 30), text: " <> < c> <x.y> </b=1> <b " },
+        ]);
+
+        let s = IndexableStr::new("[url = https://example.com/😀]link[/url]");
+        let runs: Vec<Span> = s.markup_runs(MarkupStyle::BbCode).map(|run| run.span()).collect();
+
+        assert_eq!(runs, vec![Span::new(0, 29), Span::new(29, 33), Span::new(33, 39)]);
+        assert!(matches!(s.markup_runs(MarkupStyle::BbCode).next(), Some(MarkupRun::Tag { value: Some("https://example.com/😀"), .. })));
+        assert_eq!(IndexableStr::new("").markup_runs(MarkupStyle::Angle).count(), 0);
+    }
+}
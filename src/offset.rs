@@ -0,0 +1,46 @@
+/// `Offset` is an unsigned integer type that an `IndexableStr` can store the byte offsets of its `char`s in: `u16`,
+/// `u32` or `usize`. The default, `u32`, suits most strings. `u16` makes the table of a string of up to 64 KiB a
+/// quarter smaller, and `usize` never has to switch widths. A string that is too long for the chosen type is indexed
+/// with `usize` offsets instead, so every type can index every string.
+pub trait Offset: private::Sealed + Copy + Ord + Send + Sync + 'static {
+    /// The largest byte offset that the type can hold.
+    #[doc(hidden)]
+    const MAX: usize;
+
+    /// Converts a byte offset, which must be at most `MAX`.
+    #[doc(hidden)]
+    fn from_usize(offset: usize) -> Self;
+
+    /// Converts the offset back into a byte offset.
+    #[doc(hidden)]
+    fn to_usize(self) -> usize;
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+    impl Sealed for usize {}
+}
+
+/// Implements `Offset` for an unsigned integer type that converts to and from `usize` with `as`.
+macro_rules! impl_offset {
+    ($ty:ty) => {
+        impl Offset for $ty {
+            const MAX: usize = <$ty>::MAX as usize;
+
+            fn from_usize(offset: usize) -> Self {
+                offset as $ty
+            }
+
+            fn to_usize(self) -> usize {
+                self as usize
+            }
+        }
+    };
+}
+
+impl_offset!(u16);
+impl_offset!(u32);
+impl_offset!(usize);
@@ -0,0 +1,151 @@
+use std::borrow::Cow;
+
+use crate::{IndexableStr, IndexableString, Offset, Span};
+
+/// `KeyValue` is one `key=value` pair: the decoded key and value, and the `Span`s of `char`s they were read from. A
+/// pair without a separator has an empty value, whose span is empty and at the end of the key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyValue<'a> {
+    pub key: Cow<'a, str>,
+    pub key_span: Span,
+    pub value: Cow<'a, str>,
+    pub value_span: Span,
+}
+
+/// Returns the pairs in the `separator` separated pieces of `str`, splitting each at its first `=`. Empty pieces are
+/// skipped, and `trim` removes the whitespace around keys and values.
+fn pairs<'a>(str: &'a str, separator: char, trim: bool, decode: fn(&'a str) -> Cow<'a, str>) -> impl Iterator<Item = KeyValue<'a>> + 'a {
+    let mut index: usize = 0;
+
+    str.split(separator).filter_map(move |piece| {
+        let start: usize = index;
+        let length: usize = piece.chars().count();
+
+        index += length + 1;
+
+        let (raw_key, raw_value): (&str, Option<&str>) = match piece.split_once('=') {
+            Some((key, value)) => (key, Some(value)),
+            None => (piece, None),
+        };
+        let (key, key_span): (&str, Span) = trimmed(raw_key, start, trim);
+
+        if key.is_empty() && raw_value.is_none() {
+            return None;
+        }
+
+        let (value, value_span): (&str, Span) = match raw_value {
+            Some(raw_value) => trimmed(raw_value, start + raw_key.chars().count() + 1, trim),
+            None => ("", Span::new(key_span.end, key_span.end)),
+        };
+
+        Some(KeyValue {
+            key: decode(key),
+            key_span,
+            value: decode(value),
+            value_span,
+        })
+    })
+}
+
+/// Returns `str`, without surrounding whitespace if `trim` is set, and its span given that it starts at `start`.
+fn trimmed(str: &str, start: usize, trim: bool) -> (&str, Span) {
+    let (kept, skipped): (&str, &str) = match trim {
+        true => (str.trim(), &str[..str.len() - str.trim_start().len()]),
+        false => (str, ""),
+    };
+    let kept_start: usize = start + skipped.chars().count();
+
+    (kept, Span::new(kept_start, kept_start + kept.chars().count()))
+}
+
+/// Decodes a query string component, where `+` is a space and bytes are percent escaped.
+fn decode_query(str: &str) -> Cow<'_, str> {
+    match str.contains(['%', '+']) {
+        true => Cow::Owned(IndexableStr::new(&str.replace('+', " ")).percent_decode().0.to_string()),
+        false => Cow::Borrowed(str),
+    }
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns an iterator over the pairs of a query string such as `key=value&key=value`. Keys and values are
+    /// percent decoded, with `+` decoded as a space, while their spans give the encoded text.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("q=%F0%9F%98%80+x&page=2&debug");
+    /// let pairs: Vec<_> = s.query_pairs().collect();
+    ///
+    /// assert_eq!(pairs[0].value, "😀 x");
+    /// assert_eq!(pairs[0].value_span, Span::new(2, 16));
+    /// assert_eq!(pairs[1].key_span, Span::new(17, 21));
+    /// assert_eq!(pairs[2].key, "debug");
+    /// assert_eq!(pairs[2].value, "");
+    /// ```
+    pub fn query_pairs(&self) -> impl Iterator<Item = KeyValue<'a>> + 'a {
+        pairs(self.str, '&', false, decode_query)
+    }
+
+    /// Returns an iterator over the `key = value` lines of a configuration file. Whitespace around keys and values
+    /// isn't part of them, and blank lines and lines starting with `#` or `;` are skipped.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("# 😀\nname = demo 😀\r\n\nport=80\n");
+    /// let pairs: Vec<_> = s.config_pairs().collect();
+    ///
+    /// assert_eq!(pairs.len(), 2);
+    /// assert_eq!(pairs[0].key_span, Span::new(4, 8));
+    /// assert_eq!(pairs[0].value, "demo 😀");
+    /// assert_eq!(pairs[0].value_span, Span::new(11, 17));
+    /// assert_eq!(pairs[1].value, "80");
+    /// ```
+    pub fn config_pairs(&self) -> impl Iterator<Item = KeyValue<'a>> + 'a {
+        pairs(self.str, '\n', true, Cow::Borrowed).filter(|pair| !pair.key.starts_with(['#', ';']))
+    }
+}
+
+impl IndexableString {
+    /// Returns an iterator over the pairs of a query string. See `IndexableStr::query_pairs`.
+    pub fn query_pairs(&self) -> impl Iterator<Item = KeyValue<'_>> + '_ {
+        self.as_indexable_str().query_pairs()
+    }
+
+    /// Returns an iterator over the `key = value` lines of a configuration file. See `IndexableStr::config_pairs`.
+    pub fn config_pairs(&self) -> impl Iterator<Item = KeyValue<'_>> + '_ {
+        self.as_indexable_str().config_pairs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spans_select_the_encoded_key_and_value() {
+        let str: &str = "😀 =  a = b \n&&k%3D=v+1&=x";
+        let s = IndexableStr::new(str);
+        let chars: Vec<char> = str.chars().collect();
+        let text = |span: Span| chars[span.start..span.end].iter().collect::<String>();
+
+        for pair in s.config_pairs() {
+            assert_eq!(text(pair.key_span), pair.key);
+            assert_eq!(text(pair.value_span), pair.value);
+        }
+
+        let config: Vec<KeyValue<'_>> = s.config_pairs().collect();
+
+        assert_eq!((config[0].key.as_ref(), config[0].value.as_ref()), ("😀", "a = b"));
+        assert_eq!(config[1].key, "&&k%3D");
+
+        let query: Vec<KeyValue<'_>> = s.query_pairs().collect();
+
+        assert_eq!(query.len(), 3);
+        assert_eq!((query[1].key.as_ref(), query[1].value.as_ref()), ("k=", "v 1"));
+        assert_eq!((text(query[1].key_span), text(query[1].value_span)), ("k%3D".to_string(), "v+1".to_string()));
+        assert_eq!((query[2].key.as_ref(), query[2].key_span), ("", Span::new(23, 23)));
+    }
+}
@@ -0,0 +1,519 @@
+use std::ops::Range;
+
+use crate::{IndexableStr, IndexableString, Offset, SubStr};
+
+/// `Pattern` is something that can be searched for in an indexable string: a `char`, a `&str`, a slice or array of
+/// `char`s that matches any of them, or a `FnMut(char) -> bool` that matches the `char`s it returns `true` for. It
+/// mirrors the unstable `std::str::pattern::Pattern`, and matches are found by the same `str` searchers.
+pub trait Pattern: private::Sealed {
+    /// Returns the byte ranges of the non-overlapping matches in `haystack`, from left to right.
+    #[doc(hidden)]
+    fn byte_matches<'h>(self, haystack: &'h str) -> impl Iterator<Item = Range<usize>> + 'h
+    where
+        Self: 'h;
+
+    /// Returns the byte ranges of the non-overlapping matches in `haystack`, from right to left.
+    #[doc(hidden)]
+    fn rbyte_matches<'h>(self, haystack: &'h str) -> impl Iterator<Item = Range<usize>> + 'h
+    where
+        Self: 'h;
+
+    /// Returns `haystack` without the matches repeatedly found at its start, its end, or both.
+    #[doc(hidden)]
+    fn trim_matches_of(self, haystack: &str, start: bool, end: bool) -> &str;
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for char {}
+    impl Sealed for &str {}
+    impl Sealed for &[char] {}
+    impl<const N: usize> Sealed for [char; N] {}
+    impl<const N: usize> Sealed for &[char; N] {}
+    impl<F: FnMut(char) -> bool> Sealed for F {}
+}
+
+/// Implements `Pattern` for a type that the `str` searchers accept as a `char` pattern, which can be trimmed from
+/// both ends at once.
+macro_rules! impl_char_pattern {
+    ([$($generics:tt)*] $ty:ty) => {
+        impl<$($generics)*> Pattern for $ty {
+            fn byte_matches<'h>(self, haystack: &'h str) -> impl Iterator<Item = Range<usize>> + 'h
+            where
+                Self: 'h,
+            {
+                haystack.match_indices(self).map(|(offset, matched)| offset..offset + matched.len())
+            }
+
+            fn rbyte_matches<'h>(self, haystack: &'h str) -> impl Iterator<Item = Range<usize>> + 'h
+            where
+                Self: 'h,
+            {
+                haystack.rmatch_indices(self).map(|(offset, matched)| offset..offset + matched.len())
+            }
+
+            fn trim_matches_of(self, haystack: &str, start: bool, end: bool) -> &str {
+                match (start, end) {
+                    (true, true) => haystack.trim_matches(self),
+                    (true, false) => haystack.trim_start_matches(self),
+                    (false, true) => haystack.trim_end_matches(self),
+                    (false, false) => haystack,
+                }
+            }
+        }
+    };
+}
+
+impl_char_pattern!([] char);
+impl_char_pattern!([] &[char]);
+impl_char_pattern!([const N: usize] [char; N]);
+impl_char_pattern!([const N: usize] &[char; N]);
+impl_char_pattern!([F: FnMut(char) -> bool] F);
+
+impl Pattern for &str {
+    fn byte_matches<'h>(self, haystack: &'h str) -> impl Iterator<Item = Range<usize>> + 'h
+    where
+        Self: 'h,
+    {
+        haystack.match_indices(self).map(|(offset, matched)| offset..offset + matched.len())
+    }
+
+    fn rbyte_matches<'h>(self, haystack: &'h str) -> impl Iterator<Item = Range<usize>> + 'h
+    where
+        Self: 'h,
+    {
+        haystack.rmatch_indices(self).map(|(offset, matched)| offset..offset + matched.len())
+    }
+
+    fn trim_matches_of(self, haystack: &str, start: bool, end: bool) -> &str {
+        let trimmed: &str = match start {
+            true => haystack.trim_start_matches(self),
+            false => haystack,
+        };
+
+        match end {
+            true => trimmed.trim_end_matches(self),
+            false => trimmed,
+        }
+    }
+}
+
+/// Returns the ranges of `char`s between the byte ranges of `matches`, which are in order from left to right, like
+/// `str::split`. The `char`s are counted as the matches are found, so the whole search takes a single pass over the
+/// string.
+fn split_ranges<'h>(haystack: &'h str, matches: impl Iterator<Item = Range<usize>> + 'h) -> impl Iterator<Item = Range<usize>> + 'h {
+    let mut matches = matches.map(Some).chain(std::iter::once(None));
+    let mut byte_offset: usize = 0;
+    let mut index: usize = 0;
+
+    std::iter::from_fn(move || {
+        let start: usize = index;
+
+        match matches.next()? {
+            Some(range) => {
+                let end: usize = start + haystack[byte_offset..range.start].chars().count();
+
+                index = end + haystack[range.clone()].chars().count();
+                byte_offset = range.end;
+
+                Some(start..end)
+            },
+            None => Some(start..start + haystack[byte_offset..].chars().count()),
+        }
+    })
+}
+
+/// Returns the ranges of `char`s between the byte ranges of `matches`, which are in order from right to left, like
+/// `str::rsplit`. `chars_length` is the number of `char`s in `haystack`.
+fn rsplit_ranges<'h>(haystack: &'h str, chars_length: usize, matches: impl Iterator<Item = Range<usize>> + 'h) -> impl Iterator<Item = Range<usize>> + 'h {
+    let mut matches = matches.map(Some).chain(std::iter::once(None));
+    let mut byte_offset: usize = haystack.len();
+    let mut index: usize = chars_length;
+
+    std::iter::from_fn(move || {
+        let end: usize = index;
+
+        match matches.next()? {
+            Some(range) => {
+                let start: usize = end - haystack[range.end..byte_offset].chars().count();
+
+                index = start - haystack[range.clone()].chars().count();
+                byte_offset = range.start;
+
+                Some(start..end)
+            },
+            None => Some(0..end),
+        }
+    })
+}
+
+/// Drops the last of `ranges` if it's empty, like `str::split_terminator`.
+fn without_empty_last(ranges: impl Iterator<Item = Range<usize>>) -> impl Iterator<Item = Range<usize>> {
+    let mut ranges = ranges.peekable();
+
+    std::iter::from_fn(move || {
+        let range: Range<usize> = ranges.next()?;
+
+        match range.is_empty() && ranges.peek().is_none() {
+            true => None,
+            false => Some(range),
+        }
+    })
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns the number of non-overlapping matches of `pattern`, without collecting their positions. As with
+    /// `str::matches`, an empty `&str` matches before every `char` and at the end.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure to count.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("a😀b\nc😀😀\n");
+    ///
+    /// assert_eq!(s.count_matches('\n'), 2);
+    /// assert_eq!(s.count_matches("😀"), 3);
+    /// assert_eq!(s.count_matches("😀😀"), 1);
+    /// ```
+    pub fn count_matches<P: Pattern>(&self, pattern: P) -> usize {
+        pattern.byte_matches(self.str).count()
+    }
+
+    /// Returns the index of the first `char` of the first match of `pattern`, or `None` if there isn't one.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure to look for.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("let 😀 = 42;");
+    ///
+    /// assert_eq!(s.find('='), Some(6));
+    /// assert_eq!(s.find("42"), Some(8));
+    /// assert_eq!(s.find(['=', ';']), Some(6));
+    /// assert_eq!(s.find(|chr: char| chr.is_ascii_digit()), Some(8));
+    /// assert_eq!(s.find('x'), None);
+    /// ```
+    pub fn find<P: Pattern>(&self, pattern: P) -> Option<usize> {
+        let range: Range<usize> = pattern.byte_matches(self.str).next()?;
+
+        self.char_index_at_byte(range.start)
+    }
+
+    /// Returns an iterator over the `SubStr`s between the matches of `pattern`, like `str::split`. The `span` of
+    /// each piece gives its position.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure that separates the pieces.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("😀, b,,c");
+    /// let pieces: Vec<&str> = s.split([',', ' ']).map(|piece| piece.as_str()).collect();
+    ///
+    /// assert_eq!(pieces, vec!["😀", "", "b", "", "c"]);
+    /// assert_eq!(s.split(",").nth(1).unwrap().span(), Span::new(2, 4));
+    /// ```
+    pub fn split<'s, P: Pattern + 's>(&'s self, pattern: P) -> impl Iterator<Item = SubStr<'s, O>> + 's {
+        split_ranges(self.str, pattern.byte_matches(self.str)).map(move |range| self.slice(range))
+    }
+
+    /// Returns an iterator over the `SubStr`s between the matches of `pattern`, without the empty piece after a match
+    /// at the end, like `str::split_terminator`.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure that ends each piece.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("😀;b;;");
+    /// let pieces: Vec<&str> = s.split_terminator(';').map(|piece| piece.as_str()).collect();
+    ///
+    /// assert_eq!(pieces, vec!["😀", "b", ""]);
+    /// ```
+    pub fn split_terminator<'s, P: Pattern + 's>(&'s self, pattern: P) -> impl Iterator<Item = SubStr<'s, O>> + 's {
+        without_empty_last(split_ranges(self.str, pattern.byte_matches(self.str))).map(move |range| self.slice(range))
+    }
+
+    /// Returns an iterator over at most `n` `SubStr`s between the matches of `pattern`, like `str::splitn`. The last
+    /// piece holds the rest of the string.
+    /// # Arguments
+    ///
+    /// * `n` - The largest number of pieces.
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure that separates the pieces.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("😀:value:a:b");
+    /// let fields: Vec<_> = s.splitn(3, ':').collect();
+    ///
+    /// assert_eq!(fields[1].as_str(), "value");
+    /// assert_eq!(fields[2].as_str(), "a:b");
+    /// assert_eq!(fields[2].span(), Span::new(8, 11));
+    /// ```
+    pub fn splitn<'s, P: Pattern + 's>(&'s self, n: usize, pattern: P) -> impl Iterator<Item = SubStr<'s, O>> + 's {
+        split_ranges(self.str, pattern.byte_matches(self.str).take(n.saturating_sub(1)))
+            .take(n)
+            .map(move |range| self.slice(range))
+    }
+
+    /// Returns an iterator over the `SubStr`s between the matches of `pattern`, from right to left, like
+    /// `str::rsplit`.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure that separates the pieces.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("src/😀/main.rs");
+    /// let file = s.rsplit('/').next().unwrap();
+    ///
+    /// assert_eq!(file.as_str(), "main.rs");
+    /// assert_eq!(file.span(), Span::new(6, 13));
+    /// ```
+    pub fn rsplit<'s, P: Pattern + 's>(&'s self, pattern: P) -> impl Iterator<Item = SubStr<'s, O>> + 's {
+        rsplit_ranges(self.str, self.chars_length, pattern.rbyte_matches(self.str)).map(move |range| self.slice(range))
+    }
+
+    /// Returns a `SubStr` view without the matches of `pattern` that are repeatedly found at the start and the end.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure to remove.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("😀😀x😀y😀");
+    ///
+    /// assert_eq!(s.trim_matches('😀').as_str(), "x😀y");
+    /// assert_eq!(s.trim_matches('😀').span(), Span::new(2, 5));
+    /// assert_eq!(IndexableStr::new("--x--").trim_matches("--").as_str(), "x");
+    /// ```
+    pub fn trim_matches<P: Pattern>(&self, pattern: P) -> SubStr<'_, O> {
+        self.slice(self.trimmed_matches_range(pattern, true, true))
+    }
+
+    /// Returns a `SubStr` view without the matches of `pattern` that are repeatedly found at the start.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure to remove.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("007😀");
+    ///
+    /// assert_eq!(s.trim_start_matches('0').as_str(), "7😀");
+    /// ```
+    pub fn trim_start_matches<P: Pattern>(&self, pattern: P) -> SubStr<'_, O> {
+        self.slice(self.trimmed_matches_range(pattern, true, false))
+    }
+
+    /// Returns a `SubStr` view without the matches of `pattern` that are repeatedly found at the end.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure to remove.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("😀x123");
+    ///
+    /// assert_eq!(s.trim_end_matches(char::is_numeric).as_str(), "😀x");
+    /// ```
+    pub fn trim_end_matches<P: Pattern>(&self, pattern: P) -> SubStr<'_, O> {
+        self.slice(self.trimmed_matches_range(pattern, false, true))
+    }
+
+    fn trimmed_matches_range<P: Pattern>(&self, pattern: P, start: bool, end: bool) -> Range<usize> {
+        let trimmed: &str = pattern.trim_matches_of(self.str, start, end);
+        let byte_start: usize = trimmed.as_ptr() as usize - self.str.as_ptr() as usize;
+
+        self.char_index_at_byte(byte_start).unwrap()..self.char_index_at_byte(byte_start + trimmed.len()).unwrap()
+    }
+}
+
+impl IndexableString {
+    /// Returns the number of non-overlapping matches of `pattern`. See `IndexableStr::count_matches`.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure to count.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("a😀b\nc😀😀\n");
+    ///
+    /// assert_eq!(s.count_matches('😀'), 3);
+    /// ```
+    pub fn count_matches<P: Pattern>(&self, pattern: P) -> usize {
+        self.as_indexable_str().count_matches(pattern)
+    }
+
+    /// Returns the index of the first `char` of the first match of `pattern`. See `IndexableStr::find`.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure to look for.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("😀 = 42;");
+    ///
+    /// assert_eq!(s.find(char::is_numeric), Some(4));
+    /// ```
+    pub fn find<P: Pattern>(&self, pattern: P) -> Option<usize> {
+        self.as_indexable_str().find(pattern)
+    }
+
+    /// Returns an iterator over the `SubStr`s between the matches of `pattern`. See `IndexableStr::split`.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure that separates the pieces.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("😀:b");
+    ///
+    /// assert_eq!(s.split(':').map(|piece| piece.as_str()).collect::<Vec<&str>>(), vec!["😀", "b"]);
+    /// ```
+    pub fn split<'s, P: Pattern + 's>(&'s self, pattern: P) -> impl Iterator<Item = SubStr<'s>> + 's {
+        split_ranges(self.as_str(), pattern.byte_matches(self.as_str())).map(move |range| self.slice(range))
+    }
+
+    /// Returns an iterator over the `SubStr`s between the matches of `pattern`, without the empty piece after a match
+    /// at the end. See `IndexableStr::split_terminator`.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure that ends each piece.
+    pub fn split_terminator<'s, P: Pattern + 's>(&'s self, pattern: P) -> impl Iterator<Item = SubStr<'s>> + 's {
+        without_empty_last(split_ranges(self.as_str(), pattern.byte_matches(self.as_str()))).map(move |range| self.slice(range))
+    }
+
+    /// Returns an iterator over at most `n` `SubStr`s between the matches of `pattern`. See `IndexableStr::splitn`.
+    /// # Arguments
+    ///
+    /// * `n` - The largest number of pieces.
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure that separates the pieces.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("a:😀:c");
+    ///
+    /// assert_eq!(s.splitn(2, ':').last().unwrap().as_str(), "😀:c");
+    /// ```
+    pub fn splitn<'s, P: Pattern + 's>(&'s self, n: usize, pattern: P) -> impl Iterator<Item = SubStr<'s>> + 's {
+        split_ranges(self.as_str(), pattern.byte_matches(self.as_str()).take(n.saturating_sub(1)))
+            .take(n)
+            .map(move |range| self.slice(range))
+    }
+
+    /// Returns an iterator over the `SubStr`s between the matches of `pattern`, from right to left. See
+    /// `IndexableStr::rsplit`.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure that separates the pieces.
+    pub fn rsplit<'s, P: Pattern + 's>(&'s self, pattern: P) -> impl Iterator<Item = SubStr<'s>> + 's {
+        rsplit_ranges(self.as_str(), self.len(), pattern.rbyte_matches(self.as_str())).map(move |range| self.slice(range))
+    }
+
+    /// Returns a `SubStr` view without the matches of `pattern` at the start and the end. See
+    /// `IndexableStr::trim_matches`.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure to remove.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("*😀*");
+    ///
+    /// assert_eq!(s.trim_matches('*').as_str(), "😀");
+    /// ```
+    pub fn trim_matches<P: Pattern>(&self, pattern: P) -> SubStr<'_> {
+        self.slice(self.as_indexable_str().trimmed_matches_range(pattern, true, true))
+    }
+
+    /// Returns a `SubStr` view without the matches of `pattern` at the start. See `IndexableStr::trim_start_matches`.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure to remove.
+    pub fn trim_start_matches<P: Pattern>(&self, pattern: P) -> SubStr<'_> {
+        self.slice(self.as_indexable_str().trimmed_matches_range(pattern, true, false))
+    }
+
+    /// Returns a `SubStr` view without the matches of `pattern` at the end. See `IndexableStr::trim_end_matches`.
+    /// # Arguments
+    ///
+    /// * `pattern` - A `char`, a `&str`, a slice or array of `char`s, or a closure to remove.
+    pub fn trim_end_matches<P: Pattern>(&self, pattern: P) -> SubStr<'_> {
+        self.slice(self.as_indexable_str().trimmed_matches_range(pattern, false, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_matches_agrees_with_str_matches() {
+        let str: &str = "0😀2345678😀😀😀aa";
+        let s = IndexableStr::new(str);
+
+        for pattern in ["", "a", "aa", "😀", "😀😀", "x"] {
+            assert_eq!(s.count_matches(pattern), str.matches(pattern).count());
+        }
+
+        assert_eq!(s.count_matches('😀'), 4);
+    }
+
+    #[test]
+    fn test_split_and_trim_agree_with_str() {
+        let str: &str = "😀ab😀😀cab😀";
+        let s = IndexableStr::new(str);
+        let chars: Vec<char> = str.chars().collect();
+
+        for pattern in ["", "a", "ab", "😀", "😀😀", "x"] {
+            let pieces: Vec<&str> = s.split(pattern).map(|piece| piece.as_str()).collect();
+
+            assert_eq!(pieces, str.split(pattern).collect::<Vec<&str>>());
+            assert_eq!(s.trim_matches(pattern).as_str(), str.trim_start_matches(pattern).trim_end_matches(pattern));
+        }
+
+        for piece in s.split(|chr: char| chr == 'c' || chr == '😀') {
+            assert_eq!(piece.as_str(), chars[piece.span().start..piece.span().end].iter().collect::<String>());
+        }
+
+        assert_eq!(s.find(&['c', 'b'][..]), Some(2));
+        assert_eq!(s.trim_end_matches(['😀', 'b']).as_str(), "😀ab😀😀ca");
+    }
+
+    #[test]
+    fn test_split_variants_agree_with_str() {
+        for str in ["", "a", "aaa", "😀aa😀", "a😀a😀aaa", "😀😀"] {
+            let s = IndexableStr::new(str);
+
+            for pattern in ["", "a", "aa", "😀"] {
+                let pieces = |pieces: &mut dyn Iterator<Item = SubStr<'_>>| pieces.map(|piece| piece.as_str().to_string()).collect::<Vec<String>>();
+                let strs = |pieces: &mut dyn Iterator<Item = &str>| pieces.map(|piece| piece.to_string()).collect::<Vec<String>>();
+
+                assert_eq!(pieces(&mut s.split_terminator(pattern)), strs(&mut str.split_terminator(pattern)));
+                assert_eq!(pieces(&mut s.rsplit(pattern)), strs(&mut str.rsplit(pattern)));
+
+                for n in 0..4 {
+                    assert_eq!(pieces(&mut s.splitn(n, pattern)), strs(&mut str.splitn(n, pattern)));
+                }
+            }
+        }
+    }
+}
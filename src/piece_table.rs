@@ -0,0 +1,443 @@
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    ops::{Index, Range},
+};
+
+use crate::{
+    error::{check_index, check_range},
+    IndexableString, IndexableText,
+};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Source {
+    Original,
+    Added,
+}
+
+/// `Piece` is a run of `char`s from one of the buffers.
+#[derive(Copy, Clone)]
+struct Piece {
+    source: Source,
+    start: usize,
+    length: usize,
+}
+
+/// `IndexablePieceTable` is an editable indexable string for text that changes often, such as an editor buffer.
+///
+/// The original text is never modified. Inserted text is appended to a second buffer, and the document is a list of
+/// pieces that each refer to a range of `char`s in one of the two buffers. An edit only splits and replaces pieces,
+/// so it never moves the rest of the text, and both buffers keep their indexes.
+///
+/// Because the document isn't stored contiguously, `substring` returns a `Cow<str>`, which only allocates when the
+/// range spans more than one piece.
+///
+/// # Examples
+/// ```
+/// use indexable_str::IndexablePieceTable;
+///
+/// let mut s = IndexablePieceTable::new("0😀2345678😀");
+///
+/// s.edit(2..8, "é");
+/// s.edit(0..0, "中");
+///
+/// assert_eq!(s.to_string(), "中0😀é8😀");
+/// assert_eq!(s[3], 'é');
+/// assert_eq!(s.substring(1..4), "0😀é");
+/// ```
+#[derive(Clone)]
+pub struct IndexablePieceTable {
+    original: IndexableString,
+    added: IndexableString,
+    pieces: Vec<Piece>,
+    piece_starts: Vec<usize>,
+    chars_length: usize,
+}
+
+impl IndexablePieceTable {
+    /// Returns a piece table holding a copy of the specified string slice.
+    /// # Arguments
+    ///
+    /// * `str` - The original text.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexablePieceTable;
+    ///
+    /// let s = IndexablePieceTable::new("0😀2345678😀");
+    /// ```
+    pub fn new(str: &str) -> IndexablePieceTable {
+        let original: IndexableString = IndexableString::from(str);
+        let chars_length: usize = original.len();
+
+        let pieces: Vec<Piece> = match chars_length {
+            0 => Vec::new(),
+            _ => vec![Piece {
+                source: Source::Original,
+                start: 0,
+                length: chars_length,
+            }],
+        };
+
+        let piece_starts: Vec<usize> = pieces.iter().map(|_| 0).collect();
+
+        IndexablePieceTable {
+            original,
+            added: IndexableString::from(""),
+            pieces,
+            piece_starts,
+            chars_length,
+        }
+    }
+
+    /// Returns a `usize` for the number of `char`s in the document.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexablePieceTable;
+    ///
+    /// let s = IndexablePieceTable::new("0😀2345678😀");
+    ///
+    /// assert_eq!(s.len(), 10);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.chars_length
+    }
+
+    /// Returns `true` if the document contains no `char`s.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexablePieceTable;
+    ///
+    /// assert!(IndexablePieceTable::new("").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.chars_length == 0
+    }
+
+    /// Returns the text of a range of `char`s. The text is borrowed if the range is within a single piece, and
+    /// copied into a new `String` otherwise.
+    /// # Arguments
+    ///
+    /// * `range` - The range of `char`s.
+    /// # Panics
+    /// * If the range end is greater than the number of characters in the document.
+    /// * If the range end is less than the range start.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexablePieceTable;
+    ///
+    /// let mut s = IndexablePieceTable::new("0😀2345678😀");
+    ///
+    /// s.edit(5..5, "é");
+    ///
+    /// assert_eq!(s.substring(1..5), "😀234");
+    /// assert_eq!(s.substring(4..7), "4é5");
+    /// ```
+    pub fn substring(&self, range: Range<usize>) -> Cow<'_, str> {
+        self.check_range(range.start, range.end);
+
+        if range.start == range.end {
+            return Cow::Borrowed("");
+        }
+
+        let first_piece: usize = self.piece_index(range.start);
+
+        if range.end <= self.piece_starts[first_piece] + self.pieces[first_piece].length {
+            let start: usize = range.start - self.piece_starts[first_piece];
+            let end: usize = range.end - self.piece_starts[first_piece];
+
+            return Cow::Borrowed(self.piece_str(first_piece, start..end));
+        }
+
+        let mut string: String = String::new();
+
+        for piece_index in first_piece..self.pieces.len() {
+            let piece_start: usize = self.piece_starts[piece_index];
+
+            if piece_start >= range.end {
+                break;
+            }
+
+            let start: usize = range.start.saturating_sub(piece_start);
+            let end: usize = (range.end - piece_start).min(self.pieces[piece_index].length);
+
+            string.push_str(self.piece_str(piece_index, start..end));
+        }
+
+        Cow::Owned(string)
+    }
+
+    /// Returns an iterator over the pieces of the document as string slices, in order.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexablePieceTable;
+    ///
+    /// let mut s = IndexablePieceTable::new("0123");
+    ///
+    /// s.edit(2..2, "😀");
+    ///
+    /// assert_eq!(s.chunks().collect::<Vec<&str>>(), vec!["01", "😀", "23"]);
+    /// ```
+    pub fn chunks(&self) -> impl Iterator<Item = &str> + '_ {
+        (0..self.pieces.len()).map(|piece_index| self.piece_str(piece_index, 0..self.pieces[piece_index].length))
+    }
+
+    /// Replaces a range of `char`s with `replacement`.
+    ///
+    /// The replacement is appended to the added text buffer, and only the pieces around the range are split or
+    /// replaced.
+    /// # Arguments
+    ///
+    /// * `range` - The range of `char`s to replace.
+    /// * `replacement` - The text to replace them with.
+    /// # Panics
+    /// * If the range end is greater than the number of characters in the document.
+    /// * If the range end is less than the range start.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexablePieceTable;
+    ///
+    /// let mut s = IndexablePieceTable::new("0😀2345678😀");
+    ///
+    /// s.edit(1..2, "1");
+    ///
+    /// assert_eq!(s.to_string(), "012345678😀");
+    /// ```
+    pub fn edit(&mut self, range: Range<usize>, replacement: &str) {
+        self.check_range(range.start, range.end);
+
+        let start_piece: usize = self.split_at(range.start);
+        let end_piece: usize = self.split_at(range.end);
+
+        let replacement_piece: Option<Piece> = match replacement.is_empty() {
+            true => None,
+            false => {
+                let added_length: usize = self.added.len();
+
+                self.added.edit(added_length..added_length, replacement);
+
+                Some(Piece {
+                    source: Source::Added,
+                    start: added_length,
+                    length: self.added.len() - added_length,
+                })
+            },
+        };
+
+        self.pieces.splice(start_piece..end_piece, replacement_piece);
+        self.update_piece_starts(start_piece);
+    }
+
+    fn check_range(&self, start_index: usize, end_index: usize) {
+        if let Err(error) = check_range(start_index, end_index, self.chars_length) {
+            panic!("{error}");
+        }
+    }
+
+    fn buffer(&self, source: Source) -> &IndexableString {
+        match source {
+            Source::Original => &self.original,
+            Source::Added => &self.added,
+        }
+    }
+
+    fn piece_str(&self, piece_index: usize, range: Range<usize>) -> &str {
+        let piece: Piece = self.pieces[piece_index];
+
+        &self.buffer(piece.source)[piece.start + range.start..piece.start + range.end]
+    }
+
+    /// Returns the index of the piece containing the `char` at `index`, which must be less than `len()`.
+    fn piece_index(&self, index: usize) -> usize {
+        match self.piece_starts.binary_search(&index) {
+            Ok(piece_index) => piece_index,
+            Err(piece_index) => piece_index - 1,
+        }
+    }
+
+    /// Splits the pieces so that one starts at `index`, and returns the index of that piece.
+    fn split_at(&mut self, index: usize) -> usize {
+        if index == self.chars_length {
+            return self.pieces.len();
+        }
+
+        let piece_index: usize = self.piece_index(index);
+        let offset: usize = index - self.piece_starts[piece_index];
+
+        if offset == 0 {
+            return piece_index;
+        }
+
+        let piece: Piece = self.pieces[piece_index];
+
+        self.pieces[piece_index].length = offset;
+        self.pieces.insert(piece_index + 1, Piece {
+            source: piece.source,
+            start: piece.start + offset,
+            length: piece.length - offset,
+        });
+        self.piece_starts.insert(piece_index + 1, index);
+
+        piece_index + 1
+    }
+
+    /// Recalculates the starting `char` index of every piece from `first_piece` on.
+    fn update_piece_starts(&mut self, first_piece: usize) {
+        self.piece_starts.truncate(first_piece);
+
+        let mut piece_start: usize = match first_piece {
+            0 => 0,
+            _ => self.piece_starts[first_piece - 1] + self.pieces[first_piece - 1].length,
+        };
+
+        for piece in &self.pieces[first_piece..] {
+            self.piece_starts.push(piece_start);
+            piece_start += piece.length;
+        }
+
+        self.chars_length = piece_start;
+    }
+}
+
+impl Display for IndexablePieceTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for chunk in self.chunks() {
+            write!(f, "{}", chunk)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Byte offsets are offsets in the text of the whole document, which isn't stored contiguously, so converting between
+/// `char` indices and byte offsets walks the pieces.
+impl IndexableText for IndexablePieceTable {
+    fn char_count(&self) -> usize {
+        self.chars_length
+    }
+
+    fn char_at(&self, index: usize) -> Option<char> {
+        (index < self.chars_length).then(|| self[index])
+    }
+
+    fn char_to_byte(&self, index: usize) -> Option<usize> {
+        if index > self.chars_length {
+            return None;
+        }
+
+        let mut byte_offset: usize = 0;
+
+        for (piece_index, piece) in self.pieces.iter().enumerate() {
+            let offset: usize = index - self.piece_starts[piece_index];
+
+            if offset < piece.length {
+                return Some(byte_offset + self.piece_str(piece_index, 0..offset).len());
+            }
+
+            byte_offset += self.piece_str(piece_index, 0..piece.length).len();
+        }
+
+        Some(byte_offset)
+    }
+
+    fn byte_to_char(&self, byte_offset: usize) -> Option<usize> {
+        let mut piece_byte_start: usize = 0;
+
+        for (piece_index, piece) in self.pieces.iter().enumerate() {
+            let piece_str: &str = self.piece_str(piece_index, 0..piece.length);
+
+            if byte_offset < piece_byte_start + piece_str.len() {
+                let buffer: &IndexableString = self.buffer(piece.source);
+                let buffer_byte_start: usize = buffer.char_to_byte(piece.start).unwrap();
+                let index: usize = buffer.byte_to_char(buffer_byte_start + byte_offset - piece_byte_start)?;
+
+                return Some(self.piece_starts[piece_index] + index - piece.start);
+            }
+
+            piece_byte_start += piece_str.len();
+        }
+
+        (byte_offset == piece_byte_start).then_some(self.chars_length)
+    }
+
+    fn char_slice(&self, range: Range<usize>) -> Option<Cow<'_, str>> {
+        (range.start <= range.end && range.end <= self.chars_length).then(|| self.substring(range))
+    }
+}
+
+/// # Panics
+/// * If the index is greater than or equal to the number of characters in the document.
+impl Index<usize> for IndexablePieceTable {
+    type Output = char;
+
+    fn index(&self, index: usize) -> &char {
+        if let Err(error) = check_index(index, self.chars_length) {
+            panic!("{error}");
+        }
+
+        let piece_index: usize = self.piece_index(index);
+        let piece: Piece = self.pieces[piece_index];
+
+        &self.buffer(piece.source)[piece.start + index - self.piece_starts[piece_index]]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edits_agree_with_string_edits() {
+        let mut s = IndexablePieceTable::new("0😀2345678😀");
+        let mut expected: Vec<char> = "0😀2345678😀".chars().collect();
+        let edits: [(Range<usize>, &str); 6] = [(2..4, "é中"), (0..0, "ab"), (5..12, ""), (1..3, "😀"), (4..4, "z"), (0..5, "")];
+
+        for (range, replacement) in edits {
+            s.edit(range.clone(), replacement);
+            expected.splice(range, replacement.chars());
+
+            let expected_string: String = expected.iter().collect();
+
+            assert_eq!(s.to_string(), expected_string);
+            assert_eq!(s.len(), expected.len());
+
+            for (index, chr) in expected.iter().enumerate() {
+                assert_eq!(s[index], *chr);
+            }
+
+            for start in 0..=expected.len() {
+                for end in start..=expected.len() {
+                    assert_eq!(s.substring(start..end), expected[start..end].iter().collect::<String>());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_substring_within_one_piece_is_borrowed() {
+        let mut s = IndexablePieceTable::new("0😀2345678😀");
+
+        s.edit(5..5, "é");
+
+        assert!(matches!(s.substring(1..5), Cow::Borrowed(_)));
+        assert!(matches!(s.substring(4..7), Cow::Owned(_)));
+    }
+
+    #[test]
+    fn test_index_out_of_bounds_panics() {
+        let s = IndexablePieceTable::new("0😀2");
+
+        let result = std::panic::catch_unwind(|| s[3]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_edit_with_ending_index_too_large_panics() {
+        let mut s = IndexablePieceTable::new("0😀2");
+
+        let result = std::panic::catch_unwind(move || s.edit(1..4, ""));
+        assert!(result.is_err());
+    }
+}
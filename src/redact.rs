@@ -0,0 +1,109 @@
+use std::ops::Range;
+
+use crate::{error::check_range, IndexMapping, IndexableStr, IndexableString, Offset};
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns a copy of the string with the `char`s in each range replaced by `mask`, along with an `IndexMapping`
+    /// between the `char` indices of the two strings.
+    ///
+    /// The ranges may be given in any order and may overlap, in which case they are merged. If `collapse` is `false`,
+    /// every masked `char` is replaced by one `mask`, so the `char` indices don't change. If it's `true`, each merged
+    /// range is replaced by a single `mask`, which hides the length of the text. Empty ranges are ignored.
+    /// # Arguments
+    ///
+    /// * `ranges` - The ranges of `char`s to mask.
+    /// * `mask` - The `char` to replace them with.
+    /// * `collapse` - Whether to replace each range with a single `mask`.
+    /// # Panics
+    /// * If a range end is greater than the number of characters in the string.
+    /// * If a range end is less than its range start.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("key=s3crét; id=7");
+    ///
+    /// assert_eq!(s.redact(&[4..10, 15..16], '*', false).0.as_str(), "key=******; id=*");
+    ///
+    /// let (redacted, mapping) = s.redact(&[4..10], '*', true);
+    ///
+    /// assert_eq!(redacted.as_str(), "key=*; id=7");
+    /// assert_eq!(mapping.to_new(10), Some(5));
+    /// ```
+    pub fn redact(&self, ranges: &[Range<usize>], mask: char, collapse: bool) -> (IndexableString, IndexMapping) {
+        for range in ranges {
+            if let Err(error) = check_range(range.start, range.end, self.chars_length) {
+                panic!("{error}");
+            }
+        }
+
+        let mut sorted_ranges: Vec<Range<usize>> = ranges.iter().filter(|range| !range.is_empty()).cloned().collect();
+        let mut merged_ranges: Vec<Range<usize>> = Vec::new();
+
+        sorted_ranges.sort_by_key(|range| range.start);
+
+        for range in sorted_ranges {
+            match merged_ranges.last_mut() {
+                Some(previous_range) if previous_range.end >= range.start => previous_range.end = previous_range.end.max(range.end),
+                _ => merged_ranges.push(range),
+            }
+        }
+
+        let masks: Vec<String> = merged_ranges
+            .iter()
+            .map(|range| match collapse {
+                true => mask.to_string(),
+                false => std::iter::repeat_n(mask, range.len()).collect(),
+            })
+            .collect();
+        let edits: Vec<(Range<usize>, &str)> = merged_ranges.into_iter().zip(masks.iter().map(String::as_str)).collect();
+
+        self.apply_edits(&edits)
+    }
+}
+
+impl IndexableString {
+    /// Returns a copy of the string with the `char`s in each range replaced by `mask`, along with an `IndexMapping`
+    /// between the `char` indices of the two strings. See `IndexableStr::redact`.
+    /// # Arguments
+    ///
+    /// * `ranges` - The ranges of `char`s to mask.
+    /// * `mask` - The `char` to replace them with.
+    /// * `collapse` - Whether to replace each range with a single `mask`.
+    /// # Panics
+    /// * If a range end is greater than the number of characters in the string.
+    /// * If a range end is less than its range start.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("pin 😀12");
+    ///
+    /// assert_eq!(s.redact(&[4..7], '#', false).0.as_str(), "pin ###");
+    /// ```
+    pub fn redact(&self, ranges: &[Range<usize>], mask: char, collapse: bool) -> (IndexableString, IndexMapping) {
+        self.as_indexable_str().redact(ranges, mask, collapse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_merges_overlapping_ranges() {
+        let s = IndexableStr::new("0😀2345678😀");
+        let (redacted, mapping) = s.redact(&[6..8, 1..3, 2..4, 5..5, 7..9], '·', true);
+
+        assert_eq!(redacted.as_str(), "0·45·😀");
+        assert_eq!(mapping.to_new(4), Some(2));
+        assert_eq!(mapping.to_new(9), Some(5));
+        assert_eq!(s.redact(&[6..8, 1..3, 2..4], '·', false).0.len(), s.len());
+
+        let result = std::panic::catch_unwind(|| s.redact(&[0..1, 9..11], '·', false));
+        assert!(result.is_err());
+
+        let result = std::panic::catch_unwind(|| s.redact(&[0..1, Range { start: 5, end: 3 }], '·', false));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,88 @@
+/// The high bit of every byte in a `u64`.
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+/// Calls `f` with the starting byte offset and value of every `char` in `str`, in order.
+///
+/// The string is scanned eight bytes at a time. A word without any high bits is pure ASCII, so its eight `char`s are
+/// emitted without decoding anything. Otherwise, the bytes that start a `char` are found for the whole word at once,
+/// by clearing the continuation bytes (`0b10xxxxxx`), and only those bytes are decoded.
+pub(crate) fn for_each_char(str: &str, mut f: impl FnMut(usize, char)) {
+    let bytes: &[u8] = str.as_bytes();
+    let mut words = bytes.chunks_exact(8);
+    let mut word_offset: usize = 0;
+
+    for chunk in words.by_ref() {
+        let word: u64 = u64::from_le_bytes(chunk.try_into().unwrap());
+
+        if word & HIGH_BITS == 0 {
+            for (i, byte) in chunk.iter().enumerate() {
+                f(word_offset + i, *byte as char);
+            }
+        } else {
+            let mut char_starts: u64 = !(word & !(word << 1)) & HIGH_BITS;
+
+            while char_starts != 0 {
+                let offset: usize = word_offset + (char_starts.trailing_zeros() / 8) as usize;
+
+                f(offset, decode_char(bytes, offset));
+                char_starts &= char_starts - 1;
+            }
+        }
+
+        word_offset += 8;
+    }
+
+    for (i, byte) in words.remainder().iter().enumerate() {
+        if byte & 0xC0 != 0x80 {
+            f(word_offset + i, decode_char(bytes, word_offset + i));
+        }
+    }
+}
+
+/// Decodes the `char` whose first byte is at `offset`, which must be a `char` boundary of valid UTF-8.
+fn decode_char(bytes: &[u8], offset: usize) -> char {
+    let lead: u32 = bytes[offset] as u32;
+    let continuation = |i: usize| (bytes[offset + i] & 0x3F) as u32;
+
+    let code_point: u32 = match bytes[offset].leading_ones() {
+        0 => lead,
+        2 => (lead & 0x1F) << 6 | continuation(1),
+        3 => (lead & 0x0F) << 12 | continuation(1) << 6 | continuation(2),
+        _ => (lead & 0x07) << 18 | continuation(1) << 12 | continuation(2) << 6 | continuation(3),
+    };
+
+    char::from_u32(code_point).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect_chars(str: &str) -> Vec<(usize, char)> {
+        let mut chars: Vec<(usize, char)> = Vec::new();
+
+        for_each_char(str, |offset, chr| chars.push((offset, chr)));
+
+        chars
+    }
+
+    #[test]
+    fn test_for_each_char_agrees_with_char_indices() {
+        let strs = ["", "0", "01234567", "0123456789abcdef012", "0😀2345678😀", "é中😀a\u{7FF}\u{800}\u{FFFF}\u{10000}\u{10FFFF}"];
+
+        for str in strs {
+            for length in 0..=str.chars().count() {
+                let prefix: String = str.chars().take(length).collect();
+
+                assert_eq!(collect_chars(&prefix), prefix.char_indices().collect::<Vec<(usize, char)>>());
+            }
+        }
+    }
+
+    #[test]
+    fn test_for_each_char_with_mixed_runs() {
+        let str: String = "abcdefgh中ijklmnop😀qrstuvwx".repeat(10);
+
+        assert_eq!(collect_chars(&str), str.char_indices().collect::<Vec<(usize, char)>>());
+    }
+}
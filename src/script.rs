@@ -0,0 +1,140 @@
+use crate::{unicode, IndexableStr, IndexableString, Offset, Span};
+
+/// `Script` is the Unicode Script property of a `char`, for the scripts that are common in text.
+///
+/// `Common` `char`s, such as spaces, digits and most punctuation, are shared by every script, and `Inherited` `char`s,
+/// such as combining marks, take the script of the `char` before them.
+///
+/// The coverage is partial: the property comes from a small table of the scripts named here, rather than the full
+/// Unicode Character Database. Alphabetic `char`s that the table doesn't list, including those of every other script,
+/// are `Unknown`, and other unlisted `char`s are `Common`. Requires the `script` feature.
+///
+/// # Examples
+/// ```
+/// use indexable_str::Script;
+///
+/// assert_eq!(Script::of('a'), Script::Latin);
+/// assert_eq!(Script::of('ж'), Script::Cyrillic);
+/// assert_eq!(Script::of('中'), Script::Han);
+/// assert_eq!(Script::of('カ'), Script::Katakana);
+/// assert_eq!(Script::of('1'), Script::Common);
+/// assert_eq!(Script::of('\u{301}'), Script::Inherited);
+/// assert_eq!(Script::of('ა'), Script::Unknown);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Script {
+    Common,
+    Inherited,
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Thai,
+    Hangul,
+    Hiragana,
+    Katakana,
+    Han,
+    Unknown,
+}
+
+impl Script {
+    /// Returns the script of `chr`.
+    /// # Arguments
+    ///
+    /// * `chr` - The `char`.
+    /// # Examples
+    /// ```
+    /// use indexable_str::Script;
+    ///
+    /// assert_eq!(Script::of('λ'), Script::Greek);
+    /// ```
+    pub fn of(chr: char) -> Script {
+        unicode::script(chr)
+    }
+
+    fn is_shared(self) -> bool {
+        matches!(self, Script::Common | Script::Inherited)
+    }
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns an iterator over the maximal runs of `char`s that are in a single script, with the `Span` of each run.
+    ///
+    /// `Common` and `Inherited` `char`s join the run they are in, so spaces and punctuation between words of one script
+    /// don't break it, and a run that starts with them takes the script of the first `char` after them that has one.
+    /// A run of only `Common` and `Inherited` `char`s has the script of its first `char`. Requires the `script`
+    /// feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Script, Span};
+    ///
+    /// let s = IndexableStr::new("(Hi) 中文。 ok");
+    /// let runs: Vec<(Script, Span)> = s.script_runs().collect();
+    ///
+    /// assert_eq!(runs, vec![(Script::Latin, Span::new(0, 5)), (Script::Han, Span::new(5, 9)), (Script::Latin, Span::new(9, 11))]);
+    /// ```
+    pub fn script_runs(&self) -> impl Iterator<Item = (Script, Span)> + 'a {
+        let mut scripts = self.str.chars().map(Script::of).enumerate().peekable();
+
+        std::iter::from_fn(move || {
+            let (start, mut script) = scripts.next()?;
+            let mut end: usize = start + 1;
+
+            while let Some(&(index, next)) = scripts.peek() {
+                match (script.is_shared(), next.is_shared()) {
+                    (_, true) => (),
+                    (true, false) => script = next,
+                    (false, false) if next == script => (),
+                    (false, false) => break,
+                }
+
+                end = index + 1;
+                scripts.next();
+            }
+
+            Some((script, Span::new(start, end)))
+        })
+    }
+}
+
+impl IndexableString {
+    /// Returns an iterator over the maximal runs of `char`s that are in a single script. See
+    /// `IndexableStr::script_runs`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableString, Script, Span};
+    ///
+    /// let s = IndexableString::from("ひらがなカタカナ");
+    ///
+    /// assert_eq!(s.script_runs().last(), Some((Script::Katakana, Span::new(4, 8))));
+    /// ```
+    pub fn script_runs(&self) -> impl Iterator<Item = (Script, Span)> + '_ {
+        self.as_indexable_str().script_runs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_runs_cover_the_string() {
+        let s = IndexableStr::new("1. Привет, мир! e\u{301}λ 안녕하세요 ...");
+        let runs: Vec<(Script, Span)> = s.script_runs().collect();
+
+        assert_eq!(
+            runs.iter().map(|&(script, _)| script).collect::<Vec<Script>>(),
+            vec![Script::Cyrillic, Script::Latin, Script::Greek, Script::Hangul]
+        );
+        assert_eq!(runs[0].1.start, 0);
+        assert_eq!(runs[3].1.end, s.len());
+        assert!(runs.windows(2).all(|pair| pair[0].1.end == pair[1].1.start));
+        assert_eq!(IndexableStr::new("").script_runs().count(), 0);
+        assert_eq!(IndexableStr::new("1 + 2").script_runs().collect::<Vec<(Script, Span)>>(), vec![(Script::Common, Span::new(0, 5))]);
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::BTreeMap;
+
+use crate::{IndexableStr, IndexableString, Offset, Span};
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns the `Span` of the first match of `needle`, where every run of whitespace in the needle matches any run
+    /// of one or more whitespace `char`s in the string, as defined by `char::is_whitespace`. Leading and trailing
+    /// whitespace in the needle is ignored, so the match starts and ends with a `char` that isn't whitespace. A
+    /// needle that's empty or only whitespace matches at the start of the string.
+    ///
+    /// Whitespace only matches whitespace, so `"a b"` doesn't match `"ab"`.
+    /// # Arguments
+    ///
+    /// * `needle` - The text to look for.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("😀 fn main() {\n\tlet x = 1;\n}");
+    /// let span: Span = s.find_ignoring_whitespace("{ let x  =  1; }").unwrap();
+    ///
+    /// assert_eq!(span, Span::new(12, 27));
+    /// assert_eq!(&s[span], "{\n\tlet x = 1;\n}");
+    /// assert_eq!(s.find_ignoring_whitespace("letx"), None);
+    /// ```
+    pub fn find_ignoring_whitespace(&self, needle: &str) -> Option<Span> {
+        let needle: Vec<Option<char>> = collapsed_whitespace(needle);
+
+        if needle.is_empty() {
+            return Some(Span::new(0, 0));
+        }
+
+        let chars: Vec<char> = self.str.chars().collect();
+
+        (0..chars.len()).find_map(|start| {
+            let mut index: usize = start;
+
+            for token in &needle {
+                match token {
+                    Some(chr) if chars.get(index) == Some(chr) => index += 1,
+                    Some(_) => return None,
+                    None => {
+                        let run_length: usize = chars[index..].iter().take_while(|chr| chr.is_whitespace()).count();
+
+                        if run_length == 0 {
+                            return None;
+                        }
+
+                        index += run_length;
+                    },
+                }
+            }
+
+            Some(Span::new(start, index))
+        })
+    }
+
+    /// Returns the `Span`s of the substrings that are within `max_edits` `char` insertions, deletions or substitutions
+    /// of `needle`, each with its edit distance, in order of position.
+    ///
+    /// Near a good match there are usually many overlapping candidates, so the candidates are chosen the way a reader
+    /// would: those with the fewest edits first, then the longest, then the first, skipping any that overlap a
+    /// candidate that was already chosen. An empty needle has no matches.
+    /// # Arguments
+    ///
+    /// * `needle` - The text to look for.
+    /// * `max_edits` - The largest edit distance to accept.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("let 😀 = lenth(vec) + length(vec);");
+    /// let matches: Vec<(Span, usize)> = s.find_fuzzy("length", 1);
+    ///
+    /// assert_eq!(matches, vec![(Span::new(8, 13), 1), (Span::new(21, 27), 0)]);
+    /// assert_eq!(&s[matches[0].0], "lenth");
+    /// ```
+    pub fn find_fuzzy(&self, needle: &str, max_edits: usize) -> Vec<(Span, usize)> {
+        let needle: Vec<char> = needle.chars().collect();
+
+        if needle.is_empty() {
+            return Vec::new();
+        }
+
+        // Each cell holds the edit distance of the best match of a prefix of the needle that ends at the current
+        // `char`, and the index where that match starts.
+        let mut previous: Vec<(usize, usize)> = (0..=needle.len()).map(|length| (length, 0)).collect();
+        let mut current: Vec<(usize, usize)> = vec![(0, 0); needle.len() + 1];
+        let mut candidates: Vec<(usize, Span)> = Vec::new();
+
+        for (index, chr) in self.str.chars().enumerate() {
+            current[0] = (0, index + 1);
+
+            for length in 1..=needle.len() {
+                let (substitution_cost, substitution_start) = previous[length - 1];
+                let (deletion_cost, deletion_start) = current[length - 1];
+                let (insertion_cost, insertion_start) = previous[length];
+
+                current[length] = [
+                    (substitution_cost + (needle[length - 1] != chr) as usize, substitution_start),
+                    (deletion_cost + 1, deletion_start),
+                    (insertion_cost + 1, insertion_start),
+                ]
+                .into_iter()
+                .min_by_key(|&(cost, start)| (cost, std::cmp::Reverse(start)))
+                .unwrap();
+            }
+
+            let (cost, start) = current[needle.len()];
+
+            if cost <= max_edits && start <= index {
+                candidates.push((cost, Span::new(start, index + 1)));
+            }
+
+            std::mem::swap(&mut previous, &mut current);
+        }
+
+        candidates.sort_by_key(|&(cost, span)| (cost, std::cmp::Reverse(span.len()), span.start));
+
+        let mut chosen: BTreeMap<usize, (Span, usize)> = BTreeMap::new();
+
+        for (cost, span) in candidates {
+            let overlaps_before: bool = chosen.range(..span.end).next_back().is_some_and(|(_, (other, _))| other.end > span.start);
+
+            if !overlaps_before {
+                chosen.insert(span.start, (span, cost));
+            }
+        }
+
+        chosen.into_values().collect()
+    }
+}
+
+impl IndexableString {
+    /// Returns the `Span` of the first match of `needle`, where every run of whitespace in the needle matches any run
+    /// of whitespace in the string. See `IndexableStr::find_ignoring_whitespace`.
+    /// # Arguments
+    ///
+    /// * `needle` - The text to look for.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableString, Span};
+    ///
+    /// let s = IndexableString::from("a  +\tb");
+    ///
+    /// assert_eq!(s.find_ignoring_whitespace("a + b"), Some(Span::new(0, 6)));
+    /// ```
+    pub fn find_ignoring_whitespace(&self, needle: &str) -> Option<Span> {
+        self.as_indexable_str().find_ignoring_whitespace(needle)
+    }
+
+    /// Returns the `Span`s of the substrings that are within `max_edits` edits of `needle`, each with its edit
+    /// distance. See `IndexableStr::find_fuzzy`.
+    /// # Arguments
+    ///
+    /// * `needle` - The text to look for.
+    /// * `max_edits` - The largest edit distance to accept.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableString, Span};
+    ///
+    /// let s = IndexableString::from("colour color");
+    ///
+    /// assert_eq!(s.find_fuzzy("color", 1), vec![(Span::new(0, 6), 1), (Span::new(7, 12), 0)]);
+    /// ```
+    pub fn find_fuzzy(&self, needle: &str, max_edits: usize) -> Vec<(Span, usize)> {
+        self.as_indexable_str().find_fuzzy(needle, max_edits)
+    }
+}
+
+/// Returns the `char`s of `str` without leading and trailing whitespace, with each run of whitespace inside it
+/// replaced by `None`.
+fn collapsed_whitespace(str: &str) -> Vec<Option<char>> {
+    let mut tokens: Vec<Option<char>> = Vec::new();
+
+    for chr in str.trim().chars() {
+        match chr.is_whitespace() {
+            true if tokens.last() == Some(&None) => (),
+            true => tokens.push(None),
+            false => tokens.push(Some(chr)),
+        }
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_ignoring_whitespace_agrees_with_find_for_plain_needles() {
+        let s = IndexableStr::new("0😀2 3\u{3000}45 😀 6");
+
+        for needle in ["😀", "2 3", "5 😀", "6", "7"] {
+            let expected: Option<Span> = s.as_str().find(needle).map(|byte_offset| {
+                let start: usize = s.as_str()[..byte_offset].chars().count();
+
+                Span::new(start, start + needle.chars().count())
+            });
+
+            assert_eq!(s.find_ignoring_whitespace(needle), expected, "{needle}");
+        }
+
+        assert_eq!(s.find_ignoring_whitespace(" 3   45 "), Some(Span::new(4, 8)));
+        assert_eq!(s.find_ignoring_whitespace(" \n"), Some(Span::new(0, 0)));
+        assert_eq!(IndexableStr::new("").find_ignoring_whitespace("a"), None);
+    }
+
+    #[test]
+    fn test_find_fuzzy_distances_agree_with_edit_distance() {
+        let s = IndexableStr::new("xx😀bcd yy abd zz a😀cd abcd");
+
+        for max_edits in 0..3 {
+            let matches: Vec<(Span, usize)> = s.find_fuzzy("a😀cd", max_edits);
+
+            assert!(matches.windows(2).all(|pair| pair[0].0.end <= pair[1].0.start));
+
+            for (span, edits) in matches {
+                assert!(edits <= max_edits);
+                assert_eq!(s.slice(span.into()).as_str().chars().count(), span.len());
+                assert_eq!(IndexableStr::new(&s[span]).edit_distance(&IndexableStr::new("a😀cd")), edits);
+            }
+        }
+
+        assert_eq!(s.find_fuzzy("a😀cd", 0), vec![(Span::new(17, 21), 0)]);
+        assert_eq!(s.find_fuzzy("", 3), Vec::new());
+    }
+}
@@ -0,0 +1,253 @@
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    ops::{Index, Range, RangeFrom, RangeTo},
+    sync::Arc,
+};
+
+use crate::{
+    char_table::CharTable,
+    error::check_index,
+    grapheme::{self, GraphemeCache},
+    IndexableStr, IndexableString, Span,
+};
+
+/// `SharedIndexableStr` is an owned indexable string whose text and index are both reference counted, so cloning it
+/// only bumps two pointers. It can be sent to and shared between threads, each of which can index the same text
+/// without indexing it again.
+///
+/// # Examples
+/// ```
+/// use indexable_str::SharedIndexableStr;
+///
+/// let s = SharedIndexableStr::from("0😀2345678😀");
+/// let worker_copy = s.clone();
+///
+/// let handle = std::thread::spawn(move || worker_copy[1]);
+///
+/// assert_eq!(handle.join().unwrap(), '😀');
+/// assert_eq!(&s[1..9], "😀2345678");
+/// ```
+#[derive(Clone)]
+pub struct SharedIndexableStr {
+    str: Arc<str>,
+    char_table: Arc<CharTable>,
+    chars_length: usize,
+    graphemes: Arc<GraphemeCache>,
+}
+
+impl SharedIndexableStr {
+    /// Returns a shared indexable string holding a copy of the specified string slice.
+    /// # Arguments
+    ///
+    /// * `str` - A string slice to be indexed.
+    /// # Examples
+    /// ```
+    /// use indexable_str::SharedIndexableStr;
+    ///
+    /// let s = SharedIndexableStr::new("0😀2345678😀");
+    /// ```
+    pub fn new(str: &str) -> SharedIndexableStr {
+        let char_table: CharTable = CharTable::new(str);
+
+        SharedIndexableStr::from_parts(Arc::from(str), char_table)
+    }
+
+    pub(crate) fn char_table(&self) -> &CharTable {
+        &self.char_table
+    }
+
+    fn from_parts(str: Arc<str>, char_table: CharTable) -> SharedIndexableStr {
+        let chars_length: usize = char_table.len(&str);
+
+        SharedIndexableStr {
+            str,
+            char_table: Arc::new(char_table),
+            chars_length,
+            graphemes: Arc::new(GraphemeCache::new()),
+        }
+    }
+
+    /// Returns the string as a string slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::SharedIndexableStr;
+    ///
+    /// let s = SharedIndexableStr::new("0😀2345678😀");
+    ///
+    /// assert_eq!(s.as_str(), "0😀2345678😀");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.str
+    }
+
+    /// Returns an `IndexableStr` that borrows the string and its index, without re-indexing.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::SharedIndexableStr;
+    ///
+    /// let s = SharedIndexableStr::new("0😀2345678😀");
+    ///
+    /// assert_eq!(&s.as_indexable_str()[1..], "😀2345678😀");
+    /// ```
+    pub fn as_indexable_str(&self) -> IndexableStr<'_> {
+        IndexableStr {
+            str: &self.str,
+            str_length: self.str.len(),
+            char_table: Cow::Borrowed(&self.char_table),
+            chars_length: self.chars_length,
+            graphemes: Cow::Borrowed(&self.graphemes),
+        }
+    }
+
+    /// Returns a `usize` for the number of `char`s in the string.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::SharedIndexableStr;
+    ///
+    /// let s = SharedIndexableStr::new("0😀2345678😀");
+    ///
+    /// assert_eq!(s.len(), 10);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.chars_length
+    }
+
+    /// Returns `true` if the string contains no `char`s.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::SharedIndexableStr;
+    ///
+    /// assert!(SharedIndexableStr::new("").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.chars_length == 0
+    }
+
+    /// Returns the number of bytes allocated on the heap for the index, which is shared by every clone. The string
+    /// itself isn't included.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::SharedIndexableStr;
+    ///
+    /// assert_eq!(SharedIndexableStr::new("0123456789").memory_usage(), 0);
+    /// assert!(SharedIndexableStr::new("0😀2345678😀").memory_usage() >= 80);
+    /// ```
+    pub fn memory_usage(&self) -> usize {
+        self.char_table.memory_usage() + grapheme::memory_usage(&self.graphemes)
+    }
+}
+
+impl From<&str> for SharedIndexableStr {
+    fn from(str: &str) -> Self {
+        SharedIndexableStr::new(str)
+    }
+}
+
+impl From<String> for SharedIndexableStr {
+    fn from(string: String) -> Self {
+        let char_table: CharTable = CharTable::new(&string);
+
+        SharedIndexableStr::from_parts(Arc::from(string), char_table)
+    }
+}
+
+/// Moves the index of an `IndexableString` into a `SharedIndexableStr`, without re-indexing.
+impl From<IndexableString> for SharedIndexableStr {
+    fn from(string: IndexableString) -> Self {
+        let (string, char_table) = string.into_parts();
+
+        SharedIndexableStr::from_parts(Arc::from(string), char_table)
+    }
+}
+
+impl Display for SharedIndexableStr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.str)
+    }
+}
+
+/// # Panics
+/// * If the index is greater than or equal to the number of characters in the string.
+impl Index<usize> for SharedIndexableStr {
+    type Output = char;
+
+    fn index(&self, index: usize) -> &char {
+        if let Err(error) = check_index(index, self.chars_length) {
+            panic!("{error}");
+        }
+
+        self.char_table.char_ref(&self.str, index)
+    }
+}
+
+/// # Panics
+/// * If the range end is greater than the number of characters in the string.
+/// * If the range end is less than the range start.
+impl Index<Range<usize>> for SharedIndexableStr {
+    type Output = str;
+
+    fn index(&self, range: Range<usize>) -> &Self::Output {
+        self.as_indexable_str().create_str_from_range(range.start, range.end)
+    }
+}
+
+/// # Panics
+/// * If the range end is less than the range start.
+impl Index<RangeFrom<usize>> for SharedIndexableStr {
+    type Output = str;
+
+    fn index(&self, index: RangeFrom<usize>) -> &Self::Output {
+        self.as_indexable_str().create_str_from_range(index.start, self.chars_length)
+    }
+}
+
+/// # Panics
+/// * If the range end is greater than the number of characters in the string.
+impl Index<RangeTo<usize>> for SharedIndexableStr {
+    type Output = str;
+
+    fn index(&self, index: RangeTo<usize>) -> &Self::Output {
+        self.as_indexable_str().create_str_from_range(0, index.end)
+    }
+}
+
+/// # Panics
+/// * If the span end is greater than the number of characters in the string.
+/// * If the span end is less than the span start.
+impl Index<Span> for SharedIndexableStr {
+    type Output = str;
+
+    fn index(&self, span: Span) -> &Self::Output {
+        self.as_indexable_str().create_str_from_range(span.start, span.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clone_shares_the_string_and_index() {
+        let s = SharedIndexableStr::new("0😀23456789");
+        let clone = s.clone();
+
+        assert!(Arc::ptr_eq(&s.str, &clone.str));
+        assert!(Arc::ptr_eq(&s.char_table, &clone.char_table));
+    }
+
+    #[test]
+    fn test_from_indexable_string_works() {
+        let s = SharedIndexableStr::from(IndexableString::from("0😀23456789"));
+
+        assert_eq!(s.len(), 10);
+        assert_eq!(s[1], '😀');
+        assert_eq!(&s[1..9], "😀2345678");
+        assert_eq!(&s[..9], "0😀2345678");
+    }
+}
@@ -0,0 +1,1043 @@
+use std::{
+    borrow::Cow,
+    convert::Infallible,
+    fmt::Display,
+    io::{self, Read},
+    ops::{Add, AddAssign, Index, Range, RangeFrom, RangeTo},
+    str::{Bytes, Chars, FromStr},
+    string::FromUtf8Error,
+};
+
+use crate::{
+    anchor::Anchors,
+    char_table::{CharTable, CharTableBuilder},
+    error::check_index,
+    grapheme::{self, GraphemeCache},
+    history::History,
+    IndexError, IndexableStr, IndexableStringBuilder, Span,
+};
+
+/// `IndexableString` is the owned counterpart of `IndexableStr`. It owns its `String`, so it can be stored and
+/// passed around without borrowing the text from somewhere else.
+///
+/// # Examples
+/// ```
+/// use indexable_str::IndexableString;
+///
+/// let s = IndexableString::from("0😀2345678😀");
+///
+/// assert_eq!(s[1], '😀');
+/// assert_eq!(&s[1..9], "😀2345678");
+/// ```
+#[derive(Clone)]
+pub struct IndexableString {
+    string: String,
+    char_table: CharTable,
+    chars_length: usize,
+    anchors: Anchors,
+    history: Option<Box<History>>,
+    graphemes: GraphemeCache,
+}
+
+impl IndexableString {
+    /// Returns an indexable string that owns the specified `String`.
+    /// # Arguments
+    ///
+    /// * `string` - A `String` to be indexed.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::new(String::from("0😀2345678😀"));
+    /// ```
+    pub fn new(string: String) -> IndexableString {
+        let char_table: CharTable = CharTable::new(&string);
+
+        IndexableString::from_parts(string, char_table)
+    }
+
+    /// Returns an indexable string that owns the specified `String` after removing the UTF-8 byte order mark
+    /// (`U+FEFF`) from its start, along with whether there was one.
+    /// # Arguments
+    ///
+    /// * `string` - A `String` to be indexed.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let (s, had_bom) = IndexableString::new_strip_bom(String::from("\u{FEFF}😀1"));
+    ///
+    /// assert!(had_bom);
+    /// assert_eq!(s.as_str(), "😀1");
+    /// ```
+    pub fn new_strip_bom(mut string: String) -> (IndexableString, bool) {
+        let had_bom: bool = string.starts_with(crate::BYTE_ORDER_MARK);
+
+        if had_bom {
+            string.drain(..crate::BYTE_ORDER_MARK.len_utf8());
+        }
+
+        (IndexableString::new(string), had_bom)
+    }
+
+    /// Returns an indexable string that owns `bytes`, if they're valid UTF-8. Like `String::from_utf8`, the bytes
+    /// aren't copied.
+    /// # Arguments
+    ///
+    /// * `bytes` - The UTF-8 text.
+    /// # Errors
+    /// * If the bytes aren't valid UTF-8. The error's `utf8_error().valid_up_to()` is the byte offset of the fault,
+    ///   and `into_bytes` returns the bytes. Unlike the other constructors, this doesn't return an `IndexError`,
+    ///   because an `IndexError` is `Copy` and can't give the bytes back; it converts into
+    ///   `IndexError::InvalidUtf8` with `into` or `?`.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexError, IndexableString};
+    ///
+    /// let s = IndexableString::from_utf8("0😀2".as_bytes().to_vec()).unwrap();
+    ///
+    /// assert_eq!(s[1], '😀');
+    ///
+    /// let error = IndexableString::from_utf8(vec![b'0', 0xF0, 0x9F, b'2']).err().unwrap();
+    ///
+    /// assert_eq!(error.utf8_error().valid_up_to(), 1);
+    /// assert_eq!(IndexError::from(error), IndexError::InvalidUtf8 { byte_offset: 1 });
+    /// ```
+    pub fn from_utf8(bytes: Vec<u8>) -> Result<IndexableString, FromUtf8Error> {
+        String::from_utf8(bytes).map(IndexableString::new)
+    }
+
+    /// Returns an indexable string holding a copy of `bytes`, with each invalid UTF-8 sequence replaced by
+    /// `U+FFFD REPLACEMENT CHARACTER`, like `String::from_utf8_lossy`.
+    /// # Arguments
+    ///
+    /// * `bytes` - The text, which is expected to be UTF-8.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from_utf8_lossy(&[b'0', 0xF0, 0x9F, b'2']);
+    ///
+    /// assert_eq!(s.as_str(), "0\u{FFFD}2");
+    /// assert_eq!(s[2], '2');
+    /// ```
+    pub fn from_utf8_lossy(bytes: &[u8]) -> IndexableString {
+        IndexableString::new(String::from_utf8_lossy(bytes).into_owned())
+    }
+
+    /// Returns an indexable string decoded from UTF-16, which is indexed as it's decoded.
+    /// # Arguments
+    ///
+    /// * `units` - The UTF-16 code units.
+    /// # Errors
+    /// * If the code units contain an unpaired surrogate, `IndexError::InvalidUtf16` with the offset of the first one.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexError, IndexableString};
+    ///
+    /// let units: Vec<u16> = "0😀2".encode_utf16().collect();
+    /// let s = IndexableString::from_utf16(&units).unwrap();
+    ///
+    /// assert_eq!(s[1], '😀');
+    /// assert_eq!(IndexableString::from_utf16(&[0x30, 0xD83D]).err(), Some(IndexError::InvalidUtf16 { unit_offset: 1 }));
+    /// ```
+    pub fn from_utf16(units: &[u16]) -> Result<IndexableString, IndexError> {
+        decode_utf16(units, false, None)
+    }
+
+    /// Returns an indexable string decoded from UTF-16, with each unpaired surrogate replaced by
+    /// `U+FFFD REPLACEMENT CHARACTER`.
+    /// # Arguments
+    ///
+    /// * `units` - The UTF-16 code units.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from_utf16_lossy(&[0x30, 0xD83D, 0x32]);
+    ///
+    /// assert_eq!(s.as_str(), "0\u{FFFD}2");
+    /// ```
+    pub fn from_utf16_lossy(units: &[u16]) -> IndexableString {
+        decode_utf16(units, true, None).unwrap()
+    }
+
+    /// Returns an indexable string decoded from UTF-16, along with the offset of each `char` in `units`, so that
+    /// `char` indices can be converted back to UTF-16 offsets. The last offset is the number of code units.
+    /// # Arguments
+    ///
+    /// * `units` - The UTF-16 code units.
+    /// # Errors
+    /// * If the code units contain an unpaired surrogate, `IndexError::InvalidUtf16` with the offset of the first one.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let units: Vec<u16> = "0😀2".encode_utf16().collect();
+    /// let (s, offsets) = IndexableString::from_utf16_with_offsets(&units).unwrap();
+    ///
+    /// assert_eq!(s[2], '2');
+    /// assert_eq!(offsets, vec![0, 1, 3, 4]);
+    /// ```
+    pub fn from_utf16_with_offsets(units: &[u16]) -> Result<(IndexableString, Vec<usize>), IndexError> {
+        let mut offsets: Vec<usize> = Vec::new();
+        let string: IndexableString = decode_utf16(units, false, Some(&mut offsets))?;
+
+        Ok((string, offsets))
+    }
+
+    /// Returns an indexable string read from `reader`.
+    ///
+    /// The UTF-8 is validated and indexed a buffer at a time as it's read, including `char`s that are split across
+    /// buffers, so the whole text never has to be held as bytes first.
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the UTF-8 text.
+    /// # Errors
+    /// * If reading fails.
+    /// * If the text isn't valid UTF-8, with an error of kind `InvalidData`.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from_reader("0😀2345678😀".as_bytes()).unwrap();
+    ///
+    /// assert_eq!(s[9], '😀');
+    /// ```
+    pub fn from_reader(mut reader: impl Read) -> io::Result<IndexableString> {
+        let mut string: String = String::new();
+        let mut builder: CharTableBuilder = CharTableBuilder::new();
+        let mut buffer: Vec<u8> = vec![0; 8 * 1024];
+        let mut pending: Vec<u8> = Vec::new();
+
+        loop {
+            let bytes_read: usize = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(bytes_read) => bytes_read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+
+            pending.extend_from_slice(&buffer[..bytes_read]);
+
+            let valid_length: usize = match std::str::from_utf8(&pending) {
+                Ok(str) => {
+                    string.push_str(str);
+                    pending.len()
+                },
+                Err(error) if error.error_len().is_none() => {
+                    string.push_str(std::str::from_utf8(&pending[..error.valid_up_to()]).unwrap());
+                    error.valid_up_to()
+                },
+                Err(error) => return Err(invalid_utf8(string.len() + error.valid_up_to())),
+            };
+
+            builder.extend(&string);
+            pending.drain(..valid_length);
+        }
+
+        if !pending.is_empty() {
+            return Err(invalid_utf8(string.len()));
+        }
+
+        Ok(IndexableString::from_parts(string, builder.finish()))
+    }
+
+    /// Returns the concatenation of several indexable strings.
+    ///
+    /// The index of each string is reused, with its byte offsets shifted by the length of the text before it, so
+    /// none of the `char`s are decoded again.
+    /// # Arguments
+    ///
+    /// * `strs` - The strings to concatenate, in order.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, IndexableString};
+    ///
+    /// let strs = [IndexableStr::new("0😀"), IndexableStr::new("234"), IndexableStr::new("é")];
+    /// let s = IndexableString::concat(&strs);
+    ///
+    /// assert_eq!(s.as_str(), "0😀234é");
+    /// assert_eq!(s[5], 'é');
+    /// ```
+    pub fn concat(strs: &[IndexableStr<'_>]) -> IndexableString {
+        IndexableString::join(strs, "")
+    }
+
+    /// Returns the concatenation of several indexable strings, with `separator` between each of them.
+    ///
+    /// The index of each string is reused, with its byte offsets shifted by the length of the text before it, and the
+    /// separator is only indexed once.
+    /// # Arguments
+    ///
+    /// * `strs` - The strings to join, in order.
+    /// * `separator` - The text placed between each pair of strings.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, IndexableString};
+    ///
+    /// let strs = [IndexableStr::new("0😀"), IndexableStr::new("234"), IndexableStr::new("é")];
+    /// let s = IndexableString::join(&strs, "😀, ");
+    ///
+    /// assert_eq!(s.as_str(), "0😀😀, 234😀, é");
+    /// assert_eq!(s[2], '😀');
+    /// ```
+    pub fn join(strs: &[IndexableStr<'_>], separator: &str) -> IndexableString {
+        let separator_table: CharTable = CharTable::new(separator);
+        let mut pieces: Vec<(&str, &CharTable)> = Vec::with_capacity(strs.len() * 2);
+
+        for (i, str) in strs.iter().enumerate() {
+            if i > 0 && !separator.is_empty() {
+                pieces.push((separator, &separator_table));
+            }
+
+            pieces.push((str.as_str(), &str.char_table));
+        }
+
+        let string: String = pieces.iter().map(|(str, _)| *str).collect();
+        let char_table: CharTable = CharTable::concat(&pieces);
+
+        IndexableString::from_parts(string, char_table)
+    }
+
+    pub(crate) fn char_table(&self) -> &CharTable {
+        &self.char_table
+    }
+
+    pub(crate) fn into_parts(self) -> (String, CharTable) {
+        (self.string, self.char_table)
+    }
+
+    pub(crate) fn from_parts(string: String, char_table: CharTable) -> IndexableString {
+        let chars_length: usize = char_table.len(&string);
+
+        IndexableString {
+            string,
+            char_table,
+            chars_length,
+            anchors: Anchors::default(),
+            history: None,
+            graphemes: GraphemeCache::new(),
+        }
+    }
+
+    pub(crate) fn graphemes(&self) -> &GraphemeCache {
+        &self.graphemes
+    }
+
+    pub(crate) fn anchors(&self) -> &Anchors {
+        &self.anchors
+    }
+
+    pub(crate) fn history_mut(&mut self) -> &mut Option<Box<History>> {
+        &mut self.history
+    }
+
+    pub(crate) fn anchors_mut(&mut self) -> &mut Anchors {
+        &mut self.anchors
+    }
+
+    /// Returns the string as a string slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("0😀2345678😀");
+    ///
+    /// assert_eq!(s.as_str(), "0😀2345678😀");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    /// Returns an `IndexableStr` that borrows the string and its index, without re-indexing.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("0😀2345678😀");
+    ///
+    /// assert_eq!(&s.as_indexable_str()[1..], "😀2345678😀");
+    /// ```
+    pub fn as_indexable_str(&self) -> IndexableStr<'_> {
+        IndexableStr {
+            str: &self.string,
+            str_length: self.string.len(),
+            char_table: Cow::Borrowed(&self.char_table),
+            chars_length: self.chars_length,
+            graphemes: Cow::Borrowed(&self.graphemes),
+        }
+    }
+
+    /// Returns the underlying `String`, discarding the index.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("0😀2345678😀");
+    ///
+    /// assert_eq!(s.into_string(), "0😀2345678😀");
+    /// ```
+    pub fn into_string(self) -> String {
+        self.string
+    }
+
+    /// Returns a `usize` for the number of `char`s in the string.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("0😀2345678😀");
+    ///
+    /// assert_eq!(s.len(), 10);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.chars_length
+    }
+
+    /// Replaces a range of `char`s with `replacement`.
+    ///
+    /// Only the replacement is indexed. The index entries after it are shifted by the change in length, instead of
+    /// indexing the whole string again. Any `Anchor`s are moved to stay with the text around them.
+    /// # Arguments
+    ///
+    /// * `range` - The range of `char`s to replace.
+    /// * `replacement` - The text to replace them with.
+    /// # Panics
+    /// * If the range end is greater than the number of characters in the string.
+    /// * If the range end is less than the range start.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let mut s = IndexableString::from("0😀2345678😀");
+    ///
+    /// s.edit(2..8, "é");
+    ///
+    /// assert_eq!(s.as_str(), "0😀é8😀");
+    /// assert_eq!(s[2], 'é');
+    /// assert_eq!(s.len(), 5);
+    /// ```
+    pub fn edit(&mut self, range: Range<usize>, replacement: &str) {
+        if self.history.is_some() {
+            let removed: String = self.as_indexable_str().create_str_from_range(range.start, range.end).to_string();
+
+            if let Some(history) = self.history.as_deref_mut() {
+                history.record(range.start, &removed, replacement);
+            }
+        }
+
+        self.splice(range, replacement);
+    }
+
+    /// Replaces the `char`s in `range` without recording the change in the history.
+    pub(crate) fn splice(&mut self, range: Range<usize>, replacement: &str) {
+        let old_byte_length: usize = self.as_indexable_str().create_str_from_range(range.start, range.end).len();
+        let bytes_start: usize = self.as_indexable_str().byte_offset(range.start);
+
+        self.string.replace_range(bytes_start..bytes_start + old_byte_length, replacement);
+        self.char_table.splice(&self.string, range.clone(), bytes_start, old_byte_length, replacement.len());
+        self.chars_length = self.char_table.len(&self.string);
+        self.anchors.adjust(range, replacement.chars().count());
+        self.graphemes = GraphemeCache::new();
+    }
+
+    /// Returns the number of bytes allocated on the heap for the index. The capacity of the string itself isn't
+    /// included.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("0123456789").memory_usage(), 0);
+    /// assert!(IndexableString::from("0😀2345678😀").memory_usage() >= 80);
+    /// ```
+    pub fn memory_usage(&self) -> usize {
+        self.char_table.memory_usage() + grapheme::memory_usage(&self.graphemes)
+    }
+
+    /// Shrinks the allocations of the string and its index as much as possible.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let mut s = IndexableString::from_reader("0😀2345678😀".as_bytes()).unwrap();
+    ///
+    /// s.shrink_to_fit();
+    ///
+    /// assert_eq!(s.memory_usage(), 80);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.string.shrink_to_fit();
+        self.char_table.shrink_to_fit();
+    }
+
+    /// Returns `true` if the string contains no `char`s.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert!(IndexableString::from("").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.chars_length == 0
+    }
+
+    /// Returns the first `char`, or `None` if the string is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("😀12").first(), Some('😀'));
+    /// ```
+    pub fn first(&self) -> Option<char> {
+        self.as_indexable_str().first()
+    }
+
+    /// Returns the last `char`, or `None` if the string is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("line\n").last(), Some('\n'));
+    /// ```
+    pub fn last(&self) -> Option<char> {
+        self.as_indexable_str().last()
+    }
+
+    /// Returns the `char` at `index`, or `None` if the index is greater than or equal to the number of `char`s.
+    /// # Arguments
+    ///
+    /// * `index` - The `char` index.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("0😀2").nth(2), Some('2'));
+    /// ```
+    pub fn nth(&self, index: usize) -> Option<char> {
+        self.as_indexable_str().nth(index)
+    }
+
+    /// Returns the result of `predicate` for the `char` at `index`, or `None` if the index is out of bounds. See
+    /// `IndexableStr::test_at`.
+    /// # Arguments
+    ///
+    /// * `index` - The `char` index.
+    /// * `predicate` - The test for the `char`.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("x😀1").test_at(1, |chr| chr == '😀'), Some(true));
+    /// ```
+    pub fn test_at(&self, index: usize, predicate: impl FnOnce(char) -> bool) -> Option<bool> {
+        self.as_indexable_str().test_at(index, predicate)
+    }
+
+    /// Returns whether the `char` at `index` is whitespace, or `None` if the index is out of bounds.
+    /// # Arguments
+    ///
+    /// * `index` - The `char` index.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("a ").is_whitespace_at(1), Some(true));
+    /// ```
+    pub fn is_whitespace_at(&self, index: usize) -> Option<bool> {
+        self.as_indexable_str().is_whitespace_at(index)
+    }
+
+    /// Returns whether the `char` at `index` is alphanumeric, or `None` if the index is out of bounds.
+    /// # Arguments
+    ///
+    /// * `index` - The `char` index.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("a ").is_alphanumeric_at(0), Some(true));
+    /// ```
+    pub fn is_alphanumeric_at(&self, index: usize) -> Option<bool> {
+        self.as_indexable_str().is_alphanumeric_at(index)
+    }
+
+    /// Returns an iterator over the `char`s that starts at `index`. See `IndexableStr::iter_from`.
+    /// # Arguments
+    ///
+    /// * `index` - The index of the first `char` to return. The number of `char`s gives an empty iterator.
+    /// # Panics
+    /// * If `index` is greater than the number of characters in the string.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("0😀2").iter_from(1).collect::<String>(), "😀2");
+    /// ```
+    pub fn iter_from(&self, index: usize) -> Chars<'_> {
+        self.as_indexable_str().iter_from(index)
+    }
+
+    /// Returns an iterator over the UTF-8 bytes of the string.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("0é").bytes().count(), 3);
+    /// ```
+    pub fn bytes(&self) -> Bytes<'_> {
+        self.string.bytes()
+    }
+
+    /// Returns the byte at `byte_offset`, or `None` if the byte offset is greater than or equal to the number of
+    /// bytes in the string.
+    /// # Arguments
+    ///
+    /// * `byte_offset` - The byte offset.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("0é").byte_at(2), Some(0xA9));
+    /// ```
+    pub fn byte_at(&self, byte_offset: usize) -> Option<u8> {
+        self.string.as_bytes().get(byte_offset).copied()
+    }
+
+    /// Returns `true` if `byte_offset` is the start of a `char` or the end of the string.
+    /// # Arguments
+    ///
+    /// * `byte_offset` - The byte offset.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert!(!IndexableString::from("0é").is_char_boundary(2));
+    /// ```
+    pub fn is_char_boundary(&self, byte_offset: usize) -> bool {
+        self.string.is_char_boundary(byte_offset)
+    }
+
+    /// Returns the index of the `char` that contains `byte_offset`. See `IndexableStr::floor_char_index`.
+    /// # Arguments
+    ///
+    /// * `byte_offset` - Any byte offset.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("0😀2").floor_char_index(4), 1);
+    /// ```
+    pub fn floor_char_index(&self, byte_offset: usize) -> usize {
+        self.as_indexable_str().floor_char_index(byte_offset)
+    }
+
+    /// Returns the index of the first `char` that starts at or after `byte_offset`. See
+    /// `IndexableStr::ceil_char_index`.
+    /// # Arguments
+    ///
+    /// * `byte_offset` - Any byte offset.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("0😀2").ceil_char_index(4), 2);
+    /// ```
+    pub fn ceil_char_index(&self, byte_offset: usize) -> usize {
+        self.as_indexable_str().ceil_char_index(byte_offset)
+    }
+}
+
+/// Decodes and indexes UTF-16 in one pass. If `offsets` is given, the offset of each `char` in `units` is pushed to
+/// it, followed by the number of units.
+/// # Errors
+/// * If the code units contain an unpaired surrogate and `replace` is `false`, `IndexError::InvalidUtf16` with the
+///   offset of the first one. If `replace` is `true`, each one is decoded as `char::REPLACEMENT_CHARACTER` instead.
+fn decode_utf16(units: &[u16], replace: bool, mut offsets: Option<&mut Vec<usize>>) -> Result<IndexableString, IndexError> {
+    let mut builder: IndexableStringBuilder = IndexableStringBuilder::with_capacity(units.len());
+    let mut offset: usize = 0;
+
+    for result in char::decode_utf16(units.iter().copied()) {
+        let (chr, length) = match result {
+            Ok(chr) => (chr, chr.len_utf16()),
+            Err(_) if replace => (char::REPLACEMENT_CHARACTER, 1),
+            Err(_) => return Err(IndexError::InvalidUtf16 { unit_offset: offset }),
+        };
+
+        if let Some(offsets) = offsets.as_mut() {
+            offsets.push(offset);
+        }
+
+        builder.push_char(chr);
+        offset += length;
+    }
+
+    if let Some(offsets) = offsets {
+        offsets.push(offset);
+    }
+
+    Ok(builder.finish())
+}
+
+fn invalid_utf8(byte_offset: usize) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, IndexError::InvalidUtf8 { byte_offset })
+}
+
+impl From<String> for IndexableString {
+    fn from(string: String) -> Self {
+        IndexableString::new(string)
+    }
+}
+
+impl From<&str> for IndexableString {
+    fn from(str: &str) -> Self {
+        IndexableString::new(str.to_string())
+    }
+}
+
+/// Collects `char`s into an indexable string, indexing each one as it's appended.
+///
+/// # Examples
+/// ```
+/// use indexable_str::IndexableString;
+///
+/// let s: IndexableString = "0 😀 2".chars().filter(|chr| !chr.is_whitespace()).collect();
+///
+/// assert_eq!(s.as_str(), "0😀2");
+/// assert_eq!(s[2], '2');
+/// ```
+impl FromIterator<char> for IndexableString {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut builder: IndexableStringBuilder = IndexableStringBuilder::new();
+
+        for chr in iter {
+            builder.push_char(chr);
+        }
+
+        builder.finish()
+    }
+}
+
+/// Appends `char`s to the end of the string, as one `edit` that only indexes the new text.
+///
+/// # Examples
+/// ```
+/// use indexable_str::IndexableString;
+///
+/// let mut s = IndexableString::from("0😀");
+///
+/// s.extend(['2', '😀']);
+///
+/// assert_eq!(s.as_str(), "0😀2😀");
+/// assert_eq!(s[3], '😀');
+/// ```
+impl Extend<char> for IndexableString {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        let appended: String = iter.into_iter().collect();
+
+        *self += appended.as_str();
+    }
+}
+
+/// Parses a string by indexing a copy of it, which can't fail.
+///
+/// # Examples
+/// ```
+/// use indexable_str::IndexableString;
+///
+/// let s: IndexableString = "0😀2".parse().unwrap();
+///
+/// assert_eq!(s[1], '😀');
+/// ```
+impl FromStr for IndexableString {
+    type Err = Infallible;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        Ok(IndexableString::from(str))
+    }
+}
+
+/// Appends a string slice, indexing only the appended text.
+///
+/// # Examples
+/// ```
+/// use indexable_str::IndexableString;
+///
+/// let mut s = IndexableString::from("0😀") + "2";
+///
+/// s += "😀";
+/// s += &IndexableString::from("4");
+///
+/// assert_eq!(s.as_str(), "0😀2😀4");
+/// assert_eq!(s[3], '😀');
+/// ```
+impl Add<&str> for IndexableString {
+    type Output = IndexableString;
+
+    fn add(mut self, str: &str) -> Self::Output {
+        self += str;
+        self
+    }
+}
+
+impl Add<&IndexableString> for IndexableString {
+    type Output = IndexableString;
+
+    fn add(mut self, other: &IndexableString) -> Self::Output {
+        self += other.as_str();
+        self
+    }
+}
+
+impl AddAssign<&str> for IndexableString {
+    fn add_assign(&mut self, str: &str) {
+        let len: usize = self.chars_length;
+
+        self.edit(len..len, str);
+    }
+}
+
+impl AddAssign<&IndexableString> for IndexableString {
+    fn add_assign(&mut self, other: &IndexableString) {
+        *self += other.as_str();
+    }
+}
+
+impl Display for IndexableString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.string)
+    }
+}
+
+/// # Panics
+/// * If the index is greater than or equal to the number of characters in the string.
+impl Index<usize> for IndexableString {
+    type Output = char;
+
+    fn index(&self, index: usize) -> &char {
+        if let Err(error) = check_index(index, self.chars_length) {
+            panic!("{error}");
+        }
+
+        self.char_table.char_ref(&self.string, index)
+    }
+}
+
+/// # Panics
+/// * If the range end is greater than the number of characters in the string.
+/// * If the range end is less than the range start.
+impl Index<Range<usize>> for IndexableString {
+    type Output = str;
+
+    fn index(&self, range: Range<usize>) -> &Self::Output {
+        self.as_indexable_str().create_str_from_range(range.start, range.end)
+    }
+}
+
+/// # Panics
+/// * If the range end is less than the range start.
+impl Index<RangeFrom<usize>> for IndexableString {
+    type Output = str;
+
+    fn index(&self, index: RangeFrom<usize>) -> &Self::Output {
+        self.as_indexable_str().create_str_from_range(index.start, self.chars_length)
+    }
+}
+
+/// # Panics
+/// * If the range end is greater than the number of characters in the string.
+impl Index<RangeTo<usize>> for IndexableString {
+    type Output = str;
+
+    fn index(&self, index: RangeTo<usize>) -> &Self::Output {
+        self.as_indexable_str().create_str_from_range(0, index.end)
+    }
+}
+
+/// # Panics
+/// * If the span end is greater than the number of characters in the string.
+/// * If the span end is less than the span start.
+impl Index<Span> for IndexableString {
+    type Output = str;
+
+    fn index(&self, span: Span) -> &Self::Output {
+        self.as_indexable_str().create_str_from_range(span.start, span.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A reader that hands out at most `chunk_length` bytes per read, to split `char`s across reads.
+    struct ChunkedReader<'a> {
+        bytes: &'a [u8],
+        chunk_length: usize,
+    }
+
+    impl<'a> Read for ChunkedReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let length: usize = self.chunk_length.min(buf.len()).min(self.bytes.len());
+
+            buf[..length].copy_from_slice(&self.bytes[..length]);
+            self.bytes = &self.bytes[length..];
+
+            Ok(length)
+        }
+    }
+
+    #[test]
+    fn test_index_and_ranges_work() {
+        let s = IndexableString::from("0😀23456789");
+
+        assert_eq!(s[1], '😀');
+        assert_eq!(&s[1..9], "😀2345678");
+        assert_eq!(&s[1..], "😀23456789");
+        assert_eq!(&s[..9], "0😀2345678");
+        assert_eq!(s.to_string(), "0😀23456789");
+    }
+
+    #[test]
+    fn test_from_reader_with_chars_split_across_reads() {
+        let str = "0😀2é中a😀";
+
+        for chunk_length in 1..=5 {
+            let reader = ChunkedReader {
+                bytes: str.as_bytes(),
+                chunk_length,
+            };
+            let s = IndexableString::from_reader(reader).unwrap();
+
+            assert_eq!(s.as_str(), str);
+            assert_eq!(s.len(), 7);
+            assert_eq!(s[6], '😀');
+            assert_eq!(&s[3..5], "é中");
+        }
+    }
+
+    #[test]
+    fn test_edit_works() {
+        let mut s = IndexableString::from("0123456789");
+
+        s.edit(1..1, "😀");
+        assert_eq!(s.as_str(), "0😀123456789");
+        assert_eq!(s[1], '😀');
+        assert_eq!(s[2], '1');
+
+        s.edit(0..3, "");
+        assert_eq!(s.as_str(), "23456789");
+        assert_eq!(&s[6..], "89");
+
+        s.edit(8..8, "é");
+        assert_eq!(s.len(), 9);
+        assert_eq!(&s[7..], "9é");
+    }
+
+    #[test]
+    fn test_edit_with_ending_index_too_large_panics() {
+        let mut s = IndexableString::from("0😀23456789");
+
+        let result = std::panic::catch_unwind(move || s.edit(5..11, "a"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_join_agrees_with_new() {
+        let strs = [IndexableStr::new("0123"), IndexableStr::new(""), IndexableStr::new("😀é"), IndexableStr::new("中a")];
+
+        for separator in ["", ", ", "😀"] {
+            let s = IndexableString::join(&strs, separator);
+            let expected = IndexableString::from(["0123", "", "😀é", "中a"].join(separator).as_str());
+
+            assert_eq!(s.as_str(), expected.as_str());
+            assert_eq!(s.len(), expected.len());
+
+            for index in 0..expected.len() {
+                assert_eq!(s[index], expected[index]);
+                assert_eq!(&s[index..], &expected[index..]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_concat_of_ascii_strings_stays_ascii() {
+        let s = IndexableString::concat(&[IndexableStr::new("012"), IndexableStr::new("345")]);
+
+        assert_eq!(s.memory_usage(), 0);
+        assert_eq!(&s[2..4], "23");
+    }
+
+    #[test]
+    fn test_memory_usage_of_borrowed_index_is_zero() {
+        let s = IndexableString::from("0😀23456789");
+
+        assert!(s.memory_usage() > 0);
+        assert_eq!(s.as_indexable_str().memory_usage(), 0);
+    }
+
+    #[test]
+    fn test_from_reader_with_invalid_utf8() {
+        let bytes: &[u8] = b"01\xFF3";
+        let error = IndexableString::from_reader(bytes).err().unwrap();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("(2)"));
+    }
+
+    #[test]
+    fn test_from_reader_with_truncated_char() {
+        let bytes: &[u8] = &"0😀".as_bytes()[..3];
+        let error = IndexableString::from_reader(bytes).err().unwrap();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_from_utf8_with_invalid_utf8_returns_the_bytes() {
+        let bytes: Vec<u8> = b"0\xE4\xB8\xAD\xFF3".to_vec();
+        let error = IndexableString::from_utf8(bytes.clone()).err().unwrap();
+
+        assert_eq!(error.utf8_error().valid_up_to(), 4);
+        assert_eq!(error.into_bytes(), bytes);
+        assert_eq!(IndexableString::from_utf8_lossy(&bytes).as_str(), "0中\u{FFFD}3");
+    }
+
+    #[test]
+    fn test_from_utf16_agrees_with_string_from_utf16() {
+        let units: Vec<u16> = "0😀2345678😀é中".encode_utf16().collect();
+        let s = IndexableString::from_utf16(&units).unwrap();
+
+        assert_eq!(s.as_str(), String::from_utf16(&units).unwrap());
+        assert_eq!(s[11], '中');
+
+        let unpaired: [u16; 4] = [0xDE00, 0x30, 0xD83D, 0xD83D];
+
+        assert_eq!(IndexableString::from_utf16(&unpaired).err(), Some(IndexError::InvalidUtf16 { unit_offset: 0 }));
+        assert_eq!(IndexableString::from_utf16(&unpaired[1..]).err(), Some(IndexError::InvalidUtf16 { unit_offset: 1 }));
+        assert!(IndexableString::from_utf16_with_offsets(&unpaired).is_err());
+        assert_eq!(IndexableString::from_utf16_lossy(&unpaired).as_str(), String::from_utf16_lossy(&unpaired));
+    }
+
+    #[test]
+    fn test_extend_indexes_text_appended_to_an_ascii_string() {
+        let mut s: IndexableString = "012".chars().collect();
+
+        s.extend("😀4".chars());
+        s.extend(std::iter::empty());
+
+        assert_eq!(s.as_str(), "012😀4");
+        assert_eq!(s[3], '😀');
+        assert_eq!(s[4], '4');
+        assert_eq!(s.len(), 5);
+    }
+}
@@ -0,0 +1,711 @@
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    ops::{Index, Range, RangeFrom, RangeTo},
+};
+
+use crate::{
+    char_table::CharTable,
+    error::{check_index, check_range},
+    grapheme::{GraphemeBoundary, GraphemeCache},
+    IndexableStr, IndexableString, Offset, SharedIndexableStr, Span,
+};
+
+/// `SubStr` is a view of a range of `char`s in an `IndexableStr`. It borrows the index of its parent, so creating
+/// one, and slicing it again, never allocates or re-indexes.
+///
+/// Indices into a `SubStr` are relative to the start of the view, while `span` reports where the view is in its
+/// parent.
+///
+/// # Examples
+/// ```
+/// use indexable_str::{IndexableStr, Span};
+///
+/// let s = IndexableStr::new("0😀2345678😀");
+/// let sub = s.slice(1..9);
+/// let nested = sub.slice(1..4);
+///
+/// assert_eq!(sub[0], '😀');
+/// assert_eq!(&sub[1..4], "234");
+/// assert_eq!(nested.as_str(), "234");
+/// assert_eq!(nested.span(), Span::new(2, 5));
+/// ```
+#[derive(Copy, Clone)]
+pub struct SubStr<'a, O: Offset = u32> {
+    str: &'a str,
+    char_table: &'a CharTable<O>,
+    chars_length: usize,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, O: Offset> SubStr<'a, O> {
+    pub(crate) fn new(parent: &IndexableStr<'a, O>, char_table: &'a CharTable<O>, range: Range<usize>) -> SubStr<'a, O> {
+        parent.create_str_from_range(range.start, range.end);
+
+        SubStr {
+            str: parent.str,
+            char_table,
+            chars_length: parent.chars_length,
+            start: range.start,
+            end: range.end,
+        }
+    }
+
+    /// Returns the parent string, borrowing its index.
+    fn parent(&self) -> IndexableStr<'a, O> {
+        IndexableStr {
+            str: self.str,
+            str_length: self.str.len(),
+            char_table: Cow::Borrowed(self.char_table),
+            chars_length: self.chars_length,
+            graphemes: Cow::Owned(GraphemeCache::new()),
+        }
+    }
+
+    /// Returns the view as a string slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("0😀2345678😀");
+    ///
+    /// assert_eq!(s.slice(1..3).as_str(), "😀2");
+    /// ```
+    pub fn as_str(&self) -> &'a str {
+        self.parent().create_str_from_range(self.start, self.end)
+    }
+
+    /// Returns a `usize` for the number of `char`s in the view.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("0😀2345678😀");
+    ///
+    /// assert_eq!(s.slice(1..3).len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the view contains no `char`s.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("0😀2345678😀");
+    ///
+    /// assert!(s.slice(3..3).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the `Span` of the view in its parent.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("0😀2345678😀");
+    ///
+    /// assert_eq!(s.slice(1..3).span(), Span::new(1, 3));
+    /// ```
+    pub fn span(&self) -> Span {
+        Span::new(self.start, self.end)
+    }
+
+    /// Returns a narrower view of the same parent. The range is relative to the start of this view.
+    /// # Arguments
+    ///
+    /// * `range` - The range of `char`s in this view.
+    /// # Panics
+    /// * If the range end is greater than the number of characters in the view.
+    /// * If the range end is less than the range start.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("0😀2345678😀");
+    ///
+    /// assert_eq!(s.slice(1..9).slice(0..2).as_str(), "😀2");
+    /// ```
+    pub fn slice(&self, range: Range<usize>) -> SubStr<'a, O> {
+        self.check_range(range.start, range.end);
+
+        SubStr {
+            start: self.start + range.start,
+            end: self.start + range.end,
+            ..*self
+        }
+    }
+
+    fn check_range(&self, start_index: usize, end_index: usize) {
+        if let Err(error) = check_range(start_index, end_index, self.len()) {
+            panic!("{error}");
+        }
+    }
+
+    fn create_str_from_range(&self, start_index: usize, end_index: usize) -> &'a str {
+        self.check_range(start_index, end_index);
+
+        self.parent().create_str_from_range(self.start + start_index, self.start + end_index)
+    }
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns a `SubStr` view of a range of `char`s, which borrows this string's index instead of creating a new one.
+    /// # Arguments
+    ///
+    /// * `range` - The range of `char`s to view.
+    /// # Panics
+    /// * If the range end is greater than the number of characters in the string.
+    /// * If the range end is less than the range start.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("0😀2345678😀");
+    /// let sub = s.slice(1..9);
+    ///
+    /// assert_eq!(sub.len(), 8);
+    /// assert_eq!(sub[0], '😀');
+    /// ```
+    pub fn slice(&self, range: Range<usize>) -> SubStr<'_, O> {
+        SubStr::new(self, &self.char_table, range)
+    }
+
+    /// Returns an iterator over every run of `size` consecutive `char`s, like `slice::windows`. Each window is a
+    /// `SubStr`, so its `span` gives its position. There are no windows if the string is shorter than `size`.
+    /// # Arguments
+    ///
+    /// * `size` - The number of `char`s in each window.
+    /// # Panics
+    /// * If `size` is 0.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("0😀23");
+    /// let windows: Vec<&str> = s.windows(2).map(|window| window.as_str()).collect();
+    ///
+    /// assert_eq!(windows, vec!["0😀", "😀2", "23"]);
+    /// assert_eq!(s.windows(2).last().unwrap().span(), Span::new(2, 4));
+    /// ```
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = SubStr<'_, O>> + '_ {
+        window_ranges(self.chars_length, size).map(|range| self.slice(range))
+    }
+
+    /// Returns an iterator over runs of `size` `char`s, like `slice::chunks`. The last chunk is shorter if the
+    /// length of the string isn't a multiple of `size`.
+    /// # Arguments
+    ///
+    /// * `size` - The number of `char`s in each chunk.
+    /// # Panics
+    /// * If `size` is 0.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("0😀234");
+    /// let chunks: Vec<&str> = s.chunks(2).map(|chunk| chunk.as_str()).collect();
+    ///
+    /// assert_eq!(chunks, vec!["0😀", "23", "4"]);
+    /// assert_eq!(s.chunks(2).last().unwrap().span(), Span::new(4, 5));
+    /// ```
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = SubStr<'_, O>> + '_ {
+        chunk_ranges(self.chars_length, size).map(|range| self.slice(range))
+    }
+
+    /// Returns an iterator over every run of `size` consecutive grapheme clusters, like `windows` but never splitting
+    /// a base `char` from its combining marks, or the `char`s of an emoji sequence. Each window is a `SubStr`, so its
+    /// `span` gives its position in `char`s.
+    /// # Arguments
+    ///
+    /// * `size` - The number of grapheme clusters in each window.
+    /// # Panics
+    /// * If `size` is 0.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("ae\u{301}👍🏽");
+    /// let windows: Vec<&str> = s.grapheme_windows(2).map(|window| window.as_str()).collect();
+    ///
+    /// assert_eq!(windows, vec!["ae\u{301}", "e\u{301}👍🏽"]);
+    /// assert_eq!(s.grapheme_windows(2).last().unwrap().span(), Span::new(1, 5));
+    /// ```
+    pub fn grapheme_windows(&self, size: usize) -> impl Iterator<Item = SubStr<'_, O>> + '_ {
+        grapheme_window_ranges(self.grapheme_boundaries(), size).map(|range| self.slice(range))
+    }
+
+    /// Returns a `SubStr` view of a range of `char`s like `slice`, but clamps the range to the string instead of
+    /// panicking. A range end past the end of the string is moved to the end, and a range start past the range end is
+    /// moved to the range end, so the view is empty.
+    /// # Arguments
+    ///
+    /// * `range` - The range of `char`s to view.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("0😀2345678😀");
+    /// let index: usize = 8;
+    ///
+    /// assert_eq!(s.slice_clamped(index.saturating_sub(3)..index + 3).as_str(), "5678😀");
+    /// assert_eq!(s.slice_clamped(12..20).span(), Span::new(10, 10));
+    /// ```
+    pub fn slice_clamped(&self, range: Range<usize>) -> SubStr<'_, O> {
+        self.slice(clamped_range(range, self.chars_length))
+    }
+
+    /// Returns the `char` that is `n` `char`s before the end of the string, so `char_from_end(0)` is the last
+    /// `char`.
+    /// # Arguments
+    ///
+    /// * `n` - The number of `char`s between the `char` and the end of the string.
+    /// # Panics
+    /// * If `n` is greater than or equal to the number of characters in the string.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("0😀2345678😀");
+    ///
+    /// assert_eq!(s.char_from_end(0), '😀');
+    /// assert_eq!(s.char_from_end(9), '0');
+    /// ```
+    pub fn char_from_end(&self, n: usize) -> char {
+        self[from_end_index(n, self.chars_length)]
+    }
+
+    /// Returns a `SubStr` view of the last `n` `char`s of the string.
+    /// # Arguments
+    ///
+    /// * `n` - The number of `char`s to view.
+    /// # Panics
+    /// * If `n` is greater than the number of characters in the string.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("0😀2345678😀");
+    ///
+    /// assert_eq!(s.slice_last(2).as_str(), "8😀");
+    /// assert_eq!(s.slice_last(2).span(), Span::new(8, 10));
+    /// ```
+    pub fn slice_last(&self, n: usize) -> SubStr<'_, O> {
+        self.slice(from_end_range(0..n, self.chars_length))
+    }
+
+    /// Returns a `SubStr` view of a range of `char`s that is counted from the end of the string. The range start and
+    /// end are the number of `char`s between each end of the view and the end of the string, so `0..3` is the last
+    /// three `char`s and `1..3` leaves out the last `char`.
+    /// # Arguments
+    ///
+    /// * `range` - The range of `char`s to view, counted from the end of the string.
+    /// # Panics
+    /// * If the range end is greater than the number of characters in the string.
+    /// * If the range end is less than the range start.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("0😀2345678😀");
+    ///
+    /// assert_eq!(s.slice_from_end(1..3).as_str(), "78");
+    /// assert_eq!(s.slice_from_end(0..0).as_str(), "");
+    /// ```
+    pub fn slice_from_end(&self, range: Range<usize>) -> SubStr<'_, O> {
+        self.slice(from_end_range(range, self.chars_length))
+    }
+}
+
+impl IndexableString {
+    /// Returns a `SubStr` view of a range of `char`s, which borrows this string's index instead of creating a new one.
+    /// # Arguments
+    ///
+    /// * `range` - The range of `char`s to view.
+    /// # Panics
+    /// * If the range end is greater than the number of characters in the string.
+    /// * If the range end is less than the range start.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("0😀2345678😀");
+    ///
+    /// assert_eq!(s.slice(1..3).as_str(), "😀2");
+    /// ```
+    pub fn slice(&self, range: Range<usize>) -> SubStr<'_> {
+        SubStr::new(&self.as_indexable_str(), self.char_table(), range)
+    }
+
+    /// Returns an iterator over every run of `size` consecutive `char`s. See `IndexableStr::windows`.
+    /// # Arguments
+    ///
+    /// * `size` - The number of `char`s in each window.
+    /// # Panics
+    /// * If `size` is 0.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("0😀2");
+    ///
+    /// assert_eq!(s.windows(2).map(|window| window.as_str()).collect::<Vec<&str>>(), vec!["0😀", "😀2"]);
+    /// ```
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = SubStr<'_>> + '_ {
+        window_ranges(self.len(), size).map(|range| self.slice(range))
+    }
+
+    /// Returns an iterator over runs of `size` `char`s. See `IndexableStr::chunks`.
+    /// # Arguments
+    ///
+    /// * `size` - The number of `char`s in each chunk.
+    /// # Panics
+    /// * If `size` is 0.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("0😀2");
+    ///
+    /// assert_eq!(s.chunks(2).map(|chunk| chunk.as_str()).collect::<Vec<&str>>(), vec!["0😀", "2"]);
+    /// ```
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = SubStr<'_>> + '_ {
+        chunk_ranges(self.len(), size).map(|range| self.slice(range))
+    }
+
+    /// Returns an iterator over every run of `size` consecutive grapheme clusters. See
+    /// `IndexableStr::grapheme_windows`.
+    /// # Arguments
+    ///
+    /// * `size` - The number of grapheme clusters in each window.
+    /// # Panics
+    /// * If `size` is 0.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("👍🏽😀e\u{301}");
+    ///
+    /// assert_eq!(s.grapheme_windows(2).map(|window| window.as_str()).collect::<Vec<&str>>(), vec!["👍🏽😀", "😀e\u{301}"]);
+    /// ```
+    pub fn grapheme_windows(&self, size: usize) -> impl Iterator<Item = SubStr<'_>> + '_ {
+        grapheme_window_ranges(self.grapheme_boundaries(), size).map(|range| self.slice(range))
+    }
+
+    /// Returns a `SubStr` view of a range of `char`s, clamped to the string. See `IndexableStr::slice_clamped`.
+    /// # Arguments
+    ///
+    /// * `range` - The range of `char`s to view.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("0😀2");
+    ///
+    /// assert_eq!(s.slice_clamped(1..10).as_str(), "😀2");
+    /// ```
+    pub fn slice_clamped(&self, range: Range<usize>) -> SubStr<'_> {
+        self.slice(clamped_range(range, self.len()))
+    }
+
+    /// Returns the `char` that is `n` `char`s before the end of the string. See `IndexableStr::char_from_end`.
+    /// # Arguments
+    ///
+    /// * `n` - The number of `char`s between the `char` and the end of the string.
+    /// # Panics
+    /// * If `n` is greater than or equal to the number of characters in the string.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("0😀2").char_from_end(1), '😀');
+    /// ```
+    pub fn char_from_end(&self, n: usize) -> char {
+        self[from_end_index(n, self.len())]
+    }
+
+    /// Returns a `SubStr` view of the last `n` `char`s of the string. See `IndexableStr::slice_last`.
+    /// # Arguments
+    ///
+    /// * `n` - The number of `char`s to view.
+    /// # Panics
+    /// * If `n` is greater than the number of characters in the string.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("0😀2").slice_last(2).as_str(), "😀2");
+    /// ```
+    pub fn slice_last(&self, n: usize) -> SubStr<'_> {
+        self.slice(from_end_range(0..n, self.len()))
+    }
+
+    /// Returns a `SubStr` view of a range of `char`s that is counted from the end of the string. See
+    /// `IndexableStr::slice_from_end`.
+    /// # Arguments
+    ///
+    /// * `range` - The range of `char`s to view, counted from the end of the string.
+    /// # Panics
+    /// * If the range end is greater than the number of characters in the string.
+    /// * If the range end is less than the range start.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("0😀2").slice_from_end(1..3).as_str(), "0😀");
+    /// ```
+    pub fn slice_from_end(&self, range: Range<usize>) -> SubStr<'_> {
+        self.slice(from_end_range(range, self.len()))
+    }
+}
+
+impl SharedIndexableStr {
+    /// Returns a `SubStr` view of a range of `char`s, which borrows this string's index instead of creating a new one.
+    /// # Arguments
+    ///
+    /// * `range` - The range of `char`s to view.
+    /// # Panics
+    /// * If the range end is greater than the number of characters in the string.
+    /// * If the range end is less than the range start.
+    /// # Examples
+    /// ```
+    /// use indexable_str::SharedIndexableStr;
+    ///
+    /// let s = SharedIndexableStr::from("0😀2345678😀");
+    ///
+    /// assert_eq!(s.slice(1..3).as_str(), "😀2");
+    /// ```
+    pub fn slice(&self, range: Range<usize>) -> SubStr<'_> {
+        SubStr::new(&self.as_indexable_str(), self.char_table(), range)
+    }
+}
+
+fn window_ranges(length: usize, size: usize) -> impl Iterator<Item = Range<usize>> {
+    if size == 0 {
+        panic!("Window size must be greater than 0!");
+    }
+
+    (0..(length + 1).saturating_sub(size)).map(move |start| start..start + size)
+}
+
+fn chunk_ranges(length: usize, size: usize) -> impl Iterator<Item = Range<usize>> {
+    if size == 0 {
+        panic!("Chunk size must be greater than 0!");
+    }
+
+    (0..length).step_by(size).map(move |start| start..(start + size).min(length))
+}
+
+/// Returns the ranges of `char`s of every run of `size` consecutive grapheme clusters between `boundaries`.
+fn grapheme_window_ranges(boundaries: &[GraphemeBoundary], size: usize) -> impl Iterator<Item = Range<usize>> + '_ {
+    window_ranges(boundaries.len() - 1, size).map(move |range| boundaries[range.start].index..boundaries[range.end].index)
+}
+
+fn clamped_range(range: Range<usize>, length: usize) -> Range<usize> {
+    let end: usize = range.end.min(length);
+
+    range.start.min(end)..end
+}
+
+fn from_end_index(n: usize, length: usize) -> usize {
+    if let Err(error) = check_index(n, length) {
+        panic!("{error}");
+    }
+
+    length - 1 - n
+}
+
+fn from_end_range(range: Range<usize>, length: usize) -> Range<usize> {
+    if let Err(error) = check_range(range.start, range.end, length) {
+        panic!("{error}");
+    }
+
+    length - range.end..length - range.start
+}
+
+impl<'a, O: Offset> Display for SubStr<'a, O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// # Panics
+/// * If the index is greater than or equal to the number of characters in the view.
+impl<'a, O: Offset> Index<usize> for SubStr<'a, O> {
+    type Output = char;
+
+    fn index(&self, index: usize) -> &char {
+        if let Err(error) = check_index(index, self.len()) {
+            panic!("{error}");
+        }
+
+        self.char_table.char_ref(self.str, self.start + index)
+    }
+}
+
+/// # Panics
+/// * If the range end is greater than the number of characters in the view.
+/// * If the range end is less than the range start.
+impl<'a, O: Offset> Index<Range<usize>> for SubStr<'a, O> {
+    type Output = str;
+
+    fn index(&self, range: Range<usize>) -> &Self::Output {
+        self.create_str_from_range(range.start, range.end)
+    }
+}
+
+/// # Panics
+/// * If the range end is less than the range start.
+impl<'a, O: Offset> Index<RangeFrom<usize>> for SubStr<'a, O> {
+    type Output = str;
+
+    fn index(&self, index: RangeFrom<usize>) -> &Self::Output {
+        self.create_str_from_range(index.start, self.len())
+    }
+}
+
+/// # Panics
+/// * If the range end is greater than the number of characters in the view.
+impl<'a, O: Offset> Index<RangeTo<usize>> for SubStr<'a, O> {
+    type Output = str;
+
+    fn index(&self, index: RangeTo<usize>) -> &Self::Output {
+        self.create_str_from_range(0, index.end)
+    }
+}
+
+/// # Panics
+/// * If the span end is greater than the number of characters in the view.
+/// * If the span end is less than the span start.
+impl<'a, O: Offset> Index<Span> for SubStr<'a, O> {
+    type Output = str;
+
+    fn index(&self, span: Span) -> &Self::Output {
+        self.create_str_from_range(span.start, span.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slice_does_not_copy_the_index() {
+        let s = IndexableStr::new("0😀23456789");
+        let sub = s.slice(1..9);
+
+        assert_eq!(sub.parent().memory_usage(), 0);
+        assert_eq!(sub.as_str(), "😀2345678");
+    }
+
+    #[test]
+    fn test_index_and_ranges_are_relative_to_the_view() {
+        let s = IndexableStr::new("0😀2345678😀");
+        let sub = s.slice(1..10);
+
+        assert_eq!(sub[8], '😀');
+        assert_eq!(&sub[1..3], "23");
+        assert_eq!(&sub[7..], "8😀");
+        assert_eq!(&sub[..2], "😀2");
+        assert_eq!(&sub[9..], "");
+    }
+
+    #[test]
+    fn test_index_past_the_end_of_the_view_panics() {
+        let s = IndexableStr::new("0😀2345678😀");
+        let sub = s.slice(1..3);
+
+        let result = std::panic::catch_unwind(|| sub[2]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_range_past_the_end_of_the_view_panics() {
+        let s = IndexableStr::new("0😀2345678😀");
+        let sub = s.slice(1..3);
+
+        let result = std::panic::catch_unwind(|| sub.create_str_from_range(0, 3));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_windows_and_chunks_agree_with_slices_of_chars() {
+        let str: &str = "0😀2345678😀";
+        let chars: Vec<char> = str.chars().collect();
+        let s = IndexableStr::new(str);
+
+        for size in 1..=12 {
+            let windows: Vec<String> = chars.windows(size).map(|window| window.iter().collect()).collect();
+            let chunks: Vec<String> = chars.chunks(size).map(|chunk| chunk.iter().collect()).collect();
+
+            assert_eq!(s.windows(size).map(|window| window.to_string()).collect::<Vec<String>>(), windows);
+            assert_eq!(s.chunks(size).map(|chunk| chunk.to_string()).collect::<Vec<String>>(), chunks);
+        }
+    }
+
+    #[test]
+    fn test_windows_with_zero_size_panics() {
+        let s = IndexableStr::new("0😀2");
+
+        let result = std::panic::catch_unwind(|| s.windows(0).count());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_slice_clamped_keeps_the_range_inside_the_string() {
+        let s = IndexableStr::new("0😀2345678😀");
+        let start: usize = 5;
+
+        assert_eq!(s.slice_clamped(0..usize::MAX).as_str(), "0😀2345678😀");
+        assert_eq!(s.slice_clamped(start..2).span(), Span::new(2, 2));
+        assert_eq!(s.slice_clamped(usize::MAX..usize::MAX).span(), Span::new(10, 10));
+        assert_eq!(IndexableStr::new("").slice_clamped(3..7).as_str(), "");
+    }
+
+    #[test]
+    fn test_from_end_helpers_agree_with_len_arithmetic() {
+        let s = IndexableStr::new("0😀2345678😀");
+        let length: usize = s.len();
+
+        for n in 0..length {
+            assert_eq!(s.char_from_end(n), s[length - 1 - n]);
+            assert_eq!(s.slice_last(n).as_str(), &s[length - n..]);
+            assert_eq!(s.slice_from_end(n..length).as_str(), &s[..length - n]);
+        }
+
+        let result = std::panic::catch_unwind(|| s.char_from_end(length));
+        assert!(result.is_err());
+
+        let result = std::panic::catch_unwind(|| s.slice_last(length + 1).len());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_grapheme_windows_never_split_a_grapheme() {
+        let s = IndexableStr::new("e\u{301}👨\u{200D}👩\u{200D}👧x🇫🇷");
+        let windows: Vec<SubStr<'_>> = s.grapheme_windows(2).collect();
+
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows[0].as_str(), "e\u{301}👨\u{200D}👩\u{200D}👧");
+        assert_eq!(windows[2].span(), Span::new(7, 10));
+        assert_eq!(s.grapheme_windows(4).count(), 1);
+        assert_eq!(s.grapheme_windows(5).count(), 0);
+        assert_eq!(IndexableStr::new("").grapheme_windows(1).count(), 0);
+    }
+
+    #[test]
+    fn test_slice_with_ending_index_too_large_panics() {
+        let s = IndexableStr::new("0😀2345678😀");
+
+        let result = std::panic::catch_unwind(|| s.slice(0..11).len());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,220 @@
+use std::{borrow::Cow, ops::Range};
+
+use crate::{CompactIndexableStr, IndexableStr, IndexableString, SharedIndexableStr};
+
+/// `IndexableText` is the set of `char` based operations shared by the indexable string types, so that code can
+/// accept any of them. It's also implemented for `&str`, where each operation scans the string, which takes O(n)
+/// time instead of O(1).
+///
+/// Unlike indexing, the methods return `None` instead of panicking when an index or range is out of bounds.
+///
+/// # Examples
+/// ```
+/// use indexable_str::{IndexableStr, IndexableString, IndexableText};
+///
+/// fn last_char(text: &impl IndexableText) -> Option<char> {
+///     text.char_at(text.char_count().checked_sub(1)?)
+/// }
+///
+/// assert_eq!(last_char(&IndexableStr::new("0😀")), Some('😀'));
+/// assert_eq!(last_char(&IndexableString::from("0😀2")), Some('2'));
+/// assert_eq!(last_char(&"0😀2😀"), Some('😀'));
+/// assert_eq!(last_char(&""), None);
+/// ```
+pub trait IndexableText {
+    /// Returns the number of `char`s.
+    fn char_count(&self) -> usize;
+
+    /// Returns the `char` at `index`, or `None` if the index is greater than or equal to the number of `char`s.
+    fn char_at(&self, index: usize) -> Option<char>;
+
+    /// Returns the starting byte offset of the `char` at `index`, or `None` if the index is greater than the number
+    /// of `char`s. The index equal to the number of `char`s maps to the length in bytes.
+    fn char_to_byte(&self, index: usize) -> Option<usize>;
+
+    /// Returns the index of the `char` that starts at `byte_offset`, or `None` if the byte offset isn't on a `char`
+    /// boundary. The length in bytes maps to the number of `char`s.
+    fn byte_to_char(&self, byte_offset: usize) -> Option<usize>;
+
+    /// Returns the text of a range of `char`s, or `None` if the range is out of bounds or its end is less than its
+    /// start. The text is borrowed unless the type doesn't store it contiguously.
+    fn char_slice(&self, range: Range<usize>) -> Option<Cow<'_, str>>;
+}
+
+impl<'a> IndexableText for IndexableStr<'a> {
+    fn char_count(&self) -> usize {
+        self.chars_length
+    }
+
+    fn char_at(&self, index: usize) -> Option<char> {
+        (index < self.chars_length).then(|| self[index])
+    }
+
+    fn char_to_byte(&self, index: usize) -> Option<usize> {
+        (index <= self.chars_length).then(|| self.byte_offset(index))
+    }
+
+    fn byte_to_char(&self, byte_offset: usize) -> Option<usize> {
+        self.char_index_at_byte(byte_offset)
+    }
+
+    fn char_slice(&self, range: Range<usize>) -> Option<Cow<'_, str>> {
+        (range.start <= range.end && range.end <= self.chars_length).then(|| Cow::Borrowed(self.create_str_from_range(range.start, range.end)))
+    }
+}
+
+impl IndexableText for IndexableString {
+    fn char_count(&self) -> usize {
+        self.len()
+    }
+
+    fn char_at(&self, index: usize) -> Option<char> {
+        self.as_indexable_str().char_at(index)
+    }
+
+    fn char_to_byte(&self, index: usize) -> Option<usize> {
+        self.as_indexable_str().char_to_byte(index)
+    }
+
+    fn byte_to_char(&self, byte_offset: usize) -> Option<usize> {
+        self.as_indexable_str().byte_to_char(byte_offset)
+    }
+
+    fn char_slice(&self, range: Range<usize>) -> Option<Cow<'_, str>> {
+        let str: IndexableStr<'_> = self.as_indexable_str();
+
+        (range.start <= range.end && range.end <= str.chars_length).then(|| Cow::Borrowed(str.create_str_from_range(range.start, range.end)))
+    }
+}
+
+impl IndexableText for SharedIndexableStr {
+    fn char_count(&self) -> usize {
+        self.len()
+    }
+
+    fn char_at(&self, index: usize) -> Option<char> {
+        self.as_indexable_str().char_at(index)
+    }
+
+    fn char_to_byte(&self, index: usize) -> Option<usize> {
+        self.as_indexable_str().char_to_byte(index)
+    }
+
+    fn byte_to_char(&self, byte_offset: usize) -> Option<usize> {
+        self.as_indexable_str().byte_to_char(byte_offset)
+    }
+
+    fn char_slice(&self, range: Range<usize>) -> Option<Cow<'_, str>> {
+        let str: IndexableStr<'_> = self.as_indexable_str();
+
+        (range.start <= range.end && range.end <= str.chars_length).then(|| Cow::Borrowed(str.create_str_from_range(range.start, range.end)))
+    }
+}
+
+impl<'a> IndexableText for CompactIndexableStr<'a> {
+    fn char_count(&self) -> usize {
+        self.len()
+    }
+
+    fn char_at(&self, index: usize) -> Option<char> {
+        (index < self.len()).then(|| CompactIndexableStr::char_at(self, index))
+    }
+
+    fn char_to_byte(&self, index: usize) -> Option<usize> {
+        (index <= self.len()).then(|| self.byte_offset(index))
+    }
+
+    fn byte_to_char(&self, byte_offset: usize) -> Option<usize> {
+        self.char_index(byte_offset)
+    }
+
+    fn char_slice(&self, range: Range<usize>) -> Option<Cow<'_, str>> {
+        (range.start <= range.end && range.end <= self.len()).then(|| Cow::Borrowed(&self[range]))
+    }
+}
+
+impl IndexableText for &str {
+    fn char_count(&self) -> usize {
+        self.chars().count()
+    }
+
+    fn char_at(&self, index: usize) -> Option<char> {
+        self.chars().nth(index)
+    }
+
+    fn char_to_byte(&self, index: usize) -> Option<usize> {
+        self.char_indices().map(|(offset, _)| offset).chain(std::iter::once(self.len())).nth(index)
+    }
+
+    fn byte_to_char(&self, byte_offset: usize) -> Option<usize> {
+        self.is_char_boundary(byte_offset).then(|| self[..byte_offset].chars().count())
+    }
+
+    fn char_slice(&self, range: Range<usize>) -> Option<Cow<'_, str>> {
+        if range.start > range.end {
+            return None;
+        }
+
+        let start: usize = self.char_to_byte(range.start)?;
+        let end: usize = start + (&self[start..]).char_to_byte(range.end - range.start)?;
+
+        Some(Cow::Borrowed(&self[start..end]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IndexablePieceTable;
+
+    /// Checks every operation of `text` against the `char`s of `str`.
+    fn check_text(text: &impl IndexableText, str: &str) {
+        let chars: Vec<(usize, char)> = str.char_indices().collect();
+
+        assert_eq!(text.char_count(), chars.len());
+        assert_eq!(text.char_at(chars.len()), None);
+        assert_eq!(text.char_to_byte(chars.len()), Some(str.len()));
+        assert_eq!(text.char_to_byte(chars.len() + 1), None);
+        assert_eq!(text.byte_to_char(str.len()), Some(chars.len()));
+        assert_eq!(text.byte_to_char(str.len() + 1), None);
+
+        for (index, (offset, chr)) in chars.iter().enumerate() {
+            assert_eq!(text.char_at(index), Some(*chr));
+            assert_eq!(text.char_to_byte(index), Some(*offset));
+            assert_eq!(text.byte_to_char(*offset), Some(index));
+
+            for inside in 1..chr.len_utf8() {
+                assert_eq!(text.byte_to_char(offset + inside), None);
+            }
+        }
+
+        for start in 0..=chars.len() {
+            for end in start..=chars.len() {
+                let expected: String = str.chars().skip(start).take(end - start).collect();
+
+                assert_eq!(text.char_slice(start..end).unwrap(), expected);
+            }
+        }
+
+        assert_eq!(text.char_slice(0..chars.len() + 1), None);
+    }
+
+    #[test]
+    fn test_every_implementation_agrees_with_chars() {
+        for str in ["", "0123", "0😀2345678😀", "é中😀"] {
+            check_text(&IndexableStr::new(str), str);
+            check_text(&IndexableString::from(str), str);
+            check_text(&SharedIndexableStr::from(str), str);
+            check_text(&CompactIndexableStr::new(str), str);
+            check_text(&CompactIndexableStr::new_succinct(str), str);
+            check_text(&CompactIndexableStr::with_checkpoints(str, 2), str);
+            check_text(&str, str);
+        }
+
+        let mut piece_table = IndexablePieceTable::new("0😀2345678😀");
+
+        piece_table.edit(2..5, "é中");
+        piece_table.edit(0..0, "😃");
+        check_text(&piece_table, "😃0😀é中5678😀");
+    }
+}
@@ -0,0 +1,136 @@
+use std::ops::Range;
+
+use crate::{IndexableStr, IndexableString, Offset, SubStr};
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns a `SubStr` view without leading and trailing whitespace, as defined by `char::is_whitespace`. Its
+    /// `span` gives the range of `char`s that were kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("\u{3000} 😀2 \n");
+    /// let trimmed = s.trim();
+    ///
+    /// assert_eq!(trimmed.as_str(), "😀2");
+    /// assert_eq!(trimmed.span(), Span::new(2, 4));
+    /// ```
+    pub fn trim(&self) -> SubStr<'_, O> {
+        self.slice(self.trimmed_range(true, true))
+    }
+
+    /// Returns a `SubStr` view without leading whitespace.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("\u{3000} 😀2 \n");
+    ///
+    /// assert_eq!(s.trim_start().as_str(), "😀2 \n");
+    /// assert_eq!(s.trim_start().span(), Span::new(2, 6));
+    /// ```
+    pub fn trim_start(&self) -> SubStr<'_, O> {
+        self.slice(self.trimmed_range(true, false))
+    }
+
+    /// Returns a `SubStr` view without trailing whitespace.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("\u{3000} 😀2 \n");
+    ///
+    /// assert_eq!(s.trim_end().as_str(), "\u{3000} 😀2");
+    /// assert_eq!(s.trim_end().span(), Span::new(0, 4));
+    /// ```
+    pub fn trim_end(&self) -> SubStr<'_, O> {
+        self.slice(self.trimmed_range(false, true))
+    }
+
+    fn trimmed_range(&self, start: bool, end: bool) -> Range<usize> {
+        let trimmed_start: &str = match start {
+            true => self.str.trim_start(),
+            false => self.str,
+        };
+        let start_index: usize = self.char_index_at_byte(self.str_length - trimmed_start.len()).unwrap();
+
+        let trimmed: &str = match end {
+            true => trimmed_start.trim_end(),
+            false => trimmed_start,
+        };
+        let end_index: usize = self.chars_length - trimmed_start[trimmed.len()..].chars().count();
+
+        start_index..end_index
+    }
+}
+
+impl IndexableString {
+    /// Returns a `SubStr` view without leading and trailing whitespace. See `IndexableStr::trim`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableString, Span};
+    ///
+    /// let s = IndexableString::from(" 😀2 ");
+    ///
+    /// assert_eq!(s.trim().span(), Span::new(1, 3));
+    /// ```
+    pub fn trim(&self) -> SubStr<'_> {
+        self.slice(self.as_indexable_str().trimmed_range(true, true))
+    }
+
+    /// Returns a `SubStr` view without leading whitespace. See `IndexableStr::trim_start`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableString, Span};
+    ///
+    /// let s = IndexableString::from(" 😀2 ");
+    ///
+    /// assert_eq!(s.trim_start().span(), Span::new(1, 4));
+    /// ```
+    pub fn trim_start(&self) -> SubStr<'_> {
+        self.slice(self.as_indexable_str().trimmed_range(true, false))
+    }
+
+    /// Returns a `SubStr` view without trailing whitespace. See `IndexableStr::trim_end`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableString, Span};
+    ///
+    /// let s = IndexableString::from(" 😀2 ");
+    ///
+    /// assert_eq!(s.trim_end().span(), Span::new(0, 3));
+    /// ```
+    pub fn trim_end(&self) -> SubStr<'_> {
+        self.slice(self.as_indexable_str().trimmed_range(false, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim_agrees_with_str_trim() {
+        for str in ["", " ", " \t\n", "😀", " 😀 ", "\u{3000}中\u{3000}文\u{3000}", "a  ", "  a"] {
+            let s = IndexableStr::new(str);
+
+            assert_eq!(s.trim().as_str(), str.trim());
+            assert_eq!(s.trim_start().as_str(), str.trim_start());
+            assert_eq!(s.trim_end().as_str(), str.trim_end());
+        }
+    }
+
+    #[test]
+    fn test_whitespace_only_string_trims_to_an_empty_view() {
+        let s = IndexableStr::new(" \u{3000} ");
+
+        assert_eq!(s.trim().span().len(), 0);
+        assert_eq!(s.trim_end().span().start, 0);
+    }
+}
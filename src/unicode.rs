@@ -0,0 +1,571 @@
+//! Simplified Unicode properties for display widths and grapheme clusters.
+//!
+//! The tables only cover the scripts and symbols that are common in terminal text, so they are much smaller than the
+//! full Unicode Character Database. Anything they don't list is treated as a narrow, ordinary `char`.
+
+#[cfg(feature = "script")]
+use crate::Script;
+
+/// `char`s that take two terminal columns: East Asian Wide and Fullwidth `char`s, and emoji presentation symbols.
+const WIDE: &[(u32, u32)] = &[
+    (0x1100, 0x115F),
+    (0x231A, 0x231B),
+    (0x2329, 0x232A),
+    (0x23E9, 0x23EC),
+    (0x23F0, 0x23F0),
+    (0x23F3, 0x23F3),
+    (0x25FD, 0x25FE),
+    (0x2614, 0x2615),
+    (0x2648, 0x2653),
+    (0x267F, 0x267F),
+    (0x2693, 0x2693),
+    (0x26A1, 0x26A1),
+    (0x26AA, 0x26AB),
+    (0x26BD, 0x26BE),
+    (0x26C4, 0x26C5),
+    (0x26CE, 0x26CE),
+    (0x26D4, 0x26D4),
+    (0x26EA, 0x26EA),
+    (0x26F2, 0x26F3),
+    (0x26F5, 0x26F5),
+    (0x26FA, 0x26FA),
+    (0x26FD, 0x26FD),
+    (0x2705, 0x2705),
+    (0x270A, 0x270B),
+    (0x2728, 0x2728),
+    (0x274C, 0x274C),
+    (0x274E, 0x274E),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2795, 0x2797),
+    (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x2E80, 0x303E),
+    (0x3041, 0x33FF),
+    (0x3400, 0x4DBF),
+    (0x4E00, 0x9FFF),
+    (0xA000, 0xA4CF),
+    (0xA960, 0xA97F),
+    (0xAC00, 0xD7A3),
+    (0xF900, 0xFAFF),
+    (0xFE10, 0xFE19),
+    (0xFE30, 0xFE6F),
+    (0xFF00, 0xFF60),
+    (0xFFE0, 0xFFE6),
+    (0x16FE0, 0x18CFF),
+    (0x1B000, 0x1B2FF),
+    (0x1F004, 0x1F004),
+    (0x1F0CF, 0x1F0CF),
+    (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A),
+    (0x1F1E6, 0x1F1FF),
+    (0x1F200, 0x1F265),
+    (0x1F300, 0x1F64F),
+    (0x1F680, 0x1F6FF),
+    (0x1F7E0, 0x1F7EB),
+    (0x1F90C, 0x1F9FF),
+    (0x1FA70, 0x1FAFF),
+    (0x20000, 0x2FFFD),
+    (0x30000, 0x3FFFD),
+];
+
+/// `char`s that extend the grapheme cluster before them and take no columns: combining marks, zero width joiners,
+/// variation selectors, emoji modifiers and tags.
+const EXTEND: &[(u32, u32)] = &[
+    (0x0300, 0x036F),
+    (0x0483, 0x0489),
+    (0x0591, 0x05BD),
+    (0x05BF, 0x05BF),
+    (0x05C1, 0x05C2),
+    (0x05C4, 0x05C5),
+    (0x05C7, 0x05C7),
+    (0x0610, 0x061A),
+    (0x064B, 0x065F),
+    (0x0670, 0x0670),
+    (0x06D6, 0x06DC),
+    (0x06DF, 0x06E4),
+    (0x06E7, 0x06E8),
+    (0x06EA, 0x06ED),
+    (0x0900, 0x0902),
+    (0x093C, 0x093C),
+    (0x0941, 0x0948),
+    (0x094D, 0x094D),
+    (0x0951, 0x0957),
+    (0x0E31, 0x0E31),
+    (0x0E34, 0x0E3A),
+    (0x0E47, 0x0E4E),
+    (0x1AB0, 0x1AFF),
+    (0x1DC0, 0x1DFF),
+    (0x200C, 0x200D),
+    (0x20D0, 0x20FF),
+    (0x302A, 0x302F),
+    (0x3099, 0x309A),
+    (0xFE00, 0xFE0F),
+    (0xFE20, 0xFE2F),
+    (0x1F3FB, 0x1F3FF),
+    (0xE0020, 0xE007F),
+    (0xE0100, 0xE01EF),
+];
+
+/// `char`s that can be joined into emoji ZWJ sequences.
+const PICTOGRAPHIC: &[(u32, u32)] = &[
+    (0x00A9, 0x00A9),
+    (0x00AE, 0x00AE),
+    (0x203C, 0x203C),
+    (0x2049, 0x2049),
+    (0x2122, 0x2122),
+    (0x2139, 0x2139),
+    (0x2194, 0x21AA),
+    (0x2300, 0x23FF),
+    (0x2600, 0x27BF),
+    (0x2B00, 0x2BFF),
+    (0x3030, 0x3030),
+    (0x303D, 0x303D),
+    (0x3297, 0x3297),
+    (0x3299, 0x3299),
+    (0x1F000, 0x1F1E5),
+    (0x1F200, 0x1FAFF),
+];
+
+/// Punctuation `char`s (general category P) in ASCII, Latin-1, the common scripts, General Punctuation, brackets,
+/// CJK punctuation and the halfwidth and fullwidth forms.
+#[cfg(feature = "category")]
+const PUNCTUATION: &[(u32, u32)] = &[
+    (0x0021, 0x0023),
+    (0x0025, 0x002A),
+    (0x002C, 0x002F),
+    (0x003A, 0x003B),
+    (0x003F, 0x0040),
+    (0x005B, 0x005D),
+    (0x005F, 0x005F),
+    (0x007B, 0x007B),
+    (0x007D, 0x007D),
+    (0x00A1, 0x00A1),
+    (0x00A7, 0x00A7),
+    (0x00AB, 0x00AB),
+    (0x00B6, 0x00B7),
+    (0x00BB, 0x00BB),
+    (0x00BF, 0x00BF),
+    (0x037E, 0x037E),
+    (0x0387, 0x0387),
+    (0x055A, 0x055F),
+    (0x0589, 0x058A),
+    (0x05BE, 0x05BE),
+    (0x05C0, 0x05C0),
+    (0x05C3, 0x05C3),
+    (0x05C6, 0x05C6),
+    (0x05F3, 0x05F4),
+    (0x060C, 0x060D),
+    (0x061B, 0x061B),
+    (0x061D, 0x061F),
+    (0x066A, 0x066D),
+    (0x06D4, 0x06D4),
+    (0x0964, 0x0965),
+    (0x0970, 0x0970),
+    (0x0E4F, 0x0E4F),
+    (0x0E5A, 0x0E5B),
+    (0x2010, 0x2027),
+    (0x2030, 0x2043),
+    (0x2045, 0x2051),
+    (0x2053, 0x205E),
+    (0x2308, 0x230B),
+    (0x2329, 0x232A),
+    (0x2768, 0x2775),
+    (0x27C5, 0x27C6),
+    (0x27E6, 0x27EF),
+    (0x2983, 0x2998),
+    (0x29D8, 0x29DB),
+    (0x29FC, 0x29FD),
+    (0x2E00, 0x2E2E),
+    (0x2E30, 0x2E4F),
+    (0x3001, 0x3003),
+    (0x3008, 0x3011),
+    (0x3014, 0x301F),
+    (0x3030, 0x3030),
+    (0x303D, 0x303D),
+    (0x30A0, 0x30A0),
+    (0x30FB, 0x30FB),
+    (0xFE10, 0xFE19),
+    (0xFE30, 0xFE52),
+    (0xFE54, 0xFE61),
+    (0xFE63, 0xFE63),
+    (0xFE68, 0xFE68),
+    (0xFE6A, 0xFE6B),
+    (0xFF01, 0xFF03),
+    (0xFF05, 0xFF0A),
+    (0xFF0C, 0xFF0F),
+    (0xFF1A, 0xFF1B),
+    (0xFF1F, 0xFF20),
+    (0xFF3B, 0xFF3D),
+    (0xFF3F, 0xFF3F),
+    (0xFF5B, 0xFF5B),
+    (0xFF5D, 0xFF5D),
+    (0xFF5F, 0xFF65),
+];
+
+/// The scripts of `char`s, for the scripts that `Script` names. `char`s that aren't listed are `Script::Unknown` if
+/// they are alphabetic and `Script::Common` otherwise.
+#[cfg(feature = "script")]
+const SCRIPTS: &[(u32, u32, Script)] = &[
+    (0x0041, 0x005A, Script::Latin),
+    (0x0061, 0x007A, Script::Latin),
+    (0x00AA, 0x00AA, Script::Latin),
+    (0x00BA, 0x00BA, Script::Latin),
+    (0x00C0, 0x00D6, Script::Latin),
+    (0x00D8, 0x00F6, Script::Latin),
+    (0x00F8, 0x02B8, Script::Latin),
+    (0x02B9, 0x02DF, Script::Common),
+    (0x02E0, 0x02E4, Script::Latin),
+    (0x02E5, 0x02FF, Script::Common),
+    (0x0300, 0x036F, Script::Inherited),
+    (0x0370, 0x0373, Script::Greek),
+    (0x0374, 0x0374, Script::Common),
+    (0x0375, 0x0377, Script::Greek),
+    (0x037A, 0x037D, Script::Greek),
+    (0x037E, 0x037E, Script::Common),
+    (0x037F, 0x037F, Script::Greek),
+    (0x0384, 0x0384, Script::Greek),
+    (0x0385, 0x0385, Script::Common),
+    (0x0386, 0x0386, Script::Greek),
+    (0x0387, 0x0387, Script::Common),
+    (0x0388, 0x03E1, Script::Greek),
+    (0x03F0, 0x03FF, Script::Greek),
+    (0x0400, 0x0484, Script::Cyrillic),
+    (0x0485, 0x0486, Script::Inherited),
+    (0x0487, 0x052F, Script::Cyrillic),
+    (0x0531, 0x0556, Script::Armenian),
+    (0x0559, 0x058A, Script::Armenian),
+    (0x058D, 0x058F, Script::Armenian),
+    (0x0591, 0x05C7, Script::Hebrew),
+    (0x05D0, 0x05EA, Script::Hebrew),
+    (0x05EF, 0x05F4, Script::Hebrew),
+    (0x0600, 0x0604, Script::Arabic),
+    (0x0605, 0x0605, Script::Common),
+    (0x0606, 0x060B, Script::Arabic),
+    (0x060C, 0x060C, Script::Common),
+    (0x060D, 0x061A, Script::Arabic),
+    (0x061B, 0x061B, Script::Common),
+    (0x061C, 0x061E, Script::Arabic),
+    (0x061F, 0x061F, Script::Common),
+    (0x0620, 0x063F, Script::Arabic),
+    (0x0640, 0x0640, Script::Common),
+    (0x0641, 0x064A, Script::Arabic),
+    (0x064B, 0x0655, Script::Inherited),
+    (0x0656, 0x066F, Script::Arabic),
+    (0x0670, 0x0670, Script::Inherited),
+    (0x0671, 0x06DC, Script::Arabic),
+    (0x06DD, 0x06DD, Script::Common),
+    (0x06DE, 0x06FF, Script::Arabic),
+    (0x0750, 0x077F, Script::Arabic),
+    (0x08A0, 0x08FF, Script::Arabic),
+    (0x0900, 0x0950, Script::Devanagari),
+    (0x0951, 0x0954, Script::Inherited),
+    (0x0955, 0x0963, Script::Devanagari),
+    (0x0964, 0x0965, Script::Common),
+    (0x0966, 0x097F, Script::Devanagari),
+    (0x0E01, 0x0E3A, Script::Thai),
+    (0x0E3F, 0x0E3F, Script::Common),
+    (0x0E40, 0x0E5B, Script::Thai),
+    (0x1100, 0x11FF, Script::Hangul),
+    (0x1AB0, 0x1AFF, Script::Inherited),
+    (0x1D00, 0x1D25, Script::Latin),
+    (0x1DC0, 0x1DFF, Script::Inherited),
+    (0x1E00, 0x1EFF, Script::Latin),
+    (0x1F00, 0x1FFF, Script::Greek),
+    (0x200C, 0x200D, Script::Inherited),
+    (0x20D0, 0x20FF, Script::Inherited),
+    (0x2C60, 0x2C7F, Script::Latin),
+    (0x2DE0, 0x2DFF, Script::Cyrillic),
+    (0x2E80, 0x2FD5, Script::Han),
+    (0x3000, 0x3004, Script::Common),
+    (0x3005, 0x3005, Script::Han),
+    (0x3006, 0x3006, Script::Common),
+    (0x3007, 0x3007, Script::Han),
+    (0x3008, 0x3020, Script::Common),
+    (0x3021, 0x3029, Script::Han),
+    (0x302A, 0x302D, Script::Inherited),
+    (0x3030, 0x3037, Script::Common),
+    (0x3038, 0x303B, Script::Han),
+    (0x303C, 0x303F, Script::Common),
+    (0x3041, 0x3096, Script::Hiragana),
+    (0x3099, 0x309A, Script::Inherited),
+    (0x309B, 0x309C, Script::Common),
+    (0x309D, 0x309F, Script::Hiragana),
+    (0x30A0, 0x30A0, Script::Common),
+    (0x30A1, 0x30FA, Script::Katakana),
+    (0x30FB, 0x30FC, Script::Common),
+    (0x30FD, 0x30FF, Script::Katakana),
+    (0x3131, 0x318E, Script::Hangul),
+    (0x31F0, 0x31FF, Script::Katakana),
+    (0x32D0, 0x32FE, Script::Katakana),
+    (0x3300, 0x3357, Script::Katakana),
+    (0x3400, 0x4DBF, Script::Han),
+    (0x4E00, 0x9FFF, Script::Han),
+    (0xA640, 0xA69F, Script::Cyrillic),
+    (0xA722, 0xA7FF, Script::Latin),
+    (0xA8E0, 0xA8FF, Script::Devanagari),
+    (0xA960, 0xA97C, Script::Hangul),
+    (0xAB30, 0xAB5A, Script::Latin),
+    (0xAC00, 0xD7A3, Script::Hangul),
+    (0xD7B0, 0xD7FB, Script::Hangul),
+    (0xF900, 0xFAD9, Script::Han),
+    (0xFB00, 0xFB06, Script::Latin),
+    (0xFB1D, 0xFB4F, Script::Hebrew),
+    (0xFB50, 0xFDFF, Script::Arabic),
+    (0xFE00, 0xFE0F, Script::Inherited),
+    (0xFE20, 0xFE2D, Script::Inherited),
+    (0xFE70, 0xFEFC, Script::Arabic),
+    (0xFF21, 0xFF3A, Script::Latin),
+    (0xFF41, 0xFF5A, Script::Latin),
+    (0xFF66, 0xFF6F, Script::Katakana),
+    (0xFF70, 0xFF70, Script::Common),
+    (0xFF71, 0xFF9D, Script::Katakana),
+    (0xFF9E, 0xFF9F, Script::Common),
+    (0xFFA0, 0xFFDC, Script::Hangul),
+    (0x20000, 0x2FFFD, Script::Han),
+    (0x30000, 0x3FFFD, Script::Han),
+    (0xE0100, 0xE01EF, Script::Inherited),
+];
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+const EMOJI_PRESENTATION_SELECTOR: char = '\u{FE0F}';
+
+fn in_table(table: &[(u32, u32)], chr: char) -> bool {
+    let code_point: u32 = chr as u32;
+
+    table
+        .binary_search_by(|&(first, last)| match (code_point < first, code_point > last) {
+            (true, _) => std::cmp::Ordering::Greater,
+            (_, true) => std::cmp::Ordering::Less,
+            _ => std::cmp::Ordering::Equal,
+        })
+        .is_ok()
+}
+
+/// Returns the number of terminal columns taken by `chr` on its own. Control `char`s and `char`s that extend a
+/// grapheme cluster take none.
+pub(crate) fn char_width(chr: char) -> usize {
+    match chr {
+        ' '..='~' => 1,
+        _ if chr.is_control() || in_table(EXTEND, chr) || chr == '\u{200B}' => 0,
+        _ if in_table(WIDE, chr) => 2,
+        _ => 1,
+    }
+}
+
+/// Returns the number of terminal columns taken by a grapheme cluster. A cluster is as wide as its widest `char`, and
+/// an emoji presentation selector makes a narrow symbol wide.
+pub(crate) fn grapheme_width(grapheme: &str) -> usize {
+    let width: usize = grapheme.chars().map(char_width).max().unwrap_or(0);
+
+    match grapheme.contains(EMOJI_PRESENTATION_SELECTOR) {
+        true => width.max(2),
+        false => width,
+    }
+}
+
+/// Returns `true` for format `char`s (general category Cf) such as zero width spaces and joiners, bidi controls, the
+/// byte order mark and tags.
+#[cfg(feature = "category")]
+pub(crate) fn is_format(chr: char) -> bool {
+    matches!(chr, '\u{00AD}' | '\u{200B}'..='\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2060}'..='\u{2064}' | '\u{FEFF}' | '\u{E0001}' | '\u{E0020}'..='\u{E007F}')
+}
+
+/// Returns `true` for combining marks (general category M). The approximation is the extending `char`s that aren't
+/// format `char`s or emoji modifiers, so spacing marks that are also alphabetic aren't included.
+#[cfg(feature = "category")]
+pub(crate) fn is_mark(chr: char) -> bool {
+    in_table(EXTEND, chr) && !is_format(chr) && !('\u{1F3FB}'..='\u{1F3FF}').contains(&chr)
+}
+
+/// Returns `true` for punctuation `char`s (general category P).
+#[cfg(feature = "category")]
+pub(crate) fn is_punctuation(chr: char) -> bool {
+    in_table(PUNCTUATION, chr)
+}
+
+/// Returns the script of `chr`.
+#[cfg(feature = "script")]
+pub(crate) fn script(chr: char) -> Script {
+    let code_point: u32 = chr as u32;
+
+    match SCRIPTS.binary_search_by(|&(first, last, _)| match (code_point < first, code_point > last) {
+        (true, _) => std::cmp::Ordering::Greater,
+        (_, true) => std::cmp::Ordering::Less,
+        _ => std::cmp::Ordering::Equal,
+    }) {
+        Ok(index) => SCRIPTS[index].2,
+        Err(_) if chr.is_alphabetic() => Script::Unknown,
+        Err(_) => Script::Common,
+    }
+}
+
+/// Returns `true` if a grapheme cluster is an emoji: a pictograph that is shown as an emoji by default or is followed
+/// by an emoji presentation selector or modifier, a ZWJ sequence that starts with one, a flag or a keycap sequence.
+pub(crate) fn is_emoji(grapheme: &str) -> bool {
+    let first: char = match grapheme.chars().next() {
+        Some(chr) => chr,
+        None => return false,
+    };
+
+    match first {
+        _ if is_regional_indicator(first) => true,
+        '0'..='9' | '#' | '*' => grapheme.contains('\u{20E3}'),
+        _ if in_table(PICTOGRAPHIC, first) => {
+            // The wide CJK symbols in the pictograph table are shown as text by default.
+            (char_width(first) == 2 && !('\u{3000}'..='\u{33FF}').contains(&first))
+                || grapheme.chars().any(|chr| chr == EMOJI_PRESENTATION_SELECTOR || ('\u{1F3FB}'..='\u{1F3FF}').contains(&chr))
+        },
+        _ => false,
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Hangul {
+    Leading,
+    Vowel,
+    Trailing,
+    VowelSyllable,
+    TrailingSyllable,
+}
+
+fn hangul(chr: char) -> Option<Hangul> {
+    match chr as u32 {
+        0x1100..=0x115F | 0xA960..=0xA97C => Some(Hangul::Leading),
+        0x1160..=0x11A7 | 0xD7B0..=0xD7C6 => Some(Hangul::Vowel),
+        0x11A8..=0x11FF | 0xD7CB..=0xD7FB => Some(Hangul::Trailing),
+        code_point @ 0xAC00..=0xD7A3 => match (code_point - 0xAC00) % 28 {
+            0 => Some(Hangul::VowelSyllable),
+            _ => Some(Hangul::TrailingSyllable),
+        },
+        _ => None,
+    }
+}
+
+fn is_regional_indicator(chr: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&chr)
+}
+
+/// Returns the byte offset of the end of the grapheme cluster that starts at `byte_offset`, which must be a `char`
+/// boundary before the end of `str`.
+///
+/// This follows the main rules of UAX #29 for extended grapheme clusters: CR LF, combining marks and other extending
+/// `char`s, emoji ZWJ sequences, regional indicator pairs and Hangul syllables. Prepended concatenation marks and
+/// spacing marks aren't joined.
+pub(crate) fn grapheme_end(str: &str, byte_offset: usize) -> usize {
+    let mut chars = str[byte_offset..].char_indices();
+    let (_, first) = chars.next().unwrap();
+    let mut previous: char = first;
+    let mut regional_indicators: usize = is_regional_indicator(first) as usize;
+
+    for (offset, chr) in chars {
+        let joined: bool = match (previous, chr) {
+            ('\r', '\n') => true,
+            _ if previous.is_control() || chr.is_control() => false,
+            _ if in_table(EXTEND, chr) => true,
+            (ZERO_WIDTH_JOINER, _) => in_table(PICTOGRAPHIC, chr),
+            _ if is_regional_indicator(previous) && is_regional_indicator(chr) => regional_indicators % 2 == 1,
+            _ => matches!(
+                (hangul(previous), hangul(chr)),
+                (Some(Hangul::Leading), Some(Hangul::Leading | Hangul::Vowel | Hangul::VowelSyllable | Hangul::TrailingSyllable))
+                    | (Some(Hangul::Vowel | Hangul::VowelSyllable), Some(Hangul::Vowel | Hangul::Trailing))
+                    | (Some(Hangul::Trailing | Hangul::TrailingSyllable), Some(Hangul::Trailing))
+            ),
+        };
+
+        if !joined {
+            return byte_offset + offset;
+        }
+
+        regional_indicators += is_regional_indicator(chr) as usize;
+        previous = chr;
+    }
+
+    str.len()
+}
+
+/// Returns an iterator over the byte ranges of the grapheme clusters in `str`.
+pub(crate) fn grapheme_ranges(str: &str) -> impl Iterator<Item = std::ops::Range<usize>> + '_ {
+    let mut byte_offset: usize = 0;
+
+    std::iter::from_fn(move || match byte_offset < str.len() {
+        true => {
+            let start: usize = byte_offset;
+
+            byte_offset = grapheme_end(str, start);
+
+            Some(start..byte_offset)
+        },
+        false => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graphemes(str: &str) -> Vec<&str> {
+        grapheme_ranges(str).map(|range| &str[range]).collect()
+    }
+
+    #[test]
+    fn test_graphemes_join_extending_chars() {
+        assert_eq!(graphemes(""), Vec::<&str>::new());
+        assert_eq!(graphemes("e\u{301}a"), vec!["e\u{301}", "a"]);
+        assert_eq!(graphemes("\r\n\n"), vec!["\r\n", "\n"]);
+        assert_eq!(graphemes("\u{301}a"), vec!["\u{301}", "a"]);
+        assert_eq!(graphemes("👍🏽!"), vec!["👍🏽", "!"]);
+        assert_eq!(graphemes("👨\u{200D}👩\u{200D}👧x"), vec!["👨\u{200D}👩\u{200D}👧", "x"]);
+        assert_eq!(graphemes("🇯🇵🇺🇸🇫"), vec!["🇯🇵", "🇺🇸", "🇫"]);
+        assert_eq!(graphemes("\u{1100}\u{1161}\u{11A8}가"), vec!["\u{1100}\u{1161}\u{11A8}", "가"]);
+    }
+
+    #[test]
+    fn test_tables_are_sorted_and_disjoint() {
+        for table in [WIDE, EXTEND, PICTOGRAPHIC] {
+            assert!(table.iter().all(|&(first, last)| first <= last));
+            assert!(table.windows(2).all(|pair| pair[0].1 < pair[1].0));
+        }
+    }
+
+    #[cfg(feature = "category")]
+    #[test]
+    fn test_punctuation_table_is_sorted_and_disjoint() {
+        assert!(PUNCTUATION.iter().all(|&(first, last)| first <= last));
+        assert!(PUNCTUATION.windows(2).all(|pair| pair[0].1 < pair[1].0));
+    }
+
+    #[cfg(feature = "script")]
+    #[test]
+    fn test_script_table_is_sorted_and_disjoint() {
+        assert!(SCRIPTS.iter().all(|&(first, last, _)| first <= last));
+        assert!(SCRIPTS.windows(2).all(|pair| pair[0].1 < pair[1].0));
+    }
+
+    #[test]
+    fn test_emoji_graphemes() {
+        for emoji in ["😀", "👍🏽", "👨\u{200D}👩\u{200D}👧", "🇯🇵", "\u{2764}\u{FE0F}", "1\u{FE0F}\u{20E3}", "\u{231A}"] {
+            assert!(is_emoji(emoji), "{emoji}");
+        }
+
+        for text in ["a", "1", "©", "\u{2764}", "中", "\u{3030}", ""] {
+            assert!(!is_emoji(text), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_widths() {
+        assert_eq!(char_width('a'), 1);
+        assert_eq!(char_width('中'), 2);
+        assert_eq!(char_width('\u{3000}'), 2);
+        assert_eq!(char_width('😀'), 2);
+        assert_eq!(char_width('\u{301}'), 0);
+        assert_eq!(char_width('\n'), 0);
+        assert_eq!(grapheme_width("e\u{301}"), 1);
+        assert_eq!(grapheme_width("\u{2764}\u{FE0F}"), 2);
+        assert_eq!(grapheme_width("🇯🇵"), 2);
+    }
+}
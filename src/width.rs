@@ -0,0 +1,467 @@
+use std::ops::Range;
+
+use crate::{unicode, IndexableStr, IndexableString, Offset, Span, SubStr};
+
+/// `Segment` is a run of non-whitespace grapheme clusters that can't be broken, and the whitespace after it, in a
+/// line being wrapped. East Asian wide clusters are segments on their own, since a line can break on either side of
+/// them.
+#[derive(Copy, Clone)]
+struct Segment {
+    start: usize,
+    word_end: usize,
+    end: usize,
+    word_width: usize,
+    space_width: usize,
+}
+
+impl<'a, O: Offset> IndexableStr<'a, O> {
+    /// Returns the number of terminal columns the string takes. East Asian wide `char`s and emoji take two columns,
+    /// and combining marks and control `char`s take none.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// assert_eq!(IndexableStr::new("a中😀e\u{301}").display_width(), 6);
+    /// ```
+    pub fn display_width(&self) -> usize {
+        self.grapheme_ranges().map(|range| unicode::grapheme_width(&self.str[range])).sum()
+    }
+
+    /// Returns a `SubStr` view of the longest prefix that fits in `columns` terminal columns, without splitting a
+    /// grapheme cluster. The end of its `span` is the `char` index where the string was cut.
+    /// # Arguments
+    ///
+    /// * `columns` - The number of columns available.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableStr;
+    ///
+    /// let s = IndexableStr::new("ab中文e\u{301}");
+    ///
+    /// assert_eq!(s.truncate_to_width(5).as_str(), "ab中");
+    /// assert_eq!(s.truncate_to_width(6).span().end, 4);
+    /// assert_eq!(s.truncate_to_width(7).as_str(), "ab中文e\u{301}");
+    /// ```
+    pub fn truncate_to_width(&self, columns: usize) -> SubStr<'_, O> {
+        self.slice(0..self.width_prefix_length(columns))
+    }
+
+    /// Returns the `Span` of each line after wrapping the string to fit in `columns` terminal columns, leaving the
+    /// string untouched.
+    ///
+    /// Lines are broken at newlines, after whitespace and around East Asian wide `char`s. A word that's wider than a
+    /// line is broken between grapheme clusters. The whitespace at a break and the newlines aren't part of any span.
+    /// # Arguments
+    ///
+    /// * `columns` - The number of columns in a line.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("the 😀 wrapped\nline");
+    /// let lines: Vec<&str> = s.wrap(8).into_iter().map(|span| &s[span]).collect();
+    ///
+    /// assert_eq!(lines, vec!["the 😀", "wrapped", "line"]);
+    /// assert_eq!(s.wrap(8)[1], Span::new(6, 13));
+    /// ```
+    pub fn wrap(&self, columns: usize) -> Vec<Span> {
+        let mut lines: Vec<Span> = Vec::new();
+        let mut line_start: usize = 0;
+
+        for line in self.str.split('\n') {
+            let mut start: usize = line_start;
+            let mut content_end: usize = line_start;
+            let mut width: usize = 0;
+            let mut space_width: usize = 0;
+
+            for segment in segments(line, line_start) {
+                if content_end > start && width + space_width + segment.word_width > columns {
+                    lines.push(Span::new(start, content_end));
+                    start = segment.start;
+                    width = 0;
+                    space_width = 0;
+                }
+
+                if width + space_width + segment.word_width > columns {
+                    // The word doesn't fit on a line of its own, so it's broken between grapheme clusters.
+                    let word_start: usize = self.byte_offset(segment.start);
+                    let mut index: usize = segment.start;
+
+                    for range in unicode::grapheme_ranges(&self.str[word_start..self.byte_offset(segment.word_end)]) {
+                        let grapheme: &str = &self.str[word_start + range.start..word_start + range.end];
+                        let grapheme_width: usize = unicode::grapheme_width(grapheme);
+
+                        if index > start && width + space_width + grapheme_width > columns {
+                            lines.push(Span::new(start, index));
+                            start = index;
+                            width = 0;
+                            space_width = 0;
+                        }
+
+                        width += space_width + grapheme_width;
+                        space_width = 0;
+                        index += grapheme.chars().count();
+                    }
+                } else {
+                    width += space_width + segment.word_width;
+                }
+
+                content_end = segment.word_end;
+                space_width = segment.space_width;
+            }
+
+            lines.push(Span::new(start, content_end));
+            line_start += line.chars().count() + 1;
+        }
+
+        lines
+    }
+
+    /// Returns a `SubStr` view of the grapheme clusters that lie within a range of terminal columns of a line, such as
+    /// a field of a fixed-width record. A tab moves to the next multiple of `tab_width` columns. A cluster that's only
+    /// partly inside the range, such as a wide `char` across its start, is left out.
+    /// # Arguments
+    ///
+    /// * `columns` - The range of columns, counted from 0.
+    /// * `tab_width` - The distance between tab stops, or 0 if tabs take no columns.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("id\t名前  😀ok");
+    /// let field = s.field_at_cols(8..14, 8);
+    ///
+    /// assert_eq!(field.as_str(), "名前  ");
+    /// assert_eq!(field.span(), Span::new(3, 7));
+    /// assert_eq!(s.field_at_cols(9..20, 8).as_str(), "前  😀ok");
+    /// ```
+    pub fn field_at_cols(&self, columns: Range<usize>, tab_width: usize) -> SubStr<'_, O> {
+        self.slice(self.column_range(columns, tab_width))
+    }
+
+    /// Returns the string shortened to fit in `columns` terminal columns by replacing its middle with `…`, with the
+    /// `Span`s of the `char`s that were kept. Grapheme clusters are never split, and the start keeps the extra column
+    /// when the space can't be shared evenly. A string that already fits is returned whole with one span, and there
+    /// are no spans if `columns` is 0.
+    /// # Arguments
+    ///
+    /// * `columns` - The number of columns available.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableStr, Span};
+    ///
+    /// let s = IndexableStr::new("/home/用户/文档/report.txt");
+    /// let (truncated, kept) = s.truncate_middle(16);
+    ///
+    /// assert_eq!(truncated.as_str(), "/home/用…ort.txt");
+    /// assert_eq!(truncated.display_width(), 16);
+    /// assert_eq!(kept, vec![Span::new(0, 7), Span::new(15, 22)]);
+    /// assert_eq!(s.truncate_middle(40).1, vec![Span::new(0, 22)]);
+    /// ```
+    pub fn truncate_middle(&self, columns: usize) -> (IndexableString, Vec<Span>) {
+        let graphemes: Vec<(usize, usize)> = self.grapheme_ranges().map(|range| (self.str[range.clone()].chars().count(), unicode::grapheme_width(&self.str[range]))).collect();
+
+        if graphemes.iter().map(|&(_, width)| width).sum::<usize>() <= columns {
+            return (IndexableString::from(self.str), vec![Span::new(0, self.chars_length)]);
+        }
+
+        if columns == 0 {
+            return (IndexableString::from(""), Vec::new());
+        }
+
+        let budget: usize = columns - 1;
+        let mut head_width: usize = 0;
+        let mut head_length: usize = 0;
+
+        for &(length, width) in graphemes.iter() {
+            if head_width + width > budget.div_ceil(2) {
+                break;
+            }
+
+            head_width += width;
+            head_length += length;
+        }
+
+        let mut tail_width: usize = 0;
+        let mut tail_length: usize = 0;
+
+        for &(length, width) in graphemes.iter().rev() {
+            if head_width + tail_width + width > budget {
+                break;
+            }
+
+            tail_width += width;
+            tail_length += length;
+        }
+
+        let tail_start: usize = self.chars_length - tail_length;
+        let mut truncated: String = self.create_str_from_range(0, head_length).to_string();
+
+        truncated.push('…');
+        truncated.push_str(self.create_str_from_range(tail_start, self.chars_length));
+
+        (IndexableString::from(truncated), vec![Span::new(0, head_length), Span::new(tail_start, self.chars_length)])
+    }
+
+    /// Returns the range of `char`s of the grapheme clusters that lie within `columns`.
+    fn column_range(&self, columns: Range<usize>, tab_width: usize) -> Range<usize> {
+        let mut column: usize = 0;
+        let mut index: usize = 0;
+        let mut start: Option<usize> = None;
+        let mut end: usize = 0;
+
+        for range in self.grapheme_ranges() {
+            let grapheme: &str = &self.str[range];
+            let width: usize = match (grapheme, tab_width) {
+                ("\t", 0) => 0,
+                ("\t", _) => tab_width - column % tab_width,
+                _ => unicode::grapheme_width(grapheme),
+            };
+
+            if column + width > columns.end {
+                break;
+            }
+
+            if column >= columns.start && start.is_none() {
+                start = Some(index);
+            }
+
+            column += width;
+            index += grapheme.chars().count();
+            end = index;
+        }
+
+        match start {
+            Some(start) => start..end,
+            None => end..end,
+        }
+    }
+
+    /// Returns the number of `char`s in the longest prefix of whole grapheme clusters that fits in `columns`.
+    fn width_prefix_length(&self, columns: usize) -> usize {
+        let mut width: usize = 0;
+
+        for range in self.grapheme_ranges() {
+            width += unicode::grapheme_width(&self.str[range.clone()]);
+
+            if width > columns {
+                return self.char_index_at_byte(range.start).unwrap();
+            }
+        }
+
+        self.chars_length
+    }
+}
+
+/// Splits a line without newlines, whose first `char` is at index `line_start`, into segments.
+fn segments(line: &str, line_start: usize) -> Vec<Segment> {
+    let mut segments: Vec<Segment> = Vec::new();
+    let mut index: usize = line_start;
+    let mut previous_width: usize = 0;
+
+    for range in unicode::grapheme_ranges(line) {
+        let grapheme: &str = &line[range];
+        let width: usize = unicode::grapheme_width(grapheme);
+        let length: usize = grapheme.chars().count();
+        let is_space: bool = grapheme.starts_with(char::is_whitespace);
+
+        match segments.last_mut() {
+            Some(segment) if is_space => {
+                segment.end += length;
+                segment.space_width += width;
+            },
+            Some(segment) if segment.end == segment.word_end && width < 2 && previous_width < 2 => {
+                segment.word_end += length;
+                segment.end += length;
+                segment.word_width += width;
+            },
+            _ => {
+                let (word_length, word_width) = match is_space {
+                    true => (0, 0),
+                    false => (length, width),
+                };
+
+                segments.push(Segment {
+                    start: index,
+                    word_end: index + word_length,
+                    end: index + length,
+                    word_width,
+                    space_width: width - word_width,
+                });
+            },
+        }
+
+        index += length;
+        previous_width = width;
+    }
+
+    segments
+}
+
+impl IndexableString {
+    /// Returns the number of terminal columns the string takes. See `IndexableStr::display_width`.
+    ///
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// assert_eq!(IndexableString::from("a中😀").display_width(), 5);
+    /// ```
+    pub fn display_width(&self) -> usize {
+        self.as_indexable_str().display_width()
+    }
+
+    /// Returns a `SubStr` view of the longest prefix that fits in `columns` terminal columns, without splitting a
+    /// grapheme cluster. See `IndexableStr::truncate_to_width`.
+    /// # Arguments
+    ///
+    /// * `columns` - The number of columns available.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("ab中文");
+    ///
+    /// assert_eq!(s.truncate_to_width(3).as_str(), "ab");
+    /// ```
+    pub fn truncate_to_width(&self, columns: usize) -> SubStr<'_> {
+        self.slice(0..self.as_indexable_str().width_prefix_length(columns))
+    }
+
+    /// Returns the `Span` of each line after wrapping the string to fit in `columns` terminal columns. See
+    /// `IndexableStr::wrap`.
+    /// # Arguments
+    ///
+    /// * `columns` - The number of columns in a line.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableString, Span};
+    ///
+    /// let s = IndexableString::from("中文字");
+    ///
+    /// assert_eq!(s.wrap(4), vec![Span::new(0, 2), Span::new(2, 3)]);
+    /// ```
+    pub fn wrap(&self, columns: usize) -> Vec<Span> {
+        self.as_indexable_str().wrap(columns)
+    }
+
+    /// Returns a `SubStr` view of the grapheme clusters within a range of terminal columns. See
+    /// `IndexableStr::field_at_cols`.
+    /// # Arguments
+    ///
+    /// * `columns` - The range of columns, counted from 0.
+    /// * `tab_width` - The distance between tab stops, or 0 if tabs take no columns.
+    /// # Examples
+    /// ```
+    /// use indexable_str::IndexableString;
+    ///
+    /// let s = IndexableString::from("ab\t中x");
+    ///
+    /// assert_eq!(s.field_at_cols(4..7, 4).as_str(), "中x");
+    /// assert_eq!(s.field_at_cols(5..7, 4).as_str(), "x");
+    /// ```
+    pub fn field_at_cols(&self, columns: Range<usize>, tab_width: usize) -> SubStr<'_> {
+        self.slice(self.as_indexable_str().column_range(columns, tab_width))
+    }
+
+    /// Returns the string shortened to fit in `columns` terminal columns by replacing its middle with `…`. See
+    /// `IndexableStr::truncate_middle`.
+    /// # Arguments
+    ///
+    /// * `columns` - The number of columns available.
+    /// # Examples
+    /// ```
+    /// use indexable_str::{IndexableString, Span};
+    ///
+    /// let (truncated, kept) = IndexableString::from("abcdefgh").truncate_middle(6);
+    ///
+    /// assert_eq!(truncated.as_str(), "abc…gh");
+    /// assert_eq!(kept, vec![Span::new(0, 3), Span::new(6, 8)]);
+    ///
+    /// let (truncated, kept) = IndexableString::from("ab中文cd").truncate_middle(6);
+    ///
+    /// assert_eq!(truncated.as_str(), "ab…cd");
+    /// assert_eq!(truncated.display_width(), 5);
+    /// assert_eq!(kept, vec![Span::new(0, 2), Span::new(4, 6)]);
+    /// ```
+    pub fn truncate_middle(&self, columns: usize) -> (IndexableString, Vec<Span>) {
+        self.as_indexable_str().truncate_middle(columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_to_width_does_not_split_graphemes() {
+        let s = IndexableStr::new("👨\u{200D}👩\u{200D}👧e\u{301}中");
+
+        assert_eq!(s.truncate_to_width(1).as_str(), "");
+        assert_eq!(s.truncate_to_width(2).span().end, 5);
+        assert_eq!(s.truncate_to_width(3).as_str(), "👨\u{200D}👩\u{200D}👧e\u{301}");
+        assert_eq!(s.truncate_to_width(4).span().end, 7);
+        assert_eq!(s.truncate_to_width(5).span().end, 8);
+    }
+
+    #[test]
+    fn test_field_at_cols_leaves_out_partly_covered_graphemes() {
+        let s = IndexableStr::new("a中e\u{301}\tb");
+
+        assert_eq!(s.field_at_cols(0..2, 4).as_str(), "a");
+        assert_eq!(s.field_at_cols(2..4, 4).as_str(), "e\u{301}");
+        assert_eq!(s.field_at_cols(3..5, 4).as_str(), "e\u{301}");
+        assert_eq!(s.field_at_cols(3..9, 4).as_str(), "e\u{301}\tb");
+        assert_eq!(s.field_at_cols(3..5, 0).span(), Span::new(2, 6));
+        assert_eq!(s.field_at_cols(2..2, 4).span(), Span::new(1, 1));
+        assert_eq!(s.field_at_cols(10..20, 4).span(), Span::new(6, 6));
+    }
+
+    #[test]
+    fn test_truncate_middle_fits_in_the_columns() {
+        let s = IndexableStr::new("e\u{301}中文abc👨\u{200D}👩\u{200D}👧xyz");
+
+        for columns in 0..s.display_width() {
+            let (truncated, kept) = s.truncate_middle(columns);
+
+            assert!(truncated.display_width() <= columns);
+            assert!(truncated.display_width() + 1 >= columns);
+            assert_eq!(truncated.as_str(), kept.iter().map(|&span| &s[span]).collect::<Vec<&str>>().join("…"));
+        }
+
+        assert_eq!(s.truncate_middle(2).0.as_str(), "e\u{301}…");
+        assert_eq!(s.truncate_middle(1).1, vec![Span::new(0, 0), Span::new(15, 15)]);
+    }
+
+    fn wrapped_lines(str: &str, columns: usize) -> Vec<String> {
+        let s = IndexableStr::new(str);
+
+        s.wrap(columns).into_iter().map(|span| s[span].to_string()).collect()
+    }
+
+    #[test]
+    fn test_wrap_breaks_at_whitespace_and_newlines() {
+        assert_eq!(wrapped_lines("", 10), vec![""]);
+        assert_eq!(wrapped_lines("a b c", 3), vec!["a b", "c"]);
+        assert_eq!(wrapped_lines("a  b\n\nc ", 10), vec!["a  b", "", "c"]);
+        assert_eq!(wrapped_lines("  indented text", 10), vec!["  indented", "text"]);
+        assert_eq!(wrapped_lines("e\u{301}te\u{301} e\u{301}te\u{301}", 3), vec!["e\u{301}te\u{301}", "e\u{301}te\u{301}"]);
+    }
+
+    #[test]
+    fn test_wrap_breaks_long_words_and_wide_chars() {
+        assert_eq!(wrapped_lines("abcdefg hi", 3), vec!["abc", "def", "g", "hi"]);
+        assert_eq!(wrapped_lines("中文字符串", 5), vec!["中文", "字符", "串"]);
+        assert_eq!(wrapped_lines("ab中cd", 3), vec!["ab", "中", "cd"]);
+        assert_eq!(wrapped_lines("😀😀", 1), vec!["😀", "😀"]);
+    }
+
+    #[test]
+    fn test_truncate_to_width_of_empty_string() {
+        let s = IndexableStr::new("");
+
+        assert!(s.truncate_to_width(0).is_empty());
+        assert_eq!(s.display_width(), 0);
+    }
+}